@@ -12,6 +12,10 @@ use chrono::{offset::Utc, DateTime};
 use futures::prelude::*;
 use std::{pin::Pin, time::Duration};
 
+mod explain;
+
+pub use self::explain::{Decision, ExplainRequest, Explanation};
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ServerRef {
     Default(&'static str),
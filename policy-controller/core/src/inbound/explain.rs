@@ -0,0 +1,476 @@
+//! Evaluates a request against an `InboundServer`, mirroring the route
+//! selection and authorization the proxy performs.
+
+use super::{
+    AuthorizationRef, ClientAuthentication, ClientAuthorization, InboundRoute, InboundServer,
+    ProxyProtocol, RouteRef,
+};
+use crate::{
+    routes::{GrpcRouteMatch, HeaderMatch, HostMatch, HttpRouteMatch, PathMatch, QueryParamMatch},
+    IdentityMatch,
+};
+use ahash::AHashMap as HashMap;
+use regex::Regex;
+use std::net::IpAddr;
+
+/// A client connection (and, optionally, an HTTP request on it) to be
+/// evaluated against a server.
+#[derive(Clone, Debug)]
+pub struct ExplainRequest {
+    pub client_ip: IpAddr,
+
+    /// The client's mesh identity. When unset, the connection is treated as
+    /// plaintext.
+    pub client_identity: Option<String>,
+
+    /// The request's method, URI and headers. When unset, only the
+    /// connection is authorized, as for opaque traffic.
+    pub http: Option<http::Request<()>>,
+}
+
+/// The outcome of evaluating an `ExplainRequest`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub decision: Decision,
+
+    /// The route that matched the request, if routes apply to the server.
+    pub route: Option<RouteRef>,
+
+    /// The authorization that permitted the request.
+    pub authorization: Option<AuthorizationRef>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Decision {
+    Allow,
+    /// No route matched the request; the proxy responds with a 404.
+    RouteNotFound,
+    /// No authorization permitted the client.
+    Unauthorized,
+}
+
+// === impl InboundServer ===
+
+impl InboundServer {
+    /// Determines whether the proxy would permit a request to this server, and
+    /// which route and authorization apply to it.
+    ///
+    /// HTTP requests are matched against the server's HTTP (or gRPC) routes.
+    /// A request is authorized by either the matched route's authorizations
+    /// or the server's authorizations.
+    pub fn explain(&self, req: &ExplainRequest) -> Explanation {
+        let http = match (&self.protocol, &req.http) {
+            (ProxyProtocol::Opaque | ProxyProtocol::Tls, _) | (_, None) => None,
+            (_, Some(http)) => Some(http),
+        };
+
+        let Some(http) = http else {
+            return Explanation {
+                authorization: find_authz(&self.authorizations, req),
+                ..Explanation::route(None)
+            }
+            .decide();
+        };
+
+        let route = if matches!(self.protocol, ProxyProtocol::Grpc) {
+            select_route(&self.grpc_routes, http, grpc_match_score)
+        } else {
+            select_route(&self.http_routes, http, http_match_score)
+        };
+        let Some((reference, authzs)) = route else {
+            return Explanation {
+                decision: Decision::RouteNotFound,
+                route: None,
+                authorization: None,
+            };
+        };
+
+        Explanation {
+            authorization: find_authz(authzs, req)
+                .or_else(|| find_authz(&self.authorizations, req)),
+            ..Explanation::route(Some(reference.clone()))
+        }
+        .decide()
+    }
+}
+
+// === impl Explanation ===
+
+impl Explanation {
+    fn route(route: Option<RouteRef>) -> Self {
+        Self {
+            decision: Decision::Unauthorized,
+            route,
+            authorization: None,
+        }
+    }
+
+    fn decide(mut self) -> Self {
+        if self.authorization.is_some() {
+            self.decision = Decision::Allow;
+        }
+        self
+    }
+}
+
+/// Selects the most specific route matching the request. Hostname matches
+/// take precedence over rule matches; remaining ties go to the oldest route.
+fn select_route<'r, M>(
+    routes: &'r HashMap<RouteRef, InboundRoute<M>>,
+    req: &http::Request<()>,
+    score: impl Fn(&M, &http::Request<()>) -> Option<MatchScore>,
+) -> Option<(
+    &'r RouteRef,
+    &'r HashMap<AuthorizationRef, ClientAuthorization>,
+)> {
+    let host = request_host(req);
+    routes
+        .iter()
+        .filter_map(|(reference, route)| {
+            let host = host_score(&route.hostnames, host.as_deref())?;
+            let rule = route
+                .rules
+                .iter()
+                .flat_map(|rule| rule.matches.iter())
+                .filter_map(|m| score(m, req))
+                .max()?;
+            Some(((host, rule), route.creation_timestamp, reference, route))
+        })
+        .max_by(|(a, a_ts, a_ref, _), (b, b_ts, b_ref, _)| {
+            a.cmp(b)
+                .then_with(|| match (a_ts, b_ts) {
+                    (Some(a), Some(b)) => b.cmp(a),
+                    (a, b) => b.is_some().cmp(&a.is_some()),
+                })
+                .then_with(|| b_ref.cmp(a_ref))
+        })
+        .map(|(_, _, reference, route)| (reference, &route.authorizations))
+}
+
+/// Ranks how specifically a rule matched a request.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct MatchScore {
+    path: (u8, usize),
+    method: bool,
+    headers: usize,
+    query_params: usize,
+}
+
+fn http_match_score(m: &HttpRouteMatch, req: &http::Request<()>) -> Option<MatchScore> {
+    let path = req.uri().path();
+    let path = match &m.path {
+        None => (0, 0),
+        Some(PathMatch::Exact(p)) if p == path => (3, p.len()),
+        Some(PathMatch::Prefix(p)) if prefix_matches(p, path) => (2, p.len()),
+        Some(PathMatch::Regex(r)) if full_match(r, path) => (1, r.as_str().len()),
+        Some(_) => return None,
+    };
+
+    if m.method
+        .as_ref()
+        .is_some_and(|method| method != req.method())
+    {
+        return None;
+    }
+    if !m.headers.iter().all(|h| header_matches(h, req)) {
+        return None;
+    }
+
+    let query = query_pairs(req);
+    let query_matches = |m: &QueryParamMatch| match m {
+        QueryParamMatch::Exact(name, value) => query.iter().any(|(n, v)| n == name && v == value),
+        QueryParamMatch::Regex(name, re) => {
+            query.iter().any(|(n, v)| n == name && full_match(re, v))
+        }
+    };
+    if !m.query_params.iter().all(query_matches) {
+        return None;
+    }
+
+    Some(MatchScore {
+        path,
+        method: m.method.is_some(),
+        headers: m.headers.len(),
+        query_params: m.query_params.len(),
+    })
+}
+
+fn grpc_match_score(m: &GrpcRouteMatch, req: &http::Request<()>) -> Option<MatchScore> {
+    let (service, method) = req
+        .uri()
+        .path()
+        .strip_prefix('/')
+        .and_then(|p| p.split_once('/'))
+        .unwrap_or_default();
+
+    let mut specificity = 0;
+    if let Some(rpc) = &m.method {
+        if rpc.service.as_ref().is_some_and(|s| s != service) {
+            return None;
+        }
+        if rpc.method.as_ref().is_some_and(|m| m != method) {
+            return None;
+        }
+        specificity = usize::from(rpc.service.is_some()) + usize::from(rpc.method.is_some());
+    }
+    if !m.headers.iter().all(|h| header_matches(h, req)) {
+        return None;
+    }
+
+    Some(MatchScore {
+        path: (0, specificity),
+        method: false,
+        headers: m.headers.len(),
+        query_params: 0,
+    })
+}
+
+/// Prefix matches are evaluated element-wise, so `/foo` matches `/foo` and
+/// `/foo/bar` but not `/foobar`.
+fn prefix_matches(prefix: &str, path: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    match path.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+fn full_match(re: &Regex, s: &str) -> bool {
+    re.find(s)
+        .is_some_and(|m| m.start() == 0 && m.end() == s.len())
+}
+
+fn header_matches(m: &HeaderMatch, req: &http::Request<()>) -> bool {
+    match m {
+        HeaderMatch::Exact(name, value) => req.headers().get_all(name).iter().any(|v| v == value),
+        HeaderMatch::Regex(name, re) => req
+            .headers()
+            .get_all(name)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .any(|v| full_match(re, v)),
+    }
+}
+
+fn query_pairs(req: &http::Request<()>) -> Vec<(&str, &str)> {
+    req.uri()
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|kv| !kv.is_empty())
+        .map(|kv| kv.split_once('=').unwrap_or((kv, "")))
+        .collect()
+}
+
+fn request_host(req: &http::Request<()>) -> Option<String> {
+    let host = match req.uri().host() {
+        Some(host) => host,
+        None => {
+            let host = req.headers().get(http::header::HOST)?.to_str().ok()?;
+            host.rsplit_once(':').map(|(h, _)| h).unwrap_or(host)
+        }
+    };
+    Some(host.trim_end_matches('.').to_ascii_lowercase())
+}
+
+/// Ranks how specifically a route's hostnames match the request's host. A
+/// route without hostnames matches all requests with the lowest rank.
+fn host_score(hostnames: &[HostMatch], host: Option<&str>) -> Option<(u8, usize)> {
+    if hostnames.is_empty() {
+        return Some((0, 0));
+    }
+    let host = host?;
+    hostnames
+        .iter()
+        .filter_map(|m| match m {
+            HostMatch::Exact(h) if h.eq_ignore_ascii_case(host) => Some((2, h.len())),
+            HostMatch::Suffix { reverse_labels } => {
+                let labels = host.rsplit('.').collect::<Vec<_>>();
+                let matches = labels.len() > reverse_labels.len()
+                    && reverse_labels
+                        .iter()
+                        .zip(&labels)
+                        .all(|(a, b)| a.eq_ignore_ascii_case(b));
+                matches.then_some((1, reverse_labels.len()))
+            }
+            HostMatch::Exact(_) => None,
+        })
+        .max()
+}
+
+/// Finds an authorization that permits the client. Authorizations are
+/// checked in a stable order so that the result is deterministic.
+fn find_authz(
+    authzs: &HashMap<AuthorizationRef, ClientAuthorization>,
+    req: &ExplainRequest,
+) -> Option<AuthorizationRef> {
+    let mut authzs = authzs
+        .iter()
+        .filter(|(_, authz)| is_authorized(authz, req))
+        .map(|(reference, _)| reference)
+        .collect::<Vec<_>>();
+    authzs.sort_by_key(|reference| match reference {
+        AuthorizationRef::Default(name) => (0, *name),
+        AuthorizationRef::ServerAuthorization(name) => (1, name.as_str()),
+        AuthorizationRef::AuthorizationPolicy(name) => (2, name.as_str()),
+    });
+    authzs.first().map(|r| (*r).clone())
+}
+
+fn is_authorized(authz: &ClientAuthorization, req: &ExplainRequest) -> bool {
+    let in_network = authz.networks.iter().any(|n| {
+        n.net.contains(&req.client_ip) && !n.except.iter().any(|e| e.contains(&req.client_ip))
+    });
+    if !in_network {
+        return false;
+    }
+
+    match (&authz.authentication, &req.client_identity) {
+        (ClientAuthentication::Unauthenticated, _) => true,
+        (ClientAuthentication::TlsUnauthenticated, id) => id.is_some(),
+        (ClientAuthentication::TlsAuthenticated(ids), Some(id)) => {
+            ids.iter().any(|m| identity_matches(m, id))
+        }
+        (ClientAuthentication::TlsAuthenticated(_), None) => false,
+    }
+}
+
+fn identity_matches(m: &IdentityMatch, id: &str) -> bool {
+    match m {
+        IdentityMatch::Exact(name) => name == id,
+        IdentityMatch::Suffix(suffix) if suffix.is_empty() => true,
+        IdentityMatch::Suffix(suffix) => id
+            .strip_suffix(&suffix.join("."))
+            .is_some_and(|rest| rest.ends_with('.')),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{inbound::InboundRouteRule, routes::GroupKindName, NetworkMatch};
+
+    fn mk_server(protocol: ProxyProtocol) -> InboundServer {
+        InboundServer {
+            reference: super::super::ServerRef::Server("srv".to_string()),
+            protocol,
+            authorizations: [(
+                AuthorizationRef::AuthorizationPolicy("server-authz".to_string()),
+                ClientAuthorization {
+                    networks: vec![NetworkMatch::from(
+                        "10.0.0.0/8".parse::<ipnet::IpNet>().unwrap(),
+                    )],
+                    authentication: ClientAuthentication::TlsAuthenticated(vec![
+                        IdentityMatch::Suffix(vec![
+                            "ns-0".to_string(),
+                            "serviceaccount".to_string(),
+                        ]),
+                    ]),
+                },
+            )]
+            .into_iter()
+            .collect(),
+            ratelimit: None,
+            http_routes: HashMap::default(),
+            grpc_routes: HashMap::default(),
+        }
+    }
+
+    fn mk_route(name: &'static str, path: PathMatch) -> (RouteRef, InboundRoute<HttpRouteMatch>) {
+        let reference = RouteRef::Resource(GroupKindName {
+            group: "gateway.networking.k8s.io".into(),
+            kind: "HTTPRoute".into(),
+            name: name.into(),
+        });
+        let route = InboundRoute {
+            hostnames: vec![],
+            rules: vec![InboundRouteRule {
+                matches: vec![HttpRouteMatch {
+                    path: Some(path),
+                    headers: vec![],
+                    query_params: vec![],
+                    method: None,
+                }],
+                filters: vec![],
+            }],
+            authorizations: HashMap::default(),
+            creation_timestamp: None,
+        };
+        (reference, route)
+    }
+
+    fn mk_req(identity: Option<&str>, path: &str) -> ExplainRequest {
+        ExplainRequest {
+            client_ip: "10.1.2.3".parse().unwrap(),
+            client_identity: identity.map(ToString::to_string),
+            http: Some(http::Request::get(path).body(()).unwrap()),
+        }
+    }
+
+    #[test]
+    fn selects_most_specific_route() {
+        let mut server = mk_server(ProxyProtocol::Http1);
+        server.http_routes = [
+            mk_route("prefix", PathMatch::Prefix("/".to_string())),
+            mk_route("api", PathMatch::Prefix("/api".to_string())),
+            mk_route("exact", PathMatch::Exact("/api/v1".to_string())),
+        ]
+        .into_iter()
+        .collect();
+
+        let route_name = |path| match server
+            .explain(&mk_req(Some("sa.ns-0.serviceaccount"), path))
+            .route
+        {
+            Some(RouteRef::Resource(gkn)) => gkn.name.to_string(),
+            route => panic!("unexpected route: {route:?}"),
+        };
+        assert_eq!(route_name("/api/v1"), "exact");
+        assert_eq!(route_name("/api/v2"), "api");
+        assert_eq!(route_name("/apis"), "prefix");
+    }
+
+    #[test]
+    fn authorizes_route_or_server() {
+        let mut server = mk_server(ProxyProtocol::Http1);
+        let (reference, mut route) = mk_route("health", PathMatch::Exact("/health".to_string()));
+        route.authorizations.insert(
+            AuthorizationRef::AuthorizationPolicy("route-authz".to_string()),
+            ClientAuthorization {
+                networks: vec![NetworkMatch::from(
+                    "0.0.0.0/0".parse::<ipnet::IpNet>().unwrap(),
+                )],
+                authentication: ClientAuthentication::Unauthenticated,
+            },
+        );
+        server.http_routes = [(reference.clone(), route)].into_iter().collect();
+
+        let explanation = server.explain(&mk_req(None, "/health"));
+        assert_eq!(explanation.decision, Decision::Allow);
+        assert_eq!(explanation.route, Some(reference));
+        assert_eq!(
+            explanation.authorization,
+            Some(AuthorizationRef::AuthorizationPolicy(
+                "route-authz".to_string()
+            ))
+        );
+
+        assert_eq!(
+            server.explain(&mk_req(None, "/other")).decision,
+            Decision::RouteNotFound
+        );
+    }
+
+    #[test]
+    fn opaque_uses_server_authorizations() {
+        let server = mk_server(ProxyProtocol::Opaque);
+
+        let explanation = server.explain(&mk_req(Some("sa.ns-0.serviceaccount"), "/"));
+        assert_eq!(explanation.decision, Decision::Allow);
+        assert_eq!(explanation.route, None);
+
+        let denied = server.explain(&mk_req(Some("sa.ns-1.serviceaccount"), "/"));
+        assert_eq!(denied.decision, Decision::Unauthorized);
+        assert_eq!(denied.authorization, None);
+    }
+}
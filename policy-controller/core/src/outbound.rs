@@ -32,6 +32,8 @@ pub trait DiscoverOutboundPolicy<R, T> {
     async fn watch_external_policy(&self) -> ExternalPolicyStream;
 
     fn lookup_ip(&self, addr: IpAddr, port: NonZeroU16, source_namespace: String) -> Option<T>;
+
+    fn service_exists(&self, namespace: &str, name: &str) -> bool;
 }
 
 pub type OutboundPolicyStream = Pin<Box<dyn Stream<Item = OutboundPolicy> + Send + Sync + 'static>>;
@@ -116,8 +118,14 @@ pub struct TcpRouteRule {
 pub enum Backend {
     Addr(WeightedAddr),
     Service(WeightedService),
+    /// A multicluster `ServiceImport`, discovered through its cluster-set
+    /// authority.
+    ServiceImport(WeightedService),
     EgressNetwork(WeightedEgressNetwork),
-    Invalid { weight: u32, message: String },
+    Invalid {
+        weight: u32,
+        message: String,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
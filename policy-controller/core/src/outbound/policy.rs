@@ -13,6 +13,9 @@ pub enum ParentInfo {
         name: String,
         namespace: String,
         authority: String,
+        /// The target DNS name of an `ExternalName` Service, to which
+        /// traffic is forwarded instead of being balanced over endpoints.
+        external_name: Option<String>,
     },
     EgressNetwork {
         name: String,
@@ -26,12 +26,17 @@ pub struct ResourceTarget {
 pub enum Kind {
     EgressNetwork(SocketAddr),
     Service,
+    /// A headless Service, resolved from the address of one of its
+    /// endpoints.
+    HeadlessService(SocketAddr),
 }
 
 impl ResourceTarget {
     pub fn original_dst(&self) -> Option<SocketAddr> {
         match self.kind {
-            Kind::EgressNetwork(original_dst) => Some(original_dst),
+            Kind::EgressNetwork(original_dst) | Kind::HeadlessService(original_dst) => {
+                Some(original_dst)
+            }
             Kind::Service => None,
         }
     }
@@ -41,13 +46,13 @@ impl Kind {
     pub fn group(&self) -> &'static str {
         match self {
             Kind::EgressNetwork(_) => "policy.linkerd.io",
-            Kind::Service => "core",
+            Kind::Service | Kind::HeadlessService(_) => "core",
         }
     }
     pub fn kind(&self) -> &'static str {
         match self {
             Kind::EgressNetwork(_) => "EgressNetwork",
-            Kind::Service => "Service",
+            Kind::Service | Kind::HeadlessService(_) => "Service",
         }
     }
 }
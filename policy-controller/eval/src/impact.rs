@@ -0,0 +1,432 @@
+use crate::{line_diff, Evaluator, InboundTarget, OutboundTarget, Resource};
+use anyhow::{Context, Result};
+use linkerd2_proxy_api::{
+    inbound,
+    meta::{metadata, Metadata},
+    outbound,
+};
+use linkerd_policy_controller_k8s_api::policy::server::Port;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    num::NonZeroU16,
+};
+
+/// A candidate change to the resources held by an [`Evaluator`].
+#[derive(Clone, Debug)]
+pub enum Change {
+    Apply(Resource),
+    Delete(Resource),
+}
+
+/// The policy computed for every known pod port and Service port.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Snapshot {
+    pub inbound: BTreeMap<InboundTarget, inbound::Server>,
+    pub outbound: BTreeMap<OutboundTarget, outbound::OutboundPolicy>,
+}
+
+/// Describes the targets whose policy differs between two snapshots.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Impact {
+    pub inbound: Vec<TargetImpact<InboundTarget>>,
+    pub outbound: Vec<TargetImpact<OutboundTarget>>,
+}
+
+/// Describes how the policy for a single target changes.
+///
+/// Policy is broken into items, each of which is identified by a key (e.g. the
+/// resource that produced an authorization, or a route rule's index) and
+/// rendered as text. Backends and timeouts are keyed by route rule and are
+/// omitted from the rendered routes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TargetImpact<T> {
+    pub target: T,
+    pub authorizations: Vec<Delta>,
+    pub routes: Vec<Delta>,
+    pub backends: Vec<Delta>,
+    pub timeouts: Vec<Delta>,
+
+    /// Changes to the remainder of the policy: for inbound targets, the
+    /// selected server and its protocol; for outbound targets, the policy's
+    /// metadata and protocol.
+    pub other: Vec<Delta>,
+}
+
+/// An item that was added (`before` is `None`), removed (`after` is `None`), or
+/// modified.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delta {
+    pub key: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Policy items for a single target, keyed within each category.
+#[derive(Debug, Default)]
+struct Items {
+    authorizations: BTreeMap<String, String>,
+    routes: BTreeMap<String, String>,
+    backends: BTreeMap<String, String>,
+    timeouts: BTreeMap<String, String>,
+    other: BTreeMap<String, String>,
+}
+
+// === impl Evaluator ===
+
+impl Evaluator {
+    /// Computes the policy for each port of every Pod and Service.
+    ///
+    /// Pod ports include the ports declared by the pod's containers and the
+    /// numeric ports of Servers in the pod's namespace. Service ports are
+    /// evaluated from the Service's own namespace.
+    pub fn snapshot(&self) -> Result<Snapshot> {
+        let server_ports = self
+            .resources
+            .iter()
+            .filter_map(|r| match r {
+                Resource::Server(srv) => match srv.spec.port {
+                    Port::Number(port) => Some((srv.metadata.namespace.clone(), port)),
+                    Port::Name(_) => None,
+                },
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+
+        let mut snapshot = Snapshot::default();
+        for resource in &self.resources {
+            match resource {
+                Resource::Pod(pod) => {
+                    let container_ports = pod
+                        .spec
+                        .iter()
+                        .flat_map(|spec| &spec.containers)
+                        .flat_map(|c| c.ports.iter().flatten())
+                        .filter_map(|p| u16::try_from(p.container_port).ok())
+                        .filter_map(NonZeroU16::new);
+                    let ports = server_ports
+                        .iter()
+                        .filter(|(ns, _)| *ns == pod.metadata.namespace)
+                        .map(|(_, port)| *port)
+                        .chain(container_ports)
+                        .collect::<BTreeSet<_>>();
+                    for port in ports {
+                        let target = InboundTarget {
+                            namespace: pod.metadata.namespace.clone().unwrap_or_default(),
+                            pod: pod.metadata.name.clone().unwrap_or_default(),
+                            port,
+                        };
+                        let server = self
+                            .inbound(&target)
+                            .with_context(|| format!("inbound {target}"))?;
+                        snapshot.inbound.insert(target, server);
+                    }
+                }
+
+                Resource::Service(svc) => {
+                    let namespace = svc.metadata.namespace.clone().unwrap_or_default();
+                    let ports = svc
+                        .spec
+                        .iter()
+                        .flat_map(|spec| spec.ports.iter().flatten())
+                        .filter_map(|p| u16::try_from(p.port).ok())
+                        .filter_map(NonZeroU16::new);
+                    for port in ports {
+                        let target = OutboundTarget::Service {
+                            namespace: namespace.clone(),
+                            name: svc.metadata.name.clone().unwrap_or_default(),
+                            port,
+                            source_namespace: namespace.clone(),
+                        };
+                        let policy = self
+                            .outbound(&target)
+                            .with_context(|| format!("outbound {target}"))?;
+                        snapshot.outbound.insert(target, policy);
+                    }
+                }
+
+                _ => {}
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Describes how policy would change if the given changes were applied.
+    ///
+    /// The changes are applied to a fork of this evaluator, so this evaluator
+    /// is not modified.
+    pub fn impact(&self, changes: impl IntoIterator<Item = Change>) -> Result<Impact> {
+        let before = self.snapshot()?;
+        let mut candidate = self.fork();
+        for change in changes {
+            match change {
+                Change::Apply(resource) => candidate.apply(resource),
+                Change::Delete(resource) => candidate.delete(&resource),
+            }
+        }
+        let after = candidate.snapshot()?;
+        Ok(before.diff(&after))
+    }
+}
+
+// === impl Snapshot ===
+
+impl Snapshot {
+    pub fn diff(&self, after: &Self) -> Impact {
+        Impact {
+            inbound: diff_targets(&self.inbound, &after.inbound, inbound_items),
+            outbound: diff_targets(&self.outbound, &after.outbound, outbound_items),
+        }
+    }
+}
+
+fn diff_targets<T: Clone + Ord, P>(
+    before: &BTreeMap<T, P>,
+    after: &BTreeMap<T, P>,
+    items: fn(&P) -> Items,
+) -> Vec<TargetImpact<T>> {
+    let targets = before.keys().chain(after.keys()).collect::<BTreeSet<_>>();
+    targets
+        .into_iter()
+        .filter_map(|target| {
+            let before = before.get(target).map(items).unwrap_or_default();
+            let after = after.get(target).map(items).unwrap_or_default();
+            let impact = TargetImpact {
+                target: target.clone(),
+                authorizations: diff_items(before.authorizations, after.authorizations),
+                routes: diff_items(before.routes, after.routes),
+                backends: diff_items(before.backends, after.backends),
+                timeouts: diff_items(before.timeouts, after.timeouts),
+                other: diff_items(before.other, after.other),
+            };
+            (!impact.is_empty()).then_some(impact)
+        })
+        .collect()
+}
+
+fn diff_items(mut before: BTreeMap<String, String>, after: BTreeMap<String, String>) -> Vec<Delta> {
+    let mut deltas = Vec::new();
+    for (key, after) in after {
+        let before = before.remove(&key);
+        if before.as_ref() != Some(&after) {
+            deltas.push(Delta {
+                key,
+                before,
+                after: Some(after),
+            });
+        }
+    }
+    deltas.extend(before.into_iter().map(|(key, before)| Delta {
+        key,
+        before: Some(before),
+        after: None,
+    }));
+    deltas.sort_by(|a, b| a.key.cmp(&b.key));
+    deltas
+}
+
+fn inbound_items(server: &inbound::Server) -> Items {
+    use inbound::proxy_protocol::Kind;
+
+    fn authorizations(items: &mut Items, route: Option<&str>, authzs: Vec<inbound::Authz>) {
+        for mut authz in authzs {
+            // Authorization labels duplicate its metadata.
+            authz.labels.clear();
+            let key = match route {
+                Some(route) => format!("{route} {}", meta_key(&authz.metadata)),
+                None => meta_key(&authz.metadata),
+            };
+            items.authorizations.insert(key, format!("{authz:#?}"));
+        }
+    }
+
+    let mut items = Items::default();
+    let mut server = server.clone();
+    authorizations(&mut items, None, std::mem::take(&mut server.authorizations));
+
+    let http_routes = match server.protocol.as_mut().and_then(|p| p.kind.as_mut()) {
+        Some(Kind::Detect(detect)) => std::mem::take(&mut detect.http_routes),
+        Some(Kind::Http1(http)) => std::mem::take(&mut http.routes),
+        Some(Kind::Http2(http)) => std::mem::take(&mut http.routes),
+        _ => vec![],
+    };
+    for mut route in http_routes {
+        let key = format!("http {}", meta_key(&route.metadata));
+        authorizations(
+            &mut items,
+            Some(&key),
+            std::mem::take(&mut route.authorizations),
+        );
+        items.routes.insert(key, format!("{route:#?}"));
+    }
+    if let Some(Kind::Grpc(grpc)) = server.protocol.as_mut().and_then(|p| p.kind.as_mut()) {
+        for mut route in std::mem::take(&mut grpc.routes) {
+            let key = format!("grpc {}", meta_key(&route.metadata));
+            authorizations(
+                &mut items,
+                Some(&key),
+                std::mem::take(&mut route.authorizations),
+            );
+            items.routes.insert(key, format!("{route:#?}"));
+        }
+    }
+
+    let labels = std::mem::take(&mut server.labels)
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+    items.other.insert(
+        "server".to_string(),
+        format!("labels: {labels:?}\n{server:#?}"),
+    );
+    items
+}
+
+fn outbound_items(policy: &outbound::OutboundPolicy) -> Items {
+    use outbound::proxy_protocol::Kind;
+
+    // Each route type has its own rule type, but they share a shape.
+    macro_rules! routes {
+        ($items:expr, $family:literal, $routes:expr $(, $timeouts:ident)?) => {
+            for mut route in std::mem::take($routes) {
+                let key = format!("{} {}", $family, meta_key(&route.metadata));
+                for (i, rule) in route.rules.iter_mut().enumerate() {
+                    let rule_key = format!("{key} rule {i}");
+                    $items
+                        .backends
+                        .insert(rule_key.clone(), format!("{:#?}", rule.backends.take()));
+                    $(
+                        // The deprecated request timeout mirrors the response
+                        // timeout.
+                        #[allow(deprecated)]
+                        rule.request_timeout.take();
+                        $items
+                            .timeouts
+                            .insert(rule_key, format!("{:#?}", rule.$timeouts.take()));
+                    )?
+                }
+                $items.routes.insert(key, format!("{route:#?}"));
+            }
+        };
+    }
+
+    let mut items = Items::default();
+    let mut policy = policy.clone();
+    match policy.protocol.as_mut().and_then(|p| p.kind.as_mut()) {
+        Some(Kind::Detect(detect)) => {
+            if let Some(opaque) = detect.opaque.as_mut() {
+                routes!(items, "opaque", &mut opaque.routes);
+            }
+            // HTTP/1 and HTTP/2 are configured with the same routes.
+            if let Some(http) = detect.http1.as_mut() {
+                routes!(items, "http", &mut http.routes, timeouts);
+            }
+            if let Some(http) = detect.http2.as_mut() {
+                routes!(items, "http", &mut http.routes, timeouts);
+            }
+        }
+        Some(Kind::Opaque(opaque)) => routes!(items, "opaque", &mut opaque.routes),
+        Some(Kind::Http1(http)) => routes!(items, "http", &mut http.routes, timeouts),
+        Some(Kind::Http2(http)) => routes!(items, "http", &mut http.routes, timeouts),
+        Some(Kind::Grpc(grpc)) => routes!(items, "grpc", &mut grpc.routes, timeouts),
+        Some(Kind::Tls(tls)) => routes!(items, "tls", &mut tls.routes),
+        None => {}
+    }
+
+    items
+        .other
+        .insert("policy".to_string(), format!("{policy:#?}"));
+    items
+}
+
+fn meta_key(meta: &Option<Metadata>) -> String {
+    match meta.as_ref().and_then(|m| m.kind.as_ref()) {
+        Some(metadata::Kind::Default(name)) => format!("default:{name}"),
+        Some(metadata::Kind::Resource(r)) => {
+            let mut key = if r.namespace.is_empty() {
+                format!("{}/{} {}", r.group, r.kind, r.name)
+            } else {
+                format!("{}/{} {}/{}", r.group, r.kind, r.namespace, r.name)
+            };
+            if !r.section.is_empty() {
+                key.push_str(&format!(" section={}", r.section));
+            }
+            if r.port != 0 {
+                key.push_str(&format!(" port={}", r.port));
+            }
+            key
+        }
+        None => "unknown".to_string(),
+    }
+}
+
+// === impl Impact ===
+
+impl Impact {
+    pub fn is_empty(&self) -> bool {
+        self.inbound.is_empty() && self.outbound.is_empty()
+    }
+}
+
+impl fmt::Display for Impact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for impact in &self.inbound {
+            writeln!(f, "# inbound {}", impact.target)?;
+            impact.fmt_deltas(f)?;
+        }
+        for impact in &self.outbound {
+            writeln!(f, "# outbound {}", impact.target)?;
+            impact.fmt_deltas(f)?;
+        }
+        Ok(())
+    }
+}
+
+// === impl TargetImpact ===
+
+impl<T> TargetImpact<T> {
+    pub fn is_empty(&self) -> bool {
+        self.authorizations.is_empty()
+            && self.routes.is_empty()
+            && self.backends.is_empty()
+            && self.timeouts.is_empty()
+            && self.other.is_empty()
+    }
+
+    fn fmt_deltas(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (category, deltas) in [
+            ("authorizations", &self.authorizations),
+            ("routes", &self.routes),
+            ("backends", &self.backends),
+            ("timeouts", &self.timeouts),
+            ("other", &self.other),
+        ] {
+            if deltas.is_empty() {
+                continue;
+            }
+            writeln!(f, "{category}:")?;
+            for delta in deltas {
+                write!(f, "{delta}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// === impl Delta ===
+
+impl fmt::Display for Delta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match (&self.before, &self.after) {
+            (None, _) => '+',
+            (_, None) => '-',
+            _ => '~',
+        };
+        writeln!(f, "  {op} {}", self.key)?;
+        let before = self.before.as_deref().unwrap_or_default();
+        let after = self.after.as_deref().unwrap_or_default();
+        for line in line_diff(before, after).unwrap_or_default().lines() {
+            writeln!(f, "    {line}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+//! Evaluates policy resources offline.
+//!
+//! Resources are loaded from YAML manifests and fed into the same inbound and
+//! outbound indexes that back the policy controller's gRPC API, so that the
+//! policy served to proxies can be inspected (e.g. in CI) without a cluster.
+//! An [`Impact`] report describes how candidate changes would alter the
+//! policy of every pod port and Service port.
+
+#![deny(warnings, rust_2018_idioms)]
+#![forbid(unsafe_code)]
+
+mod impact;
+mod manifest;
+mod render;
+mod target;
+
+pub use self::{
+    impact::{Change, Delta, Impact, Snapshot, TargetImpact},
+    manifest::{Manifest, Resource},
+    render::{line_diff, render_inbound, render_outbound},
+    target::{InboundTarget, OutboundTarget},
+};
+
+use anyhow::{bail, Result};
+use kubert::index::IndexNamespacedResource;
+use linkerd2_proxy_api::{inbound, outbound};
+use linkerd_policy_controller_core::outbound::{Kind, OutboundDiscoverTarget, ResourceTarget};
+use linkerd_policy_controller_grpc as grpc;
+use linkerd_policy_controller_k8s_api::{
+    self as k8s, external_workload::ExternalWorkload, gateway, multicluster::ServiceImport, policy,
+};
+use linkerd_policy_controller_k8s_index::{self as index, ClusterInfo};
+use std::sync::Arc;
+
+/// Holds the inbound and outbound indexes for a set of resources.
+pub struct Evaluator {
+    cluster_info: Arc<ClusterInfo>,
+    inbound: index::inbound::SharedIndex,
+    outbound: index::outbound::SharedIndex,
+
+    /// The applied resources, in the order in which they were first applied.
+    resources: Vec<Resource>,
+}
+
+// === impl Evaluator ===
+
+impl Evaluator {
+    pub fn new(cluster_info: ClusterInfo) -> Self {
+        let cluster_info = Arc::new(cluster_info);
+        Self {
+            inbound: index::inbound::Index::shared(cluster_info.clone()),
+            outbound: index::outbound::Index::shared(cluster_info.clone()),
+            cluster_info,
+            resources: Vec::new(),
+        }
+    }
+
+    /// Returns a new evaluator with fresh indexes holding the same resources.
+    pub fn fork(&self) -> Self {
+        let mut fork = Self::new((*self.cluster_info).clone());
+        for resource in &self.resources {
+            fork.apply(resource.clone());
+        }
+        fork
+    }
+
+    /// Applies a resource to each index that watches its kind.
+    pub fn apply(&mut self, resource: Resource) {
+        {
+            let mut inbound = self.inbound.write();
+            let mut outbound = self.outbound.write();
+            match resource.clone() {
+                Resource::Pod(pod) => IndexNamespacedResource::apply(&mut *inbound, pod),
+                Resource::Service(svc) => IndexNamespacedResource::apply(&mut *outbound, svc),
+                Resource::EndpointSlice(slice) => {
+                    IndexNamespacedResource::apply(&mut *outbound, slice)
+                }
+                Resource::ServiceImport(import) => {
+                    IndexNamespacedResource::apply(&mut *outbound, import)
+                }
+                Resource::ExternalWorkload(workload) => {
+                    IndexNamespacedResource::apply(&mut *inbound, workload)
+                }
+                Resource::Server(server) => IndexNamespacedResource::apply(&mut *inbound, server),
+                Resource::ServerAuthorization(saz) => {
+                    IndexNamespacedResource::apply(&mut *inbound, saz)
+                }
+                Resource::AuthorizationPolicy(policy) => {
+                    IndexNamespacedResource::apply(&mut *inbound, policy)
+                }
+                Resource::MeshTLSAuthentication(authn) => {
+                    IndexNamespacedResource::apply(&mut *inbound, authn)
+                }
+                Resource::NetworkAuthentication(authn) => {
+                    IndexNamespacedResource::apply(&mut *inbound, authn)
+                }
+                Resource::HttpLocalRateLimitPolicy(policy) => {
+                    IndexNamespacedResource::apply(&mut *inbound, policy)
+                }
+                Resource::EgressNetwork(net) => IndexNamespacedResource::apply(&mut *outbound, net),
+                Resource::LinkerdHttpRoute(route) => {
+                    IndexNamespacedResource::apply(&mut *inbound, route.clone());
+                    IndexNamespacedResource::apply(&mut *outbound, route);
+                }
+                Resource::HttpRoute(route) => {
+                    IndexNamespacedResource::apply(&mut *inbound, route.clone());
+                    IndexNamespacedResource::apply(&mut *outbound, route);
+                }
+                Resource::GrpcRoute(route) => {
+                    IndexNamespacedResource::apply(&mut *inbound, route.clone());
+                    IndexNamespacedResource::apply(&mut *outbound, route);
+                }
+                Resource::TlsRoute(route) => IndexNamespacedResource::apply(&mut *outbound, route),
+                Resource::TcpRoute(route) => IndexNamespacedResource::apply(&mut *outbound, route),
+            }
+        }
+
+        let key = resource.key();
+        match self.resources.iter_mut().find(|r| r.key() == key) {
+            Some(existing) => *existing = resource,
+            None => self.resources.push(resource),
+        }
+    }
+
+    /// Deletes a resource from each index that watches its kind. Only the
+    /// resource's kind, namespace, and name are considered.
+    pub fn delete(&mut self, resource: &Resource) {
+        let key = resource.key();
+        self.resources.retain(|r| r.key() != key);
+        let (_, _, namespace, name) = key;
+        let namespace = namespace.unwrap_or_default();
+
+        let mut inbound = self.inbound.write();
+        let mut outbound = self.outbound.write();
+        macro_rules! delete {
+            ($index:expr, $ty:ty) => {
+                IndexNamespacedResource::<$ty>::delete(
+                    &mut *$index,
+                    namespace.clone(),
+                    name.clone(),
+                )
+            };
+        }
+        match resource {
+            Resource::Pod(_) => delete!(inbound, k8s::Pod),
+            Resource::Service(_) => delete!(outbound, k8s::Service),
+            Resource::EndpointSlice(_) => delete!(outbound, k8s::EndpointSlice),
+            Resource::ServiceImport(_) => delete!(outbound, ServiceImport),
+            Resource::ExternalWorkload(_) => delete!(inbound, ExternalWorkload),
+            Resource::Server(_) => delete!(inbound, policy::Server),
+            Resource::ServerAuthorization(_) => delete!(inbound, policy::ServerAuthorization),
+            Resource::AuthorizationPolicy(_) => delete!(inbound, policy::AuthorizationPolicy),
+            Resource::MeshTLSAuthentication(_) => delete!(inbound, policy::MeshTLSAuthentication),
+            Resource::NetworkAuthentication(_) => delete!(inbound, policy::NetworkAuthentication),
+            Resource::HttpLocalRateLimitPolicy(_) => {
+                delete!(inbound, policy::HttpLocalRateLimitPolicy)
+            }
+            Resource::EgressNetwork(_) => delete!(outbound, policy::EgressNetwork),
+            Resource::LinkerdHttpRoute(_) => {
+                delete!(inbound, policy::HttpRoute);
+                delete!(outbound, policy::HttpRoute);
+            }
+            Resource::HttpRoute(_) => {
+                delete!(inbound, gateway::HTTPRoute);
+                delete!(outbound, gateway::HTTPRoute);
+            }
+            Resource::GrpcRoute(_) => {
+                delete!(inbound, gateway::GRPCRoute);
+                delete!(outbound, gateway::GRPCRoute);
+            }
+            Resource::TlsRoute(_) => delete!(outbound, gateway::TLSRoute),
+            Resource::TcpRoute(_) => delete!(outbound, gateway::TCPRoute),
+        }
+    }
+
+    /// Returns the server that would be served to a pod's proxy for a port.
+    pub fn inbound(&self, target: &InboundTarget) -> Result<inbound::Server> {
+        let rx = self
+            .inbound
+            .write()
+            .pod_server_rx(&target.namespace, &target.pod, target.port)?;
+        let server = rx.borrow().clone();
+        Ok(grpc::inbound::to_server(
+            &server,
+            &self.cluster_info.networks,
+        ))
+    }
+
+    /// Returns the policy that would be served to a proxy for an outbound
+    /// target.
+    pub fn outbound(&self, target: &OutboundTarget) -> Result<outbound::OutboundPolicy> {
+        let mut index = self.outbound.write();
+        match target {
+            OutboundTarget::Service {
+                namespace,
+                name,
+                port,
+                source_namespace,
+            } => {
+                let policy = index.service_policy(namespace, name, *port, source_namespace)?;
+                Ok(grpc::outbound::to_proto(policy, false, None))
+            }
+
+            OutboundTarget::Addr {
+                addr,
+                source_namespace,
+            } => {
+                let port = match (addr.port()).try_into() {
+                    Ok(port) => port,
+                    Err(_) => bail!("port must not be zero"),
+                };
+                let target = match index.lookup_service(addr.ip(), port, source_namespace.clone()) {
+                    Some(OutboundDiscoverTarget::Resource(target)) => target,
+                    Some(OutboundDiscoverTarget::UndefinedPort(_)) => {
+                        bail!("{addr} is not a port of its Service")
+                    }
+                    Some(OutboundDiscoverTarget::External(_)) | None => {
+                        match index.lookup_egress_network(addr.ip(), source_namespace.clone()) {
+                            Some((namespace, name)) => ResourceTarget {
+                                name,
+                                namespace,
+                                port,
+                                source_namespace: source_namespace.clone(),
+                                kind: Kind::EgressNetwork(*addr),
+                            },
+                            None => bail!("{addr} does not match a Service or EgressNetwork"),
+                        }
+                    }
+                };
+                let original_dst = target.original_dst();
+                let policy = index.outbound_policy_rx(target)?.borrow().clone();
+                Ok(grpc::outbound::to_proto(policy, false, original_dst))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
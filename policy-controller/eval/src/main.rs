@@ -0,0 +1,190 @@
+#![deny(warnings, rust_2018_idioms)]
+#![forbid(unsafe_code)]
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use ipnet::IpNet;
+use linkerd_policy_controller_eval::{
+    line_diff, render_inbound, render_outbound, Change, Evaluator, InboundTarget, Manifest,
+    OutboundTarget,
+};
+use linkerd_policy_controller_k8s_index::{ports::parse_portset, ClusterInfo, DefaultPolicy};
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+/// Loads policy resources from YAML manifests and prints the policy that the
+/// controller would serve for each target.
+#[derive(Debug, Parser)]
+#[clap(name = "linkerd-policy-eval", about, version)]
+struct Args {
+    /// Manifests to load. Directories are searched (non-recursively) for
+    /// `.yaml` and `.yml` files.
+    #[clap(short = 'f', long = "filename", required = true)]
+    files: Vec<PathBuf>,
+
+    /// Pod ports for which to print inbound policy, as
+    /// `<namespace>/<pod>:<port>`.
+    #[clap(long)]
+    inbound: Vec<InboundTarget>,
+
+    /// Targets for which to print outbound policy, as
+    /// `<namespace>/<service>:<port>` or `<ip>:<port>`, optionally followed by
+    /// `@<source-namespace>`.
+    #[clap(long)]
+    outbound: Vec<OutboundTarget>,
+
+    /// Candidate manifests to apply. Instead of printing policy for specific
+    /// targets, a report is printed describing how policy changes for every
+    /// pod port and Service port.
+    #[clap(long, conflicts_with_all = ["inbound", "outbound"])]
+    apply: Vec<PathBuf>,
+
+    /// Candidate manifests whose resources are deleted. Like `--apply`, this
+    /// prints a report of how policy changes.
+    #[clap(long, conflicts_with_all = ["inbound", "outbound"])]
+    delete: Vec<PathBuf>,
+
+    /// Compares the output to the contents of this file. When they differ,
+    /// a diff is printed and the command fails.
+    #[clap(long)]
+    expect: Option<PathBuf>,
+
+    /// Network CIDRs of pod IPs.
+    #[clap(
+        long,
+        value_delimiter = ',',
+        default_value = "10.0.0.0/8,100.64.0.0/10,172.16.0.0/12,192.168.0.0/16"
+    )]
+    cluster_networks: Vec<IpNet>,
+
+    #[clap(long, default_value = "cluster.local")]
+    identity_domain: String,
+
+    #[clap(long, default_value = "cluster.local")]
+    cluster_domain: String,
+
+    #[clap(long, default_value = "all-unauthenticated")]
+    default_policy: DefaultPolicy,
+
+    #[clap(long, default_value = "linkerd")]
+    control_plane_namespace: String,
+
+    /// Network CIDRs of all expected probes.
+    #[clap(long, value_delimiter = ',')]
+    probe_networks: Vec<IpNet>,
+
+    #[clap(long, default_value = "")]
+    default_opaque_ports: String,
+
+    #[clap(long, default_value = "linkerd-egress")]
+    global_egress_network_namespace: String,
+}
+
+fn main() -> Result<()> {
+    let Args {
+        files,
+        inbound,
+        outbound,
+        apply,
+        delete,
+        expect,
+        cluster_networks,
+        identity_domain,
+        cluster_domain,
+        default_policy,
+        control_plane_namespace,
+        probe_networks,
+        default_opaque_ports,
+        global_egress_network_namespace,
+    } = Args::parse();
+
+    let changes = !apply.is_empty() || !delete.is_empty();
+    if !changes && inbound.is_empty() && outbound.is_empty() {
+        bail!("at least one --inbound, --outbound, --apply, or --delete must be specified");
+    }
+
+    let mut evaluator = Evaluator::new(ClusterInfo {
+        networks: cluster_networks,
+        control_plane_ns: control_plane_namespace,
+        dns_domain: cluster_domain,
+        identity_domain,
+        default_policy,
+        default_detect_timeout: Duration::from_secs(10),
+        default_opaque_ports: parse_portset(&default_opaque_ports)?,
+        probe_networks,
+        global_egress_network_namespace: Arc::new(global_egress_network_namespace),
+        namespace_scope: Default::default(),
+    });
+
+    let manifest = load(&files)?;
+    for skipped in &manifest.skipped {
+        eprintln!("skipping {skipped}");
+    }
+    for resource in manifest.resources {
+        evaluator.apply(resource);
+    }
+
+    let mut output = String::new();
+    if changes {
+        let (apply, delete) = (load(&apply)?, load(&delete)?);
+        for skipped in &apply.skipped {
+            eprintln!("skipping {skipped}");
+        }
+        let candidate = apply
+            .resources
+            .into_iter()
+            .map(Change::Apply)
+            .chain(delete.resources.into_iter().map(Change::Delete));
+        let impact = evaluator.impact(candidate)?;
+        output.push_str(&impact.to_string());
+    }
+    for target in &inbound {
+        let server = evaluator
+            .inbound(target)
+            .with_context(|| format!("inbound {target}"))?;
+        output.push_str(&format!("# inbound {target}\n{}", render_inbound(server)));
+    }
+    for target in &outbound {
+        let policy = evaluator
+            .outbound(target)
+            .with_context(|| format!("outbound {target}"))?;
+        output.push_str(&format!("# outbound {target}\n{}", render_outbound(policy)));
+    }
+
+    match expect {
+        None => print!("{output}"),
+        Some(path) => {
+            let expected = std::fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            if let Some(diff) = line_diff(&expected, &output) {
+                print!("{diff}");
+                bail!("policy differs from {}", path.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn load(paths: &[PathBuf]) -> Result<Manifest> {
+    let mut manifest = Manifest::default();
+    for path in paths {
+        let files = if path.is_dir() {
+            let mut files = std::fs::read_dir(path)?
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<std::io::Result<Vec<_>>>()?;
+            files
+                .retain(|p| matches!(p.extension().and_then(|e| e.to_str()), Some("yaml" | "yml")));
+            files.sort();
+            files
+        } else {
+            vec![path.clone()]
+        };
+        for file in files {
+            let yaml = std::fs::read_to_string(&file)
+                .with_context(|| format!("failed to read {}", file.display()))?;
+            let parsed = Manifest::parse(&yaml)
+                .with_context(|| format!("failed to parse {}", file.display()))?;
+            manifest.extend(parsed);
+        }
+    }
+    Ok(manifest)
+}
@@ -0,0 +1,229 @@
+use anyhow::{bail, Context, Result};
+use linkerd_policy_controller_core::POLICY_CONTROLLER_NAME;
+use linkerd_policy_controller_k8s_api::{
+    self as k8s, external_workload::ExternalWorkload, gateway, multicluster::ServiceImport, policy,
+    Resource as _,
+};
+use serde::Deserialize;
+use serde_yaml::{Mapping, Value};
+
+/// A resource loaded from a manifest.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum Resource {
+    Pod(k8s::Pod),
+    Service(k8s::Service),
+    EndpointSlice(k8s::EndpointSlice),
+    ServiceImport(ServiceImport),
+    ExternalWorkload(ExternalWorkload),
+    Server(policy::Server),
+    ServerAuthorization(policy::ServerAuthorization),
+    AuthorizationPolicy(policy::AuthorizationPolicy),
+    MeshTLSAuthentication(policy::MeshTLSAuthentication),
+    NetworkAuthentication(policy::NetworkAuthentication),
+    HttpLocalRateLimitPolicy(policy::HttpLocalRateLimitPolicy),
+    EgressNetwork(policy::EgressNetwork),
+    LinkerdHttpRoute(policy::HttpRoute),
+    HttpRoute(gateway::HTTPRoute),
+    GrpcRoute(gateway::GRPCRoute),
+    TlsRoute(gateway::TLSRoute),
+    TcpRoute(gateway::TCPRoute),
+}
+
+/// The resources parsed from one or more YAML documents.
+#[derive(Clone, Debug, Default)]
+pub struct Manifest {
+    pub resources: Vec<Resource>,
+
+    /// Describes documents whose kind is not indexed by the policy
+    /// controller (e.g. Deployments).
+    pub skipped: Vec<String>,
+}
+
+/// Identifies a resource by its group, kind, namespace, and name.
+pub(crate) type Key = (String, String, Option<String>, String);
+
+// === impl Resource ===
+
+impl Resource {
+    pub(crate) fn key(&self) -> Key {
+        fn key<R: k8s::Resource<DynamicType = ()>>(resource: &R) -> Key {
+            (
+                R::group(&()).into_owned(),
+                R::kind(&()).into_owned(),
+                resource.meta().namespace.clone(),
+                resource.meta().name.clone().unwrap_or_default(),
+            )
+        }
+        match self {
+            Self::Pod(r) => key(r),
+            Self::Service(r) => key(r),
+            Self::EndpointSlice(r) => key(r),
+            Self::ServiceImport(r) => key(r),
+            Self::ExternalWorkload(r) => key(r),
+            Self::Server(r) => key(r),
+            Self::ServerAuthorization(r) => key(r),
+            Self::AuthorizationPolicy(r) => key(r),
+            Self::MeshTLSAuthentication(r) => key(r),
+            Self::NetworkAuthentication(r) => key(r),
+            Self::HttpLocalRateLimitPolicy(r) => key(r),
+            Self::EgressNetwork(r) => key(r),
+            Self::LinkerdHttpRoute(r) => key(r),
+            Self::HttpRoute(r) => key(r),
+            Self::GrpcRoute(r) => key(r),
+            Self::TlsRoute(r) => key(r),
+            Self::TcpRoute(r) => key(r),
+        }
+    }
+}
+
+// === impl Manifest ===
+
+impl Manifest {
+    /// Parses a stream of YAML documents. `List` documents are flattened.
+    ///
+    /// The policy controller only considers routes and rate limit policies
+    /// once the status controller has accepted them. Resources of these kinds
+    /// without a status are assumed to be accepted by all of their parents; set
+    /// a status explicitly to model a resource that was not accepted.
+    pub fn parse(yaml: &str) -> Result<Self> {
+        let mut manifest = Self::default();
+        for doc in serde_yaml::Deserializer::from_str(yaml) {
+            let value = Value::deserialize(doc)?;
+            manifest.push(value)?;
+        }
+        Ok(manifest)
+    }
+
+    pub fn extend(&mut self, other: Self) {
+        self.resources.extend(other.resources);
+        self.skipped.extend(other.skipped);
+    }
+
+    fn push(&mut self, mut value: Value) -> Result<()> {
+        if value.is_null() {
+            return Ok(());
+        }
+
+        let api_version = value
+            .get("apiVersion")
+            .and_then(Value::as_str)
+            .context("document must have an apiVersion")?
+            .to_string();
+        let kind = value
+            .get("kind")
+            .and_then(Value::as_str)
+            .context("document must have a kind")?
+            .to_string();
+        let group = api_version
+            .rsplit_once('/')
+            .map(|(group, _)| group)
+            .unwrap_or_default();
+
+        if kind == "List" {
+            if let Some(Value::Sequence(items)) = value.get_mut("items").map(std::mem::take) {
+                for item in items {
+                    self.push(item)?;
+                }
+            }
+            return Ok(());
+        }
+
+        let name = value
+            .get("metadata")
+            .and_then(|m| m.get("name"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        macro_rules! parse {
+            ($($variant:ident($ty:ty)),+ $(,)?) => {
+                $(
+                    if group == <$ty>::group(&()) && kind == <$ty>::kind(&()) {
+                        if is_route_like::<$ty>() {
+                            assume_accepted(&mut value);
+                        }
+                        let resource = serde_yaml::from_value(value)
+                            .with_context(|| format!("invalid {kind} {name}"))?;
+                        self.resources.push(Resource::$variant(resource));
+                        return Ok(());
+                    }
+                )+
+            };
+        }
+
+        parse!(
+            Pod(k8s::Pod),
+            Service(k8s::Service),
+            EndpointSlice(k8s::EndpointSlice),
+            ServiceImport(ServiceImport),
+            ExternalWorkload(ExternalWorkload),
+            Server(policy::Server),
+            ServerAuthorization(policy::ServerAuthorization),
+            AuthorizationPolicy(policy::AuthorizationPolicy),
+            MeshTLSAuthentication(policy::MeshTLSAuthentication),
+            NetworkAuthentication(policy::NetworkAuthentication),
+            HttpLocalRateLimitPolicy(policy::HttpLocalRateLimitPolicy),
+            EgressNetwork(policy::EgressNetwork),
+            LinkerdHttpRoute(policy::HttpRoute),
+            HttpRoute(gateway::HTTPRoute),
+            GrpcRoute(gateway::GRPCRoute),
+            TlsRoute(gateway::TLSRoute),
+            TcpRoute(gateway::TCPRoute),
+        );
+
+        if kind.is_empty() {
+            bail!("document must have a kind");
+        }
+        self.skipped.push(format!("{api_version} {kind} {name}"));
+        Ok(())
+    }
+}
+
+/// Indicates whether a resource kind is only indexed once it has been accepted
+/// by the status controller.
+fn is_route_like<R: k8s::Resource<DynamicType = ()>>() -> bool {
+    matches!(
+        &*R::kind(&()),
+        "HTTPRoute" | "GRPCRoute" | "TLSRoute" | "TCPRoute" | "HTTPLocalRateLimitPolicy"
+    )
+}
+
+/// Sets an `Accepted` status on a route (for each of its parents) or rate
+/// limit policy (for its target) that does not have a status.
+fn assume_accepted(value: &mut Value) {
+    let Some(resource) = value.as_mapping_mut() else {
+        return;
+    };
+    if resource.contains_key("status") {
+        return;
+    }
+    let spec = resource.get("spec").cloned().unwrap_or_default();
+
+    let accepted = Value::Sequence(vec![serde_yaml::from_str(
+        "{type: Accepted, status: 'True', reason: Accepted, message: '', lastTransitionTime: '1970-01-01T00:00:00Z'}",
+    )
+    .expect("condition must be valid")]);
+
+    let mut status = Mapping::new();
+    if let Some(target_ref) = spec.get("targetRef") {
+        status.insert("targetRef".into(), target_ref.clone());
+        status.insert("conditions".into(), accepted);
+    } else {
+        let parents = spec
+            .get("parentRefs")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .map(|parent_ref| {
+                let mut parent = Mapping::new();
+                parent.insert("parentRef".into(), parent_ref.clone());
+                parent.insert("controllerName".into(), POLICY_CONTROLLER_NAME.into());
+                parent.insert("conditions".into(), accepted.clone());
+                Value::Mapping(parent)
+            })
+            .collect();
+        status.insert("parents".into(), Value::Sequence(parents));
+    }
+    resource.insert("status".into(), Value::Mapping(status));
+}
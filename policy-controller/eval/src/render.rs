@@ -0,0 +1,87 @@
+use linkerd2_proxy_api::{inbound, outbound};
+use std::collections::BTreeMap;
+
+/// Renders an inbound server as text.
+///
+/// The controller builds authorizations from hash maps, so their order (and
+/// the order of the map-typed `labels` fields) is not stable across runs.
+/// Authorizations are sorted by their metadata and server labels are printed
+/// separately in sorted order. Authorization labels duplicate the
+/// authorization's metadata and are omitted.
+pub fn render_inbound(mut server: inbound::Server) -> String {
+    use inbound::proxy_protocol::Kind;
+
+    fn normalize(authzs: &mut [inbound::Authz]) {
+        for authz in authzs.iter_mut() {
+            authz.labels.clear();
+        }
+        authzs.sort_by_cached_key(|authz| format!("{:?}", authz.metadata));
+    }
+
+    normalize(&mut server.authorizations);
+    let http_routes = match server.protocol.as_mut().and_then(|p| p.kind.as_mut()) {
+        Some(Kind::Detect(detect)) => Some(&mut detect.http_routes),
+        Some(Kind::Http1(http)) => Some(&mut http.routes),
+        Some(Kind::Http2(http)) => Some(&mut http.routes),
+        _ => None,
+    };
+    for route in http_routes.into_iter().flatten() {
+        normalize(&mut route.authorizations);
+    }
+    if let Some(Kind::Grpc(grpc)) = server.protocol.as_mut().and_then(|p| p.kind.as_mut()) {
+        for route in grpc.routes.iter_mut() {
+            normalize(&mut route.authorizations);
+        }
+    }
+
+    let labels = std::mem::take(&mut server.labels)
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+    format!("labels: {labels:?}\n{server:#?}\n")
+}
+
+/// Renders an outbound policy as text.
+pub fn render_outbound(policy: outbound::OutboundPolicy) -> String {
+    format!("{policy:#?}\n")
+}
+
+/// Returns a line-oriented diff from `expected` to `actual`, or `None` if they
+/// are identical. Removed lines are prefixed with `-` and added lines with `+`.
+pub fn line_diff(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    let a = expected.lines().collect::<Vec<_>>();
+    let b = actual.lines().collect::<Vec<_>>();
+
+    // Longest common subsequence table, filled from the end so that the diff
+    // can be emitted front-to-back.
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            diff.push_str(&format!(" {}\n", a[i]));
+            i += 1;
+            j += 1;
+        } else if j < b.len() && (i == a.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            diff.push_str(&format!("+{}\n", b[j]));
+            j += 1;
+        } else {
+            diff.push_str(&format!("-{}\n", a[i]));
+            i += 1;
+        }
+    }
+    Some(diff)
+}
@@ -0,0 +1,106 @@
+use anyhow::{anyhow, Context, Error, Result};
+use std::{fmt, net::SocketAddr, num::NonZeroU16, str::FromStr};
+
+/// A pod port, formatted as `<namespace>/<pod>:<port>`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InboundTarget {
+    pub namespace: String,
+    pub pod: String,
+    pub port: NonZeroU16,
+}
+
+/// An outbound target, formatted as `<namespace>/<service>:<port>` or
+/// `<ip>:<port>`, optionally followed by `@<source-namespace>`.
+///
+/// Service targets default to a source namespace equal to the Service's.
+/// Address targets default to the `default` namespace.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OutboundTarget {
+    Service {
+        namespace: String,
+        name: String,
+        port: NonZeroU16,
+        source_namespace: String,
+    },
+    Addr {
+        addr: SocketAddr,
+        source_namespace: String,
+    },
+}
+
+// === impl InboundTarget ===
+
+impl FromStr for InboundTarget {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (namespace, pod, port) = parse_namespaced_port(s)?;
+        Ok(Self {
+            namespace: namespace.to_string(),
+            pod: pod.to_string(),
+            port,
+        })
+    }
+}
+
+impl fmt::Display for InboundTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}:{}", self.namespace, self.pod, self.port)
+    }
+}
+
+// === impl OutboundTarget ===
+
+impl FromStr for OutboundTarget {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (target, source_namespace) = match s.split_once('@') {
+            Some((target, source)) => (target, Some(source.to_string())),
+            None => (s, None),
+        };
+
+        if let Ok(addr) = target.parse::<SocketAddr>() {
+            return Ok(Self::Addr {
+                addr,
+                source_namespace: source_namespace.unwrap_or_else(|| "default".to_string()),
+            });
+        }
+
+        let (namespace, name, port) = parse_namespaced_port(target)?;
+        Ok(Self::Service {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            port,
+            source_namespace: source_namespace.unwrap_or_else(|| namespace.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for OutboundTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Service {
+                namespace,
+                name,
+                port,
+                source_namespace,
+            } => write!(f, "{namespace}/{name}:{port}@{source_namespace}"),
+            Self::Addr {
+                addr,
+                source_namespace,
+            } => write!(f, "{addr}@{source_namespace}"),
+        }
+    }
+}
+
+fn parse_namespaced_port(s: &str) -> Result<(&str, &str, NonZeroU16)> {
+    let (namespace, name_port) = s
+        .split_once('/')
+        .ok_or_else(|| anyhow!("{s}: expected <namespace>/<name>:<port>"))?;
+    let (name, port) = name_port
+        .split_once(':')
+        .ok_or_else(|| anyhow!("{s}: expected <namespace>/<name>:<port>"))?;
+    let port = port.parse().with_context(|| format!("{s}: invalid port"))?;
+    Ok((namespace, name, port))
+}
@@ -0,0 +1,222 @@
+use super::*;
+use linkerd_policy_controller_k8s_index::DefaultPolicy;
+use std::time::Duration;
+
+const MANIFEST: &str = r#"
+apiVersion: v1
+kind: Pod
+metadata:
+  namespace: ns-0
+  name: pod-0
+  labels:
+    app: web
+spec:
+  containers:
+  - name: web
+    ports:
+    - name: http
+      containerPort: 8080
+---
+apiVersion: v1
+kind: Service
+metadata:
+  namespace: ns-0
+  name: web
+spec:
+  clusterIP: 10.96.0.10
+  clusterIPs:
+  - 10.96.0.10
+  ports:
+  - port: 80
+---
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  namespace: ns-0
+  name: web
+---
+apiVersion: policy.linkerd.io/v1beta3
+kind: Server
+metadata:
+  namespace: ns-0
+  name: web-http
+spec:
+  podSelector:
+    matchLabels:
+      app: web
+  port: http
+  proxyProtocol: HTTP/1
+---
+apiVersion: policy.linkerd.io/v1alpha1
+kind: AuthorizationPolicy
+metadata:
+  namespace: ns-0
+  name: web-authz
+spec:
+  targetRef:
+    group: policy.linkerd.io
+    kind: Server
+    name: web-http
+  requiredAuthenticationRefs:
+  - kind: ServiceAccount
+    name: client
+---
+apiVersion: gateway.networking.k8s.io/v1
+kind: HTTPRoute
+metadata:
+  namespace: ns-0
+  name: web-route
+spec:
+  parentRefs:
+  - group: core
+    kind: Service
+    name: web
+    port: 80
+  rules:
+  - matches:
+    - path:
+        type: PathPrefix
+        value: /api
+"#;
+
+fn mk_evaluator() -> Evaluator {
+    Evaluator::new(ClusterInfo {
+        networks: vec!["10.0.0.0/8".parse().unwrap()],
+        control_plane_ns: "linkerd".to_string(),
+        dns_domain: "cluster.local".to_string(),
+        identity_domain: "cluster.local".to_string(),
+        default_policy: DefaultPolicy::Deny,
+        default_detect_timeout: Duration::from_secs(10),
+        default_opaque_ports: Default::default(),
+        probe_networks: vec![],
+        global_egress_network_namespace: Arc::new("linkerd-egress".to_string()),
+        namespace_scope: Default::default(),
+    })
+}
+
+#[test]
+fn parses_manifests() {
+    let manifest = Manifest::parse(MANIFEST).expect("manifest must parse");
+    assert_eq!(manifest.resources.len(), 5);
+    assert_eq!(manifest.skipped, vec!["apps/v1 Deployment web".to_string()]);
+
+    // Routes without a status are assumed to be accepted by their parents.
+    let route = manifest
+        .resources
+        .iter()
+        .find_map(|r| match r {
+            Resource::HttpRoute(route) => Some(route),
+            _ => None,
+        })
+        .expect("route must be parsed");
+    let parents = &route.status.as_ref().expect("status must be set").parents;
+    assert_eq!(parents.len(), 1);
+    assert_eq!(parents[0].parent_ref.name, "web");
+}
+
+#[test]
+fn evaluates_inbound_and_outbound() {
+    let mut evaluator = mk_evaluator();
+    for resource in Manifest::parse(MANIFEST).unwrap().resources {
+        evaluator.apply(resource);
+    }
+
+    let server = evaluator
+        .inbound(&"ns-0/pod-0:8080".parse().unwrap())
+        .expect("pod must exist");
+    assert_eq!(server.labels["name"], "web-http");
+    assert_eq!(server.authorizations.len(), 1);
+    assert!(render_inbound(server).starts_with("labels: {\"group\": \"policy.linkerd.io\""));
+
+    let policy = evaluator
+        .outbound(&"ns-0/web:80".parse().unwrap())
+        .expect("service must exist");
+    let by_ip = evaluator
+        .outbound(&"10.96.0.10:80@ns-0".parse().unwrap())
+        .expect("service must be found by its cluster IP");
+    assert_eq!(policy, by_ip);
+    assert!(render_outbound(policy).contains("web-route"));
+
+    assert!(evaluator
+        .outbound(&"ns-0/missing:80".parse().unwrap())
+        .is_err());
+}
+
+#[test]
+fn diffs_lines() {
+    assert_eq!(line_diff("a\nb\n", "a\nb\n"), None);
+    assert_eq!(
+        line_diff("a\nb\nc\n", "a\nc\nd\n").unwrap(),
+        " a\n-b\n c\n+d\n"
+    );
+}
+
+#[test]
+fn reports_impact() {
+    let mut evaluator = mk_evaluator();
+    let resources = Manifest::parse(MANIFEST).unwrap().resources;
+    let authz = resources
+        .iter()
+        .find(|r| matches!(r, Resource::AuthorizationPolicy(_)))
+        .cloned()
+        .unwrap();
+    for resource in resources {
+        evaluator.apply(resource);
+    }
+
+    let route = Manifest::parse(
+        r#"
+apiVersion: gateway.networking.k8s.io/v1
+kind: HTTPRoute
+metadata:
+  namespace: ns-0
+  name: web-route
+spec:
+  parentRefs:
+  - group: core
+    kind: Service
+    name: web
+    port: 80
+  rules:
+  - matches:
+    - path:
+        type: PathPrefix
+        value: /api
+    timeouts:
+      request: 10s
+"#,
+    )
+    .unwrap()
+    .resources
+    .pop()
+    .unwrap();
+
+    let impact = evaluator
+        .impact([Change::Delete(authz), Change::Apply(route)])
+        .expect("impact must be computed");
+
+    assert_eq!(impact.inbound.len(), 1);
+    let inbound = &impact.inbound[0];
+    assert_eq!(inbound.target.to_string(), "ns-0/pod-0:8080");
+    assert_eq!(inbound.authorizations.len(), 1);
+    assert_eq!(
+        inbound.authorizations[0].key,
+        "policy.linkerd.io/authorizationpolicy web-authz"
+    );
+    assert!(inbound.authorizations[0].after.is_none());
+    assert!(inbound.routes.is_empty());
+
+    assert_eq!(impact.outbound.len(), 1);
+    let outbound = &impact.outbound[0];
+    assert_eq!(outbound.target.to_string(), "ns-0/web:80@ns-0");
+    assert!(outbound.authorizations.is_empty());
+    assert!(outbound.backends.is_empty());
+    assert_eq!(outbound.timeouts.len(), 1);
+    assert_eq!(
+        outbound.timeouts[0].key,
+        "http gateway.networking.k8s.io/HTTPRoute ns-0/web-route rule 0"
+    );
+
+    // The evaluator itself is unchanged.
+    assert!(evaluator.impact([]).unwrap().is_empty());
+}
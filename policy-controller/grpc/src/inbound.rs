@@ -1,6 +1,7 @@
 use crate::{
-    metrics::{self, GrpcServerMetricsFamily, GrpcServerRPCMetrics},
-    workload::Workload,
+    metrics::{self, GrpcServerMetricsFamily, GrpcServerRPCMetrics, TargetKind},
+    watch,
+    workload::{self, ClientVerifier, Workload},
 };
 use futures::prelude::*;
 use linkerd2_proxy_api::{
@@ -19,7 +20,7 @@ use linkerd_policy_controller_core::{
     IdentityMatch, IpNet, NetworkMatch,
 };
 use maplit::*;
-use std::{num::NonZeroU16, str::FromStr, sync::Arc};
+use std::{num::NonZeroU16, str::FromStr, sync::Arc, time};
 use tracing::trace;
 
 mod grpc;
@@ -30,6 +31,8 @@ pub struct InboundPolicyServer<T> {
     discover: T,
     drain: drain::Watch,
     cluster_networks: Arc<[IpNet]>,
+    update_debounce: time::Duration,
+    verifier: ClientVerifier,
     get_metrics: GrpcServerRPCMetrics,
     watch_metrics: GrpcServerRPCMetrics,
 }
@@ -43,6 +46,8 @@ where
     pub fn new(
         discover: T,
         cluster_networks: Vec<IpNet>,
+        update_debounce: time::Duration,
+        verifier: ClientVerifier,
         drain: drain::Watch,
         metrics: GrpcServerMetricsFamily,
     ) -> Self {
@@ -54,6 +59,8 @@ where
             discover,
             drain,
             cluster_networks: cluster_networks.into(),
+            update_debounce,
+            verifier,
             get_metrics,
             watch_metrics,
         }
@@ -65,9 +72,11 @@ where
 
     fn check_target(
         &self,
-        proto::PortSpec { workload, port }: proto::PortSpec,
+        req: tonic::Request<proto::PortSpec>,
     ) -> Result<(Workload, NonZeroU16), tonic::Status> {
+        let (metadata, extensions, proto::PortSpec { workload, port }) = req.into_parts();
         let workload = Workload::from_str(&workload)?;
+        self.verifier.verify(&metadata, &extensions, &workload)?;
         // Ensure that the port is in the valid range.
         let port = u16::try_from(port)
             .and_then(NonZeroU16::try_from)
@@ -87,7 +96,7 @@ where
         req: tonic::Request<proto::PortSpec>,
     ) -> Result<tonic::Response<proto::Server>, tonic::Status> {
         let metrics = self.get_metrics.start();
-        let target = match self.check_target(req.into_inner()) {
+        let target = match self.check_target(req) {
             Ok(target) => target,
             Err(status) => {
                 metrics.end(status.code());
@@ -119,8 +128,8 @@ where
         &self,
         req: tonic::Request<proto::PortSpec>,
     ) -> Result<tonic::Response<BoxWatchStream>, tonic::Status> {
-        let metrics = self.watch_metrics.start();
-        let target = match self.check_target(req.into_inner()) {
+        let mut metrics = self.watch_metrics.start();
+        let target = match self.check_target(req) {
             Ok(target) => target,
             Err(status) => {
                 metrics.end(status.code());
@@ -128,10 +137,24 @@ where
             }
         };
 
+        let (Workload { namespace, kind }, _) = &target;
+        let target_kind = match kind {
+            workload::Kind::Pod(_) => TargetKind::Pod,
+            workload::Kind::External(_) => TargetKind::ExternalWorkload,
+        };
+        let namespace = namespace.clone();
+
         let drain = self.drain.clone();
         match self.discover.watch_inbound_server(target).await {
             Ok(Some(rx)) => {
-                let stream = response_stream(drain, rx, self.cluster_networks.clone(), metrics);
+                metrics.watching(target_kind, &namespace);
+                let stream = response_stream(
+                    drain,
+                    rx,
+                    self.cluster_networks.clone(),
+                    self.update_debounce,
+                    metrics,
+                );
                 Ok(tonic::Response::new(stream))
             }
             Ok(None) => {
@@ -153,22 +176,34 @@ type BoxWatchStream =
 
 fn response_stream(
     drain: drain::Watch,
-    mut rx: InboundServerStream,
+    rx: InboundServerStream,
     cluster_networks: Arc<[IpNet]>,
-    metrics: metrics::ResponseObserver,
+    update_debounce: time::Duration,
+    mut metrics: metrics::ResponseObserver,
 ) -> BoxWatchStream {
     Box::pin(async_stream::try_stream! {
         tokio::pin! {
             let shutdown = drain.signaled();
+            let updates = watch::coalesce(rx, update_debounce);
         }
 
+        // The last server sent on the stream. Index updates frequently don't
+        // change a given server's policy, so identical messages are not sent.
+        let mut last = None;
         loop {
             tokio::select! {
                 // When the port is updated with a new server, update the server watch.
-                res = rx.next() => match res {
-                    Some(s) => {
+                res = updates.next() => match res {
+                    Some((s, coalesced)) => {
+                        metrics.msg_coalesced(coalesced);
+                        let server = to_server(&s, &cluster_networks);
+                        if last.as_ref() == Some(&server) {
+                            metrics.msg_suppressed();
+                            continue;
+                        }
+                        last = Some(server.clone());
                         metrics.msg_sent();
-                        yield to_server(&s, &cluster_networks);
+                        yield server;
                     }
                     None => break,
                 },
@@ -183,7 +218,8 @@ fn response_stream(
     })
 }
 
-fn to_server(srv: &InboundServer, cluster_networks: &[IpNet]) -> proto::Server {
+/// Converts an `InboundServer` into the protobuf returned to proxies.
+pub fn to_server(srv: &InboundServer, cluster_networks: &[IpNet]) -> proto::Server {
     // Convert the protocol object into a protobuf response.
     let protocol = proto::ProxyProtocol {
         kind: match srv.protocol {
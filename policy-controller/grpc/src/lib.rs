@@ -3,6 +3,7 @@
 #![forbid(unsafe_code)]
 
 mod routes;
+mod watch;
 
 pub mod inbound;
 pub mod metrics;
@@ -1,11 +1,20 @@
 use prometheus_client::encoding::EncodeLabelSet;
 use prometheus_client::metrics::histogram::Histogram;
 use prometheus_client::{
-    metrics::{counter::Counter, family::Family},
+    metrics::{counter::Counter, family::Family, gauge::Gauge},
     registry::Registry,
 };
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
 use tokio::time;
 
+/// The namespace label used once the number of distinct namespaces reaches
+/// the configured limit. Underscores are not valid in namespace names, so this
+/// can't collide with a real namespace.
+const OTHER_NAMESPACE: &str = "_other";
+
 #[derive(Clone, Debug)]
 pub struct GrpcServerMetricsFamily {
     started: Family<Labels, Counter>,
@@ -13,6 +22,36 @@ pub struct GrpcServerMetricsFamily {
     handled: Family<CodeLabels, Counter>,
     msg_received: Family<Labels, Counter>,
     msg_sent: Family<Labels, Counter>,
+    msg_suppressed: Family<Labels, Counter>,
+    msg_coalesced: Family<Labels, Counter>,
+    watches: WatchMetricsFamily,
+}
+
+/// Metrics for policy watches, broken down by the kind of target being
+/// watched and by the namespace of the watching workload.
+#[derive(Clone, Debug)]
+struct WatchMetricsFamily {
+    active: Family<WatchLabels, Gauge>,
+    msg_sent: Family<WatchLabels, Counter>,
+    first_response: Family<TargetKindLabels, Histogram>,
+    namespaces: NamespaceLimit,
+}
+
+/// Limits the number of distinct namespace label values.
+#[derive(Clone, Debug, Default)]
+struct NamespaceLimit {
+    max: Option<usize>,
+    seen: Arc<Mutex<HashSet<String>>>,
+}
+
+/// The kind of target watched by a policy stream.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TargetKind {
+    Pod,
+    ExternalWorkload,
+    Service,
+    EgressNetwork,
+    Fallback,
 }
 
 #[derive(Clone, Debug)]
@@ -20,14 +59,27 @@ pub(crate) struct GrpcServerRPCMetrics {
     started: Counter,
     msg_received: Counter,
     msg_sent: Counter,
+    msg_suppressed: Counter,
+    msg_coalesced: Counter,
     handling: Histogram,
     handled: Family<CodeLabels, Counter>,
+    watches: WatchMetricsFamily,
     labels: Labels,
 }
 
 pub(crate) struct ResponseObserver {
     msg_sent: Counter,
+    msg_suppressed: Counter,
+    msg_coalesced: Counter,
     handled: Option<ResponseHandle>,
+    watches: WatchMetricsFamily,
+    watch: Option<WatchHandle>,
+}
+
+struct WatchHandle {
+    active: Gauge,
+    msg_sent: Counter,
+    first_response: Option<(time::Instant, Histogram)>,
 }
 
 struct ResponseHandle {
@@ -52,10 +104,26 @@ struct CodeLabels {
     grpc_code: &'static str,
 }
 
+#[derive(Clone, Hash, PartialEq, Eq, EncodeLabelSet, Debug)]
+struct WatchLabels {
+    target_kind: &'static str,
+    source_namespace: String,
+}
+
+#[derive(Clone, Hash, PartialEq, Eq, EncodeLabelSet, Debug)]
+struct TargetKindLabels {
+    target_kind: &'static str,
+}
+
 // === GrpcServerMetricsFamily ===
 
 impl GrpcServerMetricsFamily {
-    pub fn register(reg: &mut Registry) -> Self {
+    /// Registers gRPC server metrics.
+    ///
+    /// When `max_watch_namespaces` is set, watch metrics are labeled with at
+    /// most that many distinct namespaces; watches from other namespaces are
+    /// labeled `_other`.
+    pub fn register(reg: &mut Registry, max_watch_namespaces: Option<usize>) -> Self {
         let started = Family::<Labels, Counter>::default();
         reg.register(
             "started",
@@ -77,6 +145,20 @@ impl GrpcServerMetricsFamily {
             msg_sent.clone(),
         );
 
+        let msg_suppressed = Family::<Labels, Counter>::default();
+        reg.register(
+            "msg_suppressed",
+            "Total number of gRPC stream messages not sent because they were identical to the previous message",
+            msg_suppressed.clone(),
+        );
+
+        let msg_coalesced = Family::<Labels, Counter>::default();
+        reg.register(
+            "msg_coalesced",
+            "Total number of gRPC stream updates replaced by a later update within the debounce window",
+            msg_coalesced.clone(),
+        );
+
         let handled = Family::<CodeLabels, Counter>::default();
         reg.register(
             "handled",
@@ -96,12 +178,17 @@ impl GrpcServerMetricsFamily {
             handling.clone(),
         );
 
+        let watches = WatchMetricsFamily::register(reg, max_watch_namespaces);
+
         Self {
             started,
             msg_received,
             msg_sent,
+            msg_suppressed,
+            msg_coalesced,
             handled,
             handling,
+            watches,
         }
     }
 
@@ -136,13 +223,112 @@ impl GrpcServerMetricsFamily {
             started: self.started.get_or_create(&labels).clone(),
             msg_received: self.msg_received.get_or_create(&labels).clone(),
             msg_sent: self.msg_sent.get_or_create(&labels).clone(),
+            msg_suppressed: self.msg_suppressed.get_or_create(&labels).clone(),
+            msg_coalesced: self.msg_coalesced.get_or_create(&labels).clone(),
             handled: self.handled.clone(),
             handling: self.handling.get_or_create(&labels).clone(),
+            watches: self.watches.clone(),
             labels,
         }
     }
 }
 
+// === WatchMetricsFamily ===
+
+impl WatchMetricsFamily {
+    fn register(reg: &mut Registry, max_namespaces: Option<usize>) -> Self {
+        let active = Family::<WatchLabels, Gauge>::default();
+        reg.register(
+            "watch_active",
+            "Number of policy watches currently open",
+            active.clone(),
+        );
+
+        let msg_sent = Family::<WatchLabels, Counter>::default();
+        reg.register(
+            "watch_msg_sent",
+            "Total number of policy updates sent on watches",
+            msg_sent.clone(),
+        );
+
+        let first_response = Family::<TargetKindLabels, Histogram>::new_with_constructor(|| {
+            Histogram::new([0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0])
+        });
+        reg.register_with_unit(
+            "watch_first_response",
+            "Histogram of the time (seconds) from the start of a policy watch until its first update is sent",
+            prometheus_client::registry::Unit::Seconds,
+            first_response.clone(),
+        );
+
+        Self {
+            active,
+            msg_sent,
+            first_response,
+            namespaces: NamespaceLimit {
+                max: max_namespaces,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn watch(&self, start: time::Instant, kind: TargetKind, namespace: &str) -> WatchHandle {
+        let labels = WatchLabels {
+            target_kind: kind.as_str(),
+            source_namespace: self.namespaces.label(namespace),
+        };
+        let active = self.active.get_or_create(&labels).clone();
+        active.inc();
+        let first_response = self
+            .first_response
+            .get_or_create(&TargetKindLabels {
+                target_kind: kind.as_str(),
+            })
+            .clone();
+        WatchHandle {
+            active,
+            msg_sent: self.msg_sent.get_or_create(&labels).clone(),
+            first_response: Some((start, first_response)),
+        }
+    }
+}
+
+// === NamespaceLimit ===
+
+impl NamespaceLimit {
+    fn label(&self, namespace: &str) -> String {
+        let Some(max) = self.max else {
+            return namespace.to_string();
+        };
+        let mut seen = self
+            .seen
+            .lock()
+            .expect("namespace set must not be poisoned");
+        if seen.contains(namespace) {
+            return namespace.to_string();
+        }
+        if seen.len() < max {
+            seen.insert(namespace.to_string());
+            return namespace.to_string();
+        }
+        OTHER_NAMESPACE.to_string()
+    }
+}
+
+// === TargetKind ===
+
+impl TargetKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pod => "pod",
+            Self::ExternalWorkload => "external_workload",
+            Self::Service => "service",
+            Self::EgressNetwork => "egress_network",
+            Self::Fallback => "fallback",
+        }
+    }
+}
+
 // === GrpcServerRPCMetrics ===
 
 impl GrpcServerRPCMetrics {
@@ -172,7 +358,11 @@ impl GrpcServerRPCMetrics {
 
         ResponseObserver {
             msg_sent: self.msg_sent.clone(),
+            msg_suppressed: self.msg_suppressed.clone(),
+            msg_coalesced: self.msg_coalesced.clone(),
             handled,
+            watch: None,
+            watches: self.watches.clone(),
         }
     }
 }
@@ -180,8 +370,32 @@ impl GrpcServerRPCMetrics {
 // === ResponseObserver ===
 
 impl ResponseObserver {
-    pub(crate) fn msg_sent(&self) {
+    /// Records that this response is a watch of the given kind of target by
+    /// a workload in `namespace`.
+    pub(crate) fn watching(&mut self, kind: TargetKind, namespace: &str) {
+        let start = self
+            .handled
+            .as_ref()
+            .map_or_else(time::Instant::now, |h| h.start);
+        self.watch = Some(self.watches.watch(start, kind, namespace));
+    }
+
+    pub(crate) fn msg_sent(&mut self) {
         self.msg_sent.inc();
+        if let Some(watch) = self.watch.as_mut() {
+            watch.msg_sent.inc();
+            if let Some((start, histogram)) = watch.first_response.take() {
+                histogram.observe(start.elapsed().as_secs_f64());
+            }
+        }
+    }
+
+    pub(crate) fn msg_suppressed(&self) {
+        self.msg_suppressed.inc();
+    }
+
+    pub(crate) fn msg_coalesced(&self, n: u64) {
+        self.msg_coalesced.inc_by(n);
     }
 
     pub(crate) fn end(mut self, code: tonic::Code) {
@@ -200,6 +414,14 @@ impl Drop for ResponseObserver {
     }
 }
 
+// === WatchHandle ===
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        self.active.dec();
+    }
+}
+
 // === ResponseHandle ===
 
 impl ResponseHandle {
@@ -245,3 +467,45 @@ fn code_str(code: tonic::Code) -> &'static str {
         Unauthenticated => "UNAUTHENTICATED",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(reg: &Registry) -> String {
+        let mut buf = String::new();
+        prometheus_client::encoding::text::encode(&mut buf, reg).unwrap();
+        buf
+    }
+
+    #[test]
+    fn watch_metrics() {
+        let mut reg = Registry::default();
+        let family = GrpcServerMetricsFamily::register(&mut reg, Some(1));
+        let rpc = family.server_stream_rpc("svc", "Watch");
+
+        let mut a = rpc.start();
+        a.watching(TargetKind::Pod, "ns-a");
+        a.msg_sent();
+        a.msg_sent();
+        let mut b = rpc.start();
+        b.watching(TargetKind::Service, "ns-b");
+
+        let metrics = encode(&reg);
+        assert!(metrics.contains(r#"watch_active{target_kind="pod",source_namespace="ns-a"} 1"#));
+        assert!(
+            metrics.contains(r#"watch_active{target_kind="service",source_namespace="_other"} 1"#)
+        );
+        assert!(metrics
+            .contains(r#"watch_msg_sent_total{target_kind="pod",source_namespace="ns-a"} 2"#));
+        assert!(metrics.contains(r#"watch_first_response_seconds_count{target_kind="pod"} 1"#));
+
+        a.end(tonic::Code::Ok);
+        drop(b);
+        let metrics = encode(&reg);
+        assert!(metrics.contains(r#"watch_active{target_kind="pod",source_namespace="ns-a"} 0"#));
+        assert!(
+            metrics.contains(r#"watch_active{target_kind="service",source_namespace="_other"} 0"#)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 extern crate http as http_crate;
 
-use crate::metrics::{self, GrpcServerMetricsFamily, GrpcServerRPCMetrics};
-use crate::workload;
+use crate::metrics::{self, GrpcServerMetricsFamily, GrpcServerRPCMetrics, TargetKind};
+use crate::{watch, workload};
 use futures::{prelude::*, StreamExt};
 use http_crate::uri::Authority;
 use linkerd2_proxy_api::{
@@ -24,6 +24,7 @@ use std::{net::SocketAddr, num::NonZeroU16, str::FromStr, sync::Arc, time};
 
 mod grpc;
 mod http;
+mod incremental;
 mod tcp;
 mod tls;
 
@@ -32,7 +33,11 @@ pub struct OutboundPolicyServer<T> {
     index: T,
     // Used to parse named addresses into <svc>.<ns>.svc.<cluster-domain>.
     cluster_domain: Arc<str>,
+    // Additional domains under which cluster services may be addressed.
+    cluster_domain_aliases: Arc<[Arc<str>]>,
     allow_l5d_request_headers: bool,
+    update_debounce: time::Duration,
+    verifier: workload::ClientVerifier,
     drain: drain::Watch,
     get_metrics: GrpcServerRPCMetrics,
     watch_metrics: GrpcServerRPCMetrics,
@@ -42,10 +47,14 @@ impl<T> OutboundPolicyServer<T>
 where
     T: DiscoverOutboundPolicy<ResourceTarget, OutboundDiscoverTarget> + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         discover: T,
         cluster_domain: impl Into<Arc<str>>,
+        cluster_domain_aliases: impl IntoIterator<Item = impl Into<Arc<str>>>,
         allow_l5d_request_headers: bool,
+        update_debounce: time::Duration,
+        verifier: workload::ClientVerifier,
         drain: drain::Watch,
         metrics: GrpcServerMetricsFamily,
     ) -> Self {
@@ -56,7 +65,10 @@ where
         Self {
             index: discover,
             cluster_domain: cluster_domain.into(),
+            cluster_domain_aliases: cluster_domain_aliases.into_iter().map(Into::into).collect(),
             allow_l5d_request_headers,
+            update_debounce,
+            verifier,
             drain,
             get_metrics,
             watch_metrics,
@@ -67,23 +79,32 @@ where
         OutboundPoliciesServer::new(self)
     }
 
-    fn lookup(&self, spec: outbound::TrafficSpec) -> Result<OutboundDiscoverTarget, tonic::Status> {
+    fn lookup(
+        &self,
+        req: tonic::Request<outbound::TrafficSpec>,
+    ) -> Result<OutboundDiscoverTarget, tonic::Status> {
+        let (metadata, extensions, spec) = req.into_parts();
         let target = spec
             .target
             .ok_or_else(|| tonic::Status::invalid_argument("target is required"))?;
-        let source_namespace = workload::Workload::from_str(&spec.source_workload)?.namespace;
+        let source_workload = workload::Workload::from_str(&spec.source_workload)?;
+        self.verifier
+            .verify(&metadata, &extensions, &source_workload)?;
+        let source_namespace = source_workload.namespace;
         let target = match target {
             outbound::traffic_spec::Target::Addr(target) => target,
             outbound::traffic_spec::Target::Authority(auth) => {
-                return self.lookup_authority(&auth).map(|(namespace, name, port)| {
-                    OutboundDiscoverTarget::Resource(ResourceTarget {
-                        kind: Kind::Service,
-                        name,
-                        namespace,
-                        port,
-                        source_namespace,
-                    })
-                })
+                return self.lookup_authority(&auth, &source_namespace).map(
+                    |(namespace, name, port)| {
+                        OutboundDiscoverTarget::Resource(ResourceTarget {
+                            kind: Kind::Service,
+                            name,
+                            namespace,
+                            port,
+                            source_namespace,
+                        })
+                    },
+                )
             }
         };
 
@@ -110,43 +131,20 @@ where
     fn lookup_authority(
         &self,
         authority: &str,
+        source_namespace: &str,
     ) -> Result<(String, String, NonZeroU16), tonic::Status> {
-        let auth = authority
-            .parse::<Authority>()
-            .map_err(|_| tonic::Status::invalid_argument("invalid authority"))?;
-
-        let mut host = auth.host();
-        if host.is_empty() {
-            return Err(tonic::Status::invalid_argument(
-                "authority must have a host",
-            ));
+        let domains = std::iter::once(&*self.cluster_domain)
+            .chain(self.cluster_domain_aliases.iter().map(|domain| &**domain));
+        let (namespace, name, port) = resolve_authority(authority, source_namespace, domains)?;
+
+        // Names such as `example.com` are shaped like `<name>.<namespace>`
+        // but usually refer to hosts outside of the cluster. Only Services
+        // that exist are discovered, so that lookups of arbitrary names don't
+        // create index entries; other names get no policy.
+        if !self.index.service_exists(&namespace, &name) {
+            return Err(tonic::Status::not_found("No such service"));
         }
-
-        host = host
-            .trim_end_matches('.')
-            .trim_end_matches(&*self.cluster_domain);
-
-        let mut parts = host.split('.');
-        let invalid = {
-            let domain = &self.cluster_domain;
-            move || {
-                tonic::Status::not_found(format!(
-                    "authority must be of the form <name>.<namespace>.svc.{domain}",
-                ))
-            }
-        };
-        let name = parts.next().ok_or_else(invalid)?;
-        let namespace = parts.next().ok_or_else(invalid)?;
-        if parts.next() != Some("svc") {
-            return Err(invalid());
-        };
-
-        let port = auth
-            .port_u16()
-            .and_then(|p| NonZeroU16::try_from(p).ok())
-            .unwrap_or_else(|| 80.try_into().unwrap());
-
-        Ok((namespace.to_string(), name.to_string(), port))
+        Ok((namespace, name, port))
     }
 }
 
@@ -160,7 +158,7 @@ where
         req: tonic::Request<outbound::TrafficSpec>,
     ) -> Result<tonic::Response<outbound::OutboundPolicy>, tonic::Status> {
         let metrics = self.get_metrics.start();
-        let target = match self.lookup(req.into_inner()) {
+        let target = match self.lookup(req) {
             Ok(target) => target,
             Err(status) => {
                 metrics.end(status.code());
@@ -172,11 +170,17 @@ where
             OutboundDiscoverTarget::Resource(resource) => {
                 let original_dst = resource.original_dst();
                 match self.index.get_outbound_policy(resource).await {
-                    Ok(Some(policy)) => Ok(tonic::Response::new(to_proto(
-                        policy,
-                        self.allow_l5d_request_headers,
-                        original_dst,
-                    ))),
+                    Ok(Some(policy)) => {
+                        let original_dst = match original_dst {
+                            Some(addr) => Some(addr),
+                            None => resolve_external_name(&policy).await,
+                        };
+                        Ok(tonic::Response::new(to_proto(
+                            policy,
+                            self.allow_l5d_request_headers,
+                            original_dst,
+                        )))
+                    }
                     Ok(None) => {
                         let status = tonic::Status::not_found("unknown target");
                         metrics.end(status.code());
@@ -208,9 +212,16 @@ where
         &self,
         req: tonic::Request<outbound::TrafficSpec>,
     ) -> Result<tonic::Response<BoxWatchStream>, tonic::Status> {
-        let metrics = self.watch_metrics.start();
+        let mut metrics = self.watch_metrics.start();
         tracing::debug!(?req, "watching outbound policy");
-        let target = match self.lookup(req.into_inner()) {
+        let incremental = req
+            .metadata()
+            .get(incremental::UPDATES_HEADER)
+            .is_some_and(|v| v == incremental::INCREMENTAL);
+        let source_namespace = workload::Workload::from_str(&req.get_ref().source_workload)
+            .map(|w| w.namespace)
+            .unwrap_or_default();
+        let target = match self.lookup(req) {
             Ok(target) => target,
             Err(status) => {
                 metrics.end(status.code());
@@ -222,6 +233,10 @@ where
         match target {
             OutboundDiscoverTarget::Resource(resource) => {
                 let original_dst = resource.original_dst();
+                let target_kind = match resource.kind {
+                    Kind::EgressNetwork(_) => TargetKind::EgressNetwork,
+                    Kind::Service | Kind::HeadlessService(_) => TargetKind::Service,
+                };
                 let rx = match self.index.watch_outbound_policy(resource).await {
                     Ok(Some(rx)) => rx,
                     Ok(None) => {
@@ -238,21 +253,26 @@ where
                     }
                 };
 
+                metrics.watching(target_kind, &source_namespace);
                 Ok(tonic::Response::new(response_stream(
                     drain,
                     rx,
                     self.allow_l5d_request_headers,
                     original_dst,
+                    self.update_debounce,
+                    incremental,
                     metrics,
                 )))
             }
 
             OutboundDiscoverTarget::External(original_dst) => {
                 let rx = self.index.watch_external_policy().await;
+                metrics.watching(TargetKind::Fallback, &source_namespace);
                 Ok(tonic::Response::new(external_stream(
                     drain,
                     rx,
                     original_dst,
+                    self.update_debounce,
                     metrics,
                 )))
             }
@@ -263,58 +283,226 @@ where
     }
 }
 
+/// Resolves an authority to a Service's namespace, name, and port, following
+/// the DNS search path of a pod in `source_namespace`: `<name>`,
+/// `<name>.<namespace>`, and `<name>.<namespace>.svc` are all accepted, as is
+/// `<name>.<namespace>.svc.<domain>` for any of the given cluster domains.
+///
+/// The authority may be prefixed with a scheme, which determines the port
+/// used when the authority does not specify one.
+fn resolve_authority<'d>(
+    authority: &str,
+    source_namespace: &str,
+    cluster_domains: impl IntoIterator<Item = &'d str>,
+) -> Result<(String, String, NonZeroU16), tonic::Status> {
+    let (default_port, authority) = match authority.split_once("://") {
+        Some((scheme, authority)) => {
+            let port = match scheme.to_ascii_lowercase().as_str() {
+                "http" | "ws" => 80,
+                "https" | "wss" => 443,
+                scheme => {
+                    return Err(tonic::Status::invalid_argument(format!(
+                        "unsupported scheme: {scheme}"
+                    )))
+                }
+            };
+            (port, authority)
+        }
+        None => (80, authority),
+    };
+
+    let auth = authority
+        .parse::<Authority>()
+        .map_err(|_| tonic::Status::invalid_argument("invalid authority"))?;
+
+    let host = auth.host().trim_end_matches('.').to_ascii_lowercase();
+    if host.is_empty() {
+        return Err(tonic::Status::invalid_argument(
+            "authority must have a host",
+        ));
+    }
+
+    // Names qualified with a cluster domain must be fully qualified service
+    // names. Otherwise, the name is relative to the search path.
+    let (name, qualified) = cluster_domains
+        .into_iter()
+        .find_map(|domain| {
+            let domain = domain.trim_matches('.').to_ascii_lowercase();
+            host.strip_suffix(&*domain)?.strip_suffix('.')
+        })
+        .map_or((&*host, false), |name| (name, true));
+
+    let invalid = || {
+        tonic::Status::not_found(
+            "authority must be of the form <name>, <name>.<namespace>, or <name>.<namespace>.svc[.<cluster-domain>]",
+        )
+    };
+    let labels = name.split('.').collect::<Vec<_>>();
+    let (name, namespace) = match labels[..] {
+        [name] if !qualified => (name, source_namespace),
+        [name, namespace] if !qualified => (name, namespace),
+        [name, namespace, "svc"] => (name, namespace),
+        _ => return Err(invalid()),
+    };
+    if name.is_empty() || namespace.is_empty() {
+        return Err(invalid());
+    }
+
+    let port = auth
+        .port_u16()
+        .and_then(|p| NonZeroU16::try_from(p).ok())
+        .unwrap_or_else(|| default_port.try_into().unwrap());
+
+    Ok((namespace.to_string(), name.to_string(), port))
+}
+
+/// How often the target names of ExternalName parents are re-resolved.
+const EXTERNAL_NAME_REFRESH_INTERVAL: time::Duration = time::Duration::from_secs(30);
+
 type BoxWatchStream = std::pin::Pin<
     Box<dyn Stream<Item = Result<outbound::OutboundPolicy, tonic::Status>> + Send + Sync>,
 >;
 
 fn response_stream(
     drain: drain::Watch,
-    mut rx: OutboundPolicyStream,
+    rx: OutboundPolicyStream,
     allow_l5d_request_headers: bool,
     original_dst: Option<SocketAddr>,
-    metrics: metrics::ResponseObserver,
+    update_debounce: time::Duration,
+    incremental: bool,
+    mut metrics: metrics::ResponseObserver,
 ) -> BoxWatchStream {
     Box::pin(async_stream::try_stream! {
         tokio::pin! {
             let shutdown = drain.signaled();
+            let updates = watch::coalesce(rx, update_debounce);
         }
 
+        // The last policy sent on the stream. Reindexing frequently doesn't
+        // change a given target's policy, so identical messages are not sent.
+        let mut last = None;
+        let mut sent_routes = incremental.then(incremental::SentRoutes::default);
+
+        // The current policy and, for ExternalName parents, the address its
+        // target name resolved to.
+        let mut current: Option<OutboundPolicy> = None;
+        let mut external_dst = None;
+        let mut refresh = tokio::time::interval_at(
+            tokio::time::Instant::now() + EXTERNAL_NAME_REFRESH_INTERVAL,
+            EXTERNAL_NAME_REFRESH_INTERVAL,
+        );
+        refresh.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
         loop {
-            tokio::select! {
+            let is_external_name = current.as_ref().is_some_and(|policy| {
+                matches!(
+                    policy.parent_info,
+                    ParentInfo::Service { external_name: Some(_), .. }
+                )
+            });
+            let policy = tokio::select! {
                 // When the port is updated with a new server, update the server watch.
-                res = rx.next() => match res {
-                    Some(policy) => {
-                        metrics.msg_sent();
-                        yield to_proto(policy, allow_l5d_request_headers, original_dst);
+                res = updates.next() => match res {
+                    Some((policy, coalesced)) => {
+                        metrics.msg_coalesced(coalesced);
+                        external_dst = resolve_external_name(&policy).await;
+                        policy
                     }
                     None => break,
                 },
 
+                // ExternalName targets are re-resolved so that the forward
+                // address follows changes to their DNS records.
+                _ = refresh.tick(), if is_external_name => {
+                    let Some(policy) = current.clone() else { continue };
+                    let dst = resolve_external_name(&policy).await;
+                    if dst == external_dst {
+                        continue;
+                    }
+                    external_dst = dst;
+                    policy
+                }
+
                 // If the server starts shutting down, close the stream so that it doesn't hold the
                 // server open.
                 _ = &mut shutdown => break,
+            };
+
+            current = Some(policy.clone());
+            let policy = to_proto(
+                policy,
+                allow_l5d_request_headers,
+                original_dst.or(external_dst),
+            );
+            if last.as_ref() == Some(&policy) {
+                metrics.msg_suppressed();
+                continue;
+            }
+            last = Some(policy.clone());
+            let mut policy = policy;
+            if let Some(sent) = sent_routes.as_mut() {
+                sent.elide_unchanged(&mut policy);
             }
+            metrics.msg_sent();
+            yield policy;
         }
 
         metrics.end(tonic::Code::Ok);
     })
 }
 
+/// Resolves the target name of an ExternalName parent, if the policy has one.
+async fn resolve_external_name(policy: &OutboundPolicy) -> Option<SocketAddr> {
+    let ParentInfo::Service {
+        external_name: Some(name),
+        ..
+    } = &policy.parent_info
+    else {
+        return None;
+    };
+
+    match tokio::net::lookup_host((name.as_str(), policy.port.get())).await {
+        // Names often resolve to several addresses in varying order; always
+        // forward to the lowest so that re-resolving doesn't churn updates.
+        Ok(addrs) => {
+            let addr = addrs.min();
+            if addr.is_none() {
+                tracing::warn!(%name, "ExternalName target has no addresses");
+            }
+            addr
+        }
+        Err(error) => {
+            tracing::warn!(%error, %name, "Failed to resolve ExternalName target");
+            None
+        }
+    }
+}
+
 fn external_stream(
     drain: drain::Watch,
-    mut rx: ExternalPolicyStream,
+    rx: ExternalPolicyStream,
     original_dst: SocketAddr,
-    metrics: metrics::ResponseObserver,
+    update_debounce: time::Duration,
+    mut metrics: metrics::ResponseObserver,
 ) -> BoxWatchStream {
     Box::pin(async_stream::try_stream! {
         tokio::pin! {
             let shutdown = drain.signaled();
+            let updates = watch::coalesce(rx, update_debounce);
         }
 
+        // The fallback policy only depends on the original destination, so
+        // only the first update needs to be sent.
+        let mut sent = false;
         loop {
             tokio::select! {
-                res = rx.next() => match res {
-                    Some(_) => {
+                res = updates.next() => match res {
+                    Some((_, coalesced)) => {
+                        metrics.msg_coalesced(coalesced);
+                        if sent {
+                            metrics.msg_suppressed();
+                            continue;
+                        }
+                        sent = true;
                         metrics.msg_sent();
                         yield fallback(original_dst);
                     }
@@ -467,7 +655,8 @@ fn undefined_port_stream(drain: drain::Watch, target: ResourceTarget) -> BoxWatc
     })
 }
 
-fn to_proto(
+/// Converts an `OutboundPolicy` into the protobuf returned to proxies.
+pub fn to_proto(
     policy: OutboundPolicy,
     allow_l5d_request_headers: bool,
     original_dst: Option<SocketAddr>,
@@ -665,6 +854,43 @@ fn timestamp_then_name<R: Route>(
 
 fn default_backend(policy: &OutboundPolicy, original_dst: Option<SocketAddr>) -> outbound::Backend {
     match policy.parent_info.clone() {
+        ParentInfo::Service {
+            namespace,
+            name,
+            external_name: Some(external_name),
+            ..
+        } => {
+            let metadata = Some(Metadata {
+                kind: Some(metadata::Kind::Resource(api::meta::Resource {
+                    group: "core".to_string(),
+                    kind: "Service".to_string(),
+                    name,
+                    namespace,
+                    section: Default::default(),
+                    port: u16::from(policy.port).into(),
+                })),
+            });
+
+            let Some(addr) = original_dst else {
+                tracing::warn!(
+                    ?metadata,
+                    %external_name,
+                    "ExternalName target did not resolve; synthesizing invalid backend"
+                );
+                return outbound::Backend {
+                    metadata,
+                    queue: None,
+                    kind: None,
+                };
+            };
+
+            outbound::Backend {
+                metadata,
+                queue: Some(default_queue_config()),
+                kind: Some(forward_external_name(addr, &external_name, policy.port)),
+            }
+        }
+
         ParentInfo::Service {
             authority,
             namespace,
@@ -687,7 +913,11 @@ fn default_backend(policy: &OutboundPolicy, original_dst: Option<SocketAddr>) ->
                     discovery: Some(outbound::backend::EndpointDiscovery {
                         kind: Some(outbound::backend::endpoint_discovery::Kind::Dst(
                             outbound::backend::endpoint_discovery::DestinationGet {
-                                path: authority.clone(),
+                                // Headless Services are resolved by the
+                                // address of the endpoint the client targeted.
+                                path: original_dst
+                                    .map(|addr| addr.to_string())
+                                    .unwrap_or(authority),
                             },
                         )),
                     }),
@@ -810,6 +1040,79 @@ pub(crate) fn convert_duration(
         .ok()
 }
 
+/// Returns how a Service backend is reached. Backends that refer to an
+/// ExternalName parent are forwarded to the address its target name resolved
+/// to; all other Services are balanced over discovered endpoints.
+pub(crate) fn service_backend_kind(
+    svc: &WeightedService,
+    parent_info: &ParentInfo,
+    original_dst: Option<SocketAddr>,
+) -> outbound::backend::Kind {
+    if let (
+        ParentInfo::Service {
+            name,
+            namespace,
+            external_name: Some(external_name),
+            ..
+        },
+        Some(addr),
+    ) = (parent_info, original_dst)
+    {
+        if *name == svc.name && *namespace == svc.namespace {
+            return forward_external_name(addr, external_name, svc.port);
+        }
+    }
+
+    outbound::backend::Kind::Balancer(outbound::backend::BalanceP2c {
+        discovery: Some(outbound::backend::EndpointDiscovery {
+            kind: Some(outbound::backend::endpoint_discovery::Kind::Dst(
+                outbound::backend::endpoint_discovery::DestinationGet {
+                    path: service_dst_path(svc, parent_info, original_dst),
+                },
+            )),
+        }),
+        load: Some(default_balancer_config()),
+    })
+}
+
+/// Forwards to the resolved address of an ExternalName Service's target,
+/// presenting the target name as the request authority.
+fn forward_external_name(
+    addr: SocketAddr,
+    external_name: &str,
+    port: NonZeroU16,
+) -> outbound::backend::Kind {
+    outbound::backend::Kind::Forward(destination::WeightedAddr {
+        addr: Some(SocketAddr::new(addr.ip(), port.get()).into()),
+        weight: 1,
+        authority_override: Some(destination::AuthorityOverride {
+            authority_override: format!("{external_name}:{port}"),
+        }),
+        ..Default::default()
+    })
+}
+
+/// Returns the discovery path for a Service backend. Backends that refer to a
+/// headless parent are resolved to the endpoint the client targeted, so that
+/// routes apply per destination pod.
+fn service_dst_path(
+    svc: &WeightedService,
+    parent_info: &ParentInfo,
+    original_dst: Option<SocketAddr>,
+) -> String {
+    match (parent_info, original_dst) {
+        (
+            ParentInfo::Service {
+                name, namespace, ..
+            },
+            Some(original_dst),
+        ) if *name == svc.name && *namespace == svc.namespace => {
+            SocketAddr::new(original_dst.ip(), svc.port.get()).to_string()
+        }
+        _ => svc.authority.clone(),
+    }
+}
+
 pub(crate) fn service_meta(svc: WeightedService) -> Metadata {
     Metadata {
         kind: Some(metadata::Kind::Resource(Resource {
@@ -823,6 +1126,19 @@ pub(crate) fn service_meta(svc: WeightedService) -> Metadata {
     }
 }
 
+pub(crate) fn service_import_meta(svc: WeightedService) -> Metadata {
+    Metadata {
+        kind: Some(metadata::Kind::Resource(Resource {
+            group: "multicluster.x-k8s.io".to_string(),
+            kind: "ServiceImport".to_string(),
+            name: svc.name,
+            namespace: svc.namespace,
+            section: Default::default(),
+            port: u16::from(svc.port).into(),
+        })),
+    }
+}
+
 pub(crate) fn egress_net_meta(
     egress_net: WeightedEgressNetwork,
     original_dst_port: Option<u16>,
@@ -844,3 +1160,101 @@ pub(crate) fn egress_net_meta(
         })),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(authority: &str) -> Result<(String, String, u16), tonic::Code> {
+        resolve_authority(authority, "src", ["cluster.local", "example.org"])
+            .map(|(ns, name, port)| (ns, name, port.get()))
+            .map_err(|status| status.code())
+    }
+
+    fn ok(ns: &str, name: &str, port: u16) -> Result<(String, String, u16), tonic::Code> {
+        Ok((ns.to_string(), name.to_string(), port))
+    }
+
+    #[test]
+    fn resolves_search_path() {
+        assert_eq!(resolve("web"), ok("src", "web", 80));
+        assert_eq!(resolve("web:8080"), ok("src", "web", 8080));
+        assert_eq!(resolve("web.ns"), ok("ns", "web", 80));
+        assert_eq!(resolve("web.ns.svc"), ok("ns", "web", 80));
+        assert_eq!(resolve("web.ns.svc.cluster.local"), ok("ns", "web", 80));
+        assert_eq!(resolve("web.ns.svc.cluster.local.:81"), ok("ns", "web", 81));
+        assert_eq!(resolve("Web.NS.svc.Cluster.Local"), ok("ns", "web", 80));
+    }
+
+    #[test]
+    fn resolves_alias_domains() {
+        assert_eq!(resolve("web.ns.svc.example.org"), ok("ns", "web", 80));
+        assert_eq!(
+            resolve("web.ns.svc.example.com"),
+            Err(tonic::Code::NotFound)
+        );
+        assert_eq!(resolve("web.ns.cluster.local"), Err(tonic::Code::NotFound));
+    }
+
+    #[test]
+    fn scheme_default_ports() {
+        assert_eq!(resolve("https://web.ns"), ok("ns", "web", 443));
+        assert_eq!(resolve("wss://web.ns"), ok("ns", "web", 443));
+        assert_eq!(resolve("http://web.ns"), ok("ns", "web", 80));
+        assert_eq!(resolve("https://web.ns:8443"), ok("ns", "web", 8443));
+        assert_eq!(resolve("ftp://web.ns"), Err(tonic::Code::InvalidArgument));
+    }
+
+    fn external_name_policy(external_name: &str) -> OutboundPolicy {
+        OutboundPolicy {
+            parent_info: ParentInfo::Service {
+                name: "db".to_string(),
+                namespace: "ns".to_string(),
+                authority: "db.ns.svc.cluster.local:5432".to_string(),
+                external_name: Some(external_name.to_string()),
+            },
+            http_routes: Default::default(),
+            grpc_routes: Default::default(),
+            tls_routes: Default::default(),
+            tcp_routes: Default::default(),
+            port: NonZeroU16::new(5432).unwrap(),
+            app_protocol: None,
+            accrual: None,
+            http_retry: None,
+            grpc_retry: None,
+            timeouts: Default::default(),
+        }
+    }
+
+    #[test]
+    fn external_name_forwards_to_target() {
+        let policy = external_name_policy("db.example.com");
+        let resolved = "192.0.2.7:5432".parse().unwrap();
+
+        let backend = default_backend(&policy, Some(resolved));
+        let Some(outbound::backend::Kind::Forward(addr)) = backend.kind else {
+            panic!("ExternalName backends must forward to the target");
+        };
+        assert_eq!(addr.addr, Some(resolved.into()));
+        assert_eq!(
+            addr.authority_override,
+            Some(destination::AuthorityOverride {
+                authority_override: "db.example.com:5432".to_string(),
+            })
+        );
+
+        // Until the target resolves, the backend is invalid rather than
+        // discovered by a name the destination service doesn't know.
+        let backend = default_backend(&policy, None);
+        assert_eq!(backend.kind, None);
+    }
+
+    #[tokio::test]
+    async fn resolves_external_name() {
+        let policy = external_name_policy("192.0.2.7");
+        assert_eq!(
+            resolve_external_name(&policy).await,
+            Some("192.0.2.7:5432".parse().unwrap())
+        );
+    }
+}
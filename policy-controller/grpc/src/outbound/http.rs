@@ -308,6 +308,31 @@ fn convert_backend(
                     backend: Some(outbound::Backend {
                         metadata: Some(super::service_meta(svc.clone())),
                         queue: Some(default_queue_config()),
+                        kind: Some(super::service_backend_kind(&svc, parent_info, original_dst)),
+                    }),
+                    filters,
+                    ..Default::default()
+                }),
+            }
+        }
+        Backend::Service(svc) => invalid_backend(
+            svc.weight,
+            format!("Service not found {}", svc.name),
+            super::service_meta(svc),
+        ),
+        Backend::ServiceImport(svc) if svc.exists => {
+            let filters = svc
+                .filters
+                .clone()
+                .into_iter()
+                .map(convert_to_filter)
+                .collect();
+            outbound::http_route::WeightedRouteBackend {
+                weight: svc.weight,
+                backend: Some(outbound::http_route::RouteBackend {
+                    backend: Some(outbound::Backend {
+                        metadata: Some(super::service_import_meta(svc.clone())),
+                        queue: Some(default_queue_config()),
                         kind: Some(outbound::backend::Kind::Balancer(
                             outbound::backend::BalanceP2c {
                                 discovery: Some(outbound::backend::EndpointDiscovery {
@@ -326,10 +351,10 @@ fn convert_backend(
                 }),
             }
         }
-        Backend::Service(svc) => invalid_backend(
+        Backend::ServiceImport(svc) => invalid_backend(
             svc.weight,
-            format!("Service not found {}", svc.name),
-            super::service_meta(svc),
+            format!("ServiceImport not found {}", svc.name),
+            super::service_import_meta(svc),
         ),
         Backend::EgressNetwork(egress_net) if egress_net.exists => {
             match (parent_info, original_dst) {
@@ -0,0 +1,224 @@
+//! Incremental outbound policy updates.
+//!
+//! Clients opt in by setting the `l5d-policy-updates: incremental` header on a
+//! `Watch` request; other clients receive a full snapshot in every message.
+//!
+//! The first message on an incremental stream is a full snapshot. Each later
+//! message still lists every route, so a route that is absent from a message
+//! has been removed. A route that has not changed since the previous message,
+//! however, is sent with only its metadata, and the client should reuse the
+//! route it last received with the same metadata. Routes without rules match no
+//! traffic and are omitted, so that a metadata-only route is never ambiguous.
+//!
+//! Only routes that correspond to a resource are elided; default routes are
+//! always sent in full.
+
+use linkerd2_proxy_api::{
+    meta::{metadata, Metadata},
+    outbound::{self, proxy_protocol},
+};
+use linkerd_policy_controller_core::routes::GroupKindNamespaceName;
+use std::collections::HashMap;
+
+pub(crate) const UPDATES_HEADER: &str = "l5d-policy-updates";
+pub(crate) const INCREMENTAL: &str = "incremental";
+
+/// The routes last sent on a stream, keyed by the resource that produced them.
+#[derive(Debug, Default)]
+pub(crate) struct SentRoutes {
+    http: HashMap<GroupKindNamespaceName, outbound::HttpRoute>,
+    grpc: HashMap<GroupKindNamespaceName, outbound::GrpcRoute>,
+    opaque: HashMap<GroupKindNamespaceName, outbound::OpaqueRoute>,
+    tls: HashMap<GroupKindNamespaceName, outbound::TlsRoute>,
+}
+
+trait Route: Clone + Default + PartialEq {
+    fn metadata(&self) -> &Option<Metadata>;
+
+    fn metadata_mut(&mut self) -> &mut Option<Metadata>;
+
+    fn has_rules(&self) -> bool;
+
+    /// Returns a route that holds only this route's metadata.
+    fn elided(&self) -> Self {
+        let mut route = Self::default();
+        *route.metadata_mut() = self.metadata().clone();
+        route
+    }
+}
+
+// === impl SentRoutes ===
+
+impl SentRoutes {
+    /// Replaces each route in `policy` that is unchanged since the previous
+    /// call with a metadata-only route.
+    pub(crate) fn elide_unchanged(&mut self, policy: &mut outbound::OutboundPolicy) {
+        use proxy_protocol::Kind;
+
+        let mut sent = Self::default();
+        match policy.protocol.as_mut().and_then(|p| p.kind.as_mut()) {
+            Some(Kind::Detect(detect)) => {
+                if let Some(opaque) = detect.opaque.as_mut() {
+                    elide(&self.opaque, &mut sent.opaque, &mut opaque.routes);
+                }
+                if let Some(http) = detect.http1.as_mut() {
+                    elide(&self.http, &mut sent.http, &mut http.routes);
+                }
+                if let Some(http) = detect.http2.as_mut() {
+                    elide(&self.http, &mut sent.http, &mut http.routes);
+                }
+            }
+            Some(Kind::Opaque(opaque)) => elide(&self.opaque, &mut sent.opaque, &mut opaque.routes),
+            Some(Kind::Http1(http)) => elide(&self.http, &mut sent.http, &mut http.routes),
+            Some(Kind::Http2(http)) => elide(&self.http, &mut sent.http, &mut http.routes),
+            Some(Kind::Grpc(grpc)) => elide(&self.grpc, &mut sent.grpc, &mut grpc.routes),
+            Some(Kind::Tls(tls)) => elide(&self.tls, &mut sent.tls, &mut tls.routes),
+            None => {}
+        }
+        *self = sent;
+    }
+}
+
+fn elide<R: Route>(
+    prior: &HashMap<GroupKindNamespaceName, R>,
+    sent: &mut HashMap<GroupKindNamespaceName, R>,
+    routes: &mut Vec<R>,
+) {
+    routes.retain(Route::has_rules);
+    for route in routes.iter_mut() {
+        let Some(key) = resource_key(route.metadata()) else {
+            continue;
+        };
+        let unchanged = prior.get(&key) == Some(route);
+        sent.insert(key, route.clone());
+        if unchanged {
+            *route = route.elided();
+        }
+    }
+}
+
+fn resource_key(metadata: &Option<Metadata>) -> Option<GroupKindNamespaceName> {
+    match metadata.as_ref()?.kind.as_ref()? {
+        metadata::Kind::Resource(r) => Some(GroupKindNamespaceName {
+            group: r.group.clone().into(),
+            kind: r.kind.clone().into(),
+            namespace: r.namespace.clone().into(),
+            name: r.name.clone().into(),
+        }),
+        metadata::Kind::Default(_) => None,
+    }
+}
+
+macro_rules! impl_route {
+    ($($ty:ty),+) => {
+        $(
+            impl Route for $ty {
+                fn metadata(&self) -> &Option<Metadata> {
+                    &self.metadata
+                }
+
+                fn metadata_mut(&mut self) -> &mut Option<Metadata> {
+                    &mut self.metadata
+                }
+
+                fn has_rules(&self) -> bool {
+                    !self.rules.is_empty()
+                }
+            }
+        )+
+    };
+}
+
+impl_route!(
+    outbound::HttpRoute,
+    outbound::GrpcRoute,
+    outbound::OpaqueRoute,
+    outbound::TlsRoute
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkerd2_proxy_api::meta;
+
+    fn route(name: &str, path: &str) -> outbound::HttpRoute {
+        outbound::HttpRoute {
+            metadata: Some(Metadata {
+                kind: Some(metadata::Kind::Resource(meta::Resource {
+                    group: "gateway.networking.k8s.io".to_string(),
+                    kind: "HTTPRoute".to_string(),
+                    namespace: "ns".to_string(),
+                    name: name.to_string(),
+                    ..Default::default()
+                })),
+            }),
+            rules: vec![outbound::http_route::Rule {
+                matches: vec![linkerd2_proxy_api::http_route::HttpRouteMatch {
+                    path: Some(linkerd2_proxy_api::http_route::PathMatch {
+                        kind: Some(linkerd2_proxy_api::http_route::path_match::Kind::Prefix(
+                            path.to_string(),
+                        )),
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn policy(routes: Vec<outbound::HttpRoute>) -> outbound::OutboundPolicy {
+        outbound::OutboundPolicy {
+            protocol: Some(outbound::ProxyProtocol {
+                kind: Some(proxy_protocol::Kind::Http1(proxy_protocol::Http1 {
+                    routes,
+                    failure_accrual: None,
+                })),
+            }),
+            metadata: None,
+        }
+    }
+
+    fn routes(policy: outbound::OutboundPolicy) -> Vec<outbound::HttpRoute> {
+        match policy.protocol.and_then(|p| p.kind) {
+            Some(proxy_protocol::Kind::Http1(http)) => http.routes,
+            _ => panic!("expected HTTP/1 protocol"),
+        }
+    }
+
+    #[test]
+    fn elides_unchanged_routes() {
+        let mut sent = SentRoutes::default();
+
+        let mut first = policy(vec![route("a", "/a"), route("b", "/b")]);
+        sent.elide_unchanged(&mut first);
+        assert_eq!(routes(first), vec![route("a", "/a"), route("b", "/b")]);
+
+        // `a` is unchanged, `b` is modified, and `c` is added.
+        let mut second = policy(vec![route("a", "/a"), route("b", "/b2"), route("c", "/c")]);
+        sent.elide_unchanged(&mut second);
+        assert_eq!(
+            routes(second),
+            vec![
+                route("a", "/a").elided(),
+                route("b", "/b2"),
+                route("c", "/c")
+            ]
+        );
+
+        // `a` is removed; `b` is unchanged; `c` no longer has rules.
+        let mut empty = route("c", "/c");
+        empty.rules.clear();
+        let mut third = policy(vec![route("b", "/b2"), empty]);
+        sent.elide_unchanged(&mut third);
+        assert_eq!(routes(third), vec![route("b", "/b2").elided()]);
+
+        // `a` is sent in full when it is re-added.
+        let mut fourth = policy(vec![route("a", "/a"), route("b", "/b2")]);
+        sent.elide_unchanged(&mut fourth);
+        assert_eq!(
+            routes(fourth),
+            vec![route("a", "/a"), route("b", "/b2").elided()]
+        );
+    }
+}
@@ -123,6 +123,22 @@ fn convert_backend(
                 backend: Some(outbound::Backend {
                     metadata: Some(super::service_meta(svc.clone())),
                     queue: Some(default_queue_config()),
+                    kind: Some(super::service_backend_kind(&svc, parent_info, original_dst)),
+                }),
+                filters: Vec::new(),
+            }),
+        },
+        Backend::Service(svc) => invalid_backend(
+            svc.weight,
+            format!("Service not found {}", svc.name),
+            super::service_meta(svc),
+        ),
+        Backend::ServiceImport(svc) if svc.exists => outbound::opaque_route::WeightedRouteBackend {
+            weight: svc.weight,
+            backend: Some(outbound::opaque_route::RouteBackend {
+                backend: Some(outbound::Backend {
+                    metadata: Some(super::service_import_meta(svc.clone())),
+                    queue: Some(default_queue_config()),
                     kind: Some(outbound::backend::Kind::Balancer(
                         outbound::backend::BalanceP2c {
                             discovery: Some(outbound::backend::EndpointDiscovery {
@@ -139,10 +155,10 @@ fn convert_backend(
                 filters: Vec::new(),
             }),
         },
-        Backend::Service(svc) => invalid_backend(
+        Backend::ServiceImport(svc) => invalid_backend(
             svc.weight,
-            format!("Service not found {}", svc.name),
-            super::service_meta(svc),
+            format!("ServiceImport not found {}", svc.name),
+            super::service_import_meta(svc),
         ),
         Backend::EgressNetwork(egress_net) if egress_net.exists => {
             match (parent_info, original_dst) {
@@ -0,0 +1,87 @@
+use futures::prelude::*;
+use tokio::time;
+
+/// Coalesces bursts of updates from a watch.
+///
+/// The first update is yielded immediately. When `window` is non-zero, each
+/// later update is held for up to `window`; any updates received in that time
+/// replace it. Each update is yielded with the number of updates it replaced.
+pub(crate) fn coalesce<S>(
+    mut updates: S,
+    window: time::Duration,
+) -> impl Stream<Item = (S::Item, u64)>
+where
+    S: Stream + Unpin,
+{
+    async_stream::stream! {
+        let Some(first) = updates.next().await else {
+            return;
+        };
+        yield (first, 0);
+
+        while let Some(mut update) = updates.next().await {
+            let mut coalesced = 0;
+            let mut closed = false;
+            if !window.is_zero() {
+                let deadline = time::sleep(window);
+                tokio::pin!(deadline);
+                loop {
+                    tokio::select! {
+                        res = updates.next() => match res {
+                            Some(next) => {
+                                update = next;
+                                coalesced += 1;
+                            }
+                            None => {
+                                closed = true;
+                                break;
+                            }
+                        },
+                        _ = &mut deadline => break,
+                    }
+                }
+            }
+            yield (update, coalesced);
+            if closed {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn coalesces_bursts() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let updates = coalesce(
+            tokio_stream::wrappers::UnboundedReceiverStream::new(rx),
+            time::Duration::from_secs(1),
+        );
+        tokio::pin!(updates);
+
+        tx.send(1).unwrap();
+        assert_eq!(updates.next().await, Some((1, 0)));
+
+        tx.send(2).unwrap();
+        tx.send(3).unwrap();
+        tx.send(4).unwrap();
+        assert_eq!(updates.next().await, Some((4, 2)));
+
+        tx.send(5).unwrap();
+        drop(tx);
+        assert_eq!(updates.next().await, Some((5, 0)));
+        assert_eq!(updates.next().await, None);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn passes_through_without_window() {
+        let updates = coalesce(stream::iter([1, 2, 3]), time::Duration::ZERO);
+        assert_eq!(
+            updates.collect::<Vec<_>>().await,
+            vec![(1, 0), (2, 0), (3, 0)]
+        );
+    }
+}
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use std::{net::SocketAddr, str::FromStr, sync::Arc};
+
+/// The header in which the proxy in front of the policy controller forwards the
+/// mTLS identity of its client.
+const CLIENT_ID_HEADER: &str = "l5d-client-id";
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Kind {
@@ -43,6 +47,115 @@ impl FromStr for Workload {
     }
 }
 
+/// Resolves the mTLS identity of a workload's proxy.
+pub trait WorkloadIdentity {
+    fn workload_identity(&self, workload: &Workload) -> Option<String>;
+}
+
+/// Verifies that clients are the workloads they claim to be.
+///
+/// When enabled, the mTLS identity of each client must match the identity of
+/// the workload named in the request. The identity is taken from the client's
+/// certificate when the server terminates TLS. Otherwise, it is taken from the
+/// `l5d-client-id` header only if the verifier trusts the controller's proxy
+/// to set it: any client can set the header when the controller isn't meshed.
+#[derive(Clone, Default)]
+pub struct ClientVerifier(Option<Verify>);
+
+#[derive(Clone)]
+struct Verify {
+    identities: Arc<dyn WorkloadIdentity + Send + Sync>,
+    trust_proxy_header: bool,
+}
+
+/// Connection info for clients of a server that terminates TLS itself.
+///
+/// When a request carries this info, the client's identity is taken from its
+/// certificate and the `l5d-client-id` header is ignored.
+#[derive(Clone, Debug, Default)]
+pub struct TlsClientInfo {
+    pub remote_addr: Option<SocketAddr>,
+    pub client_id: Option<String>,
+}
+
+// === impl ClientVerifier ===
+
+impl ClientVerifier {
+    /// A verifier that trusts the `l5d-client-id` header on plaintext
+    /// connections only if `trust_proxy_header` is set.
+    pub fn new(
+        identities: impl WorkloadIdentity + Send + Sync + 'static,
+        trust_proxy_header: bool,
+    ) -> Self {
+        Self(Some(Verify {
+            identities: Arc::new(identities),
+            trust_proxy_header,
+        }))
+    }
+
+    /// A verifier that accepts all clients.
+    pub fn disabled() -> Self {
+        Self(None)
+    }
+
+    pub(crate) fn verify(
+        &self,
+        metadata: &tonic::metadata::MetadataMap,
+        extensions: &tonic::Extensions,
+        workload: &Workload,
+    ) -> Result<(), tonic::Status> {
+        let Some(Verify {
+            identities,
+            trust_proxy_header,
+        }) = self.0.as_ref()
+        else {
+            return Ok(());
+        };
+
+        let client_id = match extensions.get::<TlsClientInfo>() {
+            Some(tls) => tls.client_id.as_deref(),
+            None if *trust_proxy_header => {
+                metadata.get(CLIENT_ID_HEADER).and_then(|v| v.to_str().ok())
+            }
+            None => None,
+        }
+        .ok_or_else(|| tonic::Status::permission_denied("client identity is required"))?;
+        match identities.workload_identity(workload) {
+            Some(id) if id == client_id => Ok(()),
+            Some(_) => {
+                tracing::info!(%client_id, ?workload, "Client identity does not match workload");
+                Err(tonic::Status::permission_denied(format!(
+                    "{client_id} is not authorized for workload {workload}"
+                )))
+            }
+            // The workload may not have been indexed yet, so the client
+            // should retry rather than treat the request as forbidden.
+            None => Err(tonic::Status::unavailable(format!(
+                "unknown workload {workload}"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Debug for ClientVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ClientVerifier")
+            .field(&self.0.is_some())
+            .finish()
+    }
+}
+
+// === impl Workload ===
+
+impl std::fmt::Display for Workload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            Kind::Pod(name) => write!(f, "{}/pod/{name}", self.namespace),
+            Kind::External(name) => write!(f, "{}/external_workload/{name}", self.namespace),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,4 +195,73 @@ mod tests {
         let input = r#"{"ns":"my-namespace", "nonsense":"my-external"}"#;
         assert!(Workload::from_str(input).is_err());
     }
+
+    #[test]
+    fn verifies_client_identity() {
+        struct Identities;
+        impl WorkloadIdentity for Identities {
+            fn workload_identity(&self, workload: &Workload) -> Option<String> {
+                match &workload.kind {
+                    Kind::Pod(name) if name == "my-pod" => Some(format!(
+                        "default.{}.serviceaccount.identity.linkerd.cluster.local",
+                        workload.namespace
+                    )),
+                    _ => None,
+                }
+            }
+        }
+
+        let workload = Workload::from_str("my-namespace:my-pod").unwrap();
+        let mut metadata = tonic::metadata::MetadataMap::new();
+        let extensions = tonic::Extensions::new();
+        assert!(ClientVerifier::disabled()
+            .verify(&metadata, &extensions, &workload)
+            .is_ok());
+
+        let verifier = ClientVerifier::new(Identities, true);
+        let code = |metadata: &tonic::metadata::MetadataMap, workload: &Workload| {
+            verifier
+                .verify(metadata, &extensions, workload)
+                .unwrap_err()
+                .code()
+        };
+        assert_eq!(code(&metadata, &workload), tonic::Code::PermissionDenied);
+
+        metadata.insert(
+            CLIENT_ID_HEADER,
+            "default.my-namespace.serviceaccount.identity.linkerd.cluster.local"
+                .parse()
+                .unwrap(),
+        );
+        assert!(verifier.verify(&metadata, &extensions, &workload).is_ok());
+
+        let other = Workload::from_str("other-namespace:my-pod").unwrap();
+        assert_eq!(code(&metadata, &other), tonic::Code::PermissionDenied);
+        // Workloads that aren't indexed yet may be retried.
+        let unknown = Workload::from_str("my-namespace:unknown").unwrap();
+        assert_eq!(code(&metadata, &unknown), tonic::Code::Unavailable);
+
+        // The header is not trusted unless the controller is meshed.
+        assert_eq!(
+            ClientVerifier::new(Identities, false)
+                .verify(&metadata, &extensions, &workload)
+                .unwrap_err()
+                .code(),
+            tonic::Code::PermissionDenied
+        );
+
+        // When the server terminates TLS, the header is not trusted.
+        let mut tls = tonic::Extensions::new();
+        tls.insert(TlsClientInfo::default());
+        assert!(verifier.verify(&metadata, &tls, &workload).is_err());
+        tls.insert(TlsClientInfo {
+            remote_addr: None,
+            client_id: Some(
+                "default.my-namespace.serviceaccount.identity.linkerd.cluster.local".to_string(),
+            ),
+        });
+        assert!(verifier
+            .verify(&tonic::metadata::MetadataMap::new(), &tls, &workload)
+            .is_ok());
+    }
 }
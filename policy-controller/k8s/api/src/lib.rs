@@ -4,7 +4,9 @@
 pub mod duration;
 pub mod external_workload;
 pub mod labels;
+pub mod multicluster;
 pub mod policy;
+pub mod routes;
 
 pub use self::labels::Labels;
 pub use k8s_openapi::{
@@ -15,6 +17,7 @@ pub use k8s_openapi::{
             Container, ContainerPort, Endpoints, HTTPGetAction, Namespace, Node, NodeSpec, Pod,
             PodSpec, PodStatus, Probe, Service, ServiceAccount, ServicePort, ServiceSpec,
         },
+        discovery::v1::EndpointSlice,
     },
     apimachinery::{
         self,
@@ -28,13 +31,14 @@ pub use k8s_openapi::{
 pub use kube::{
     api::{Api, ListParams, ObjectMeta, Patch, PatchParams, Resource, ResourceExt},
     error::ErrorResponse,
-    runtime::watcher::Event as WatchEvent,
+    runtime::{events, watcher::Event as WatchEvent},
     Client, Error,
 };
 
 pub mod gateway {
     pub use gateway_api::apis::experimental::grpcroutes::*;
     pub use gateway_api::apis::experimental::httproutes::*;
+    pub use gateway_api::apis::experimental::referencegrants::*;
     pub use gateway_api::apis::experimental::tcproutes::*;
     pub use gateway_api::apis::experimental::tlsroutes::*;
 
@@ -0,0 +1,57 @@
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// ServiceImport describes a service imported from clusters in a ClusterSet,
+/// as defined by the Multi-Cluster Services API (KEP-1645).
+#[derive(Clone, Debug, PartialEq, Eq, CustomResource, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "multicluster.x-k8s.io",
+    version = "v1alpha1",
+    kind = "ServiceImport",
+    status = "ServiceImportStatus",
+    namespaced
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceImportSpec {
+    /// Ports exposed by the imported service.
+    pub ports: Vec<ServicePort>,
+    /// IP addresses allocated to the imported service. Only set for
+    /// ClusterSetIP services.
+    pub ips: Option<Vec<String>>,
+    /// The type of the imported service.
+    #[serde(rename = "type")]
+    pub type_: ServiceImportType,
+}
+
+/// ServicePort describes a port exposed by an imported service.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ServicePort {
+    pub name: Option<String>,
+    /// Protocol defines network protocols supported. One of UDP, TCP, or SCTP.
+    /// Defaults to "TCP" if unspecified.
+    pub protocol: Option<String>,
+    pub app_protocol: Option<String>,
+    pub port: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum ServiceImportType {
+    /// The imported service is reachable through a cluster-set VIP.
+    ClusterSetIP,
+    /// The imported service resolves to the addresses of its endpoints.
+    Headless,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct ServiceImportStatus {
+    /// The clusters from which the service is currently imported.
+    pub clusters: Option<Vec<ClusterStatus>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct ClusterStatus {
+    /// The name of an exporting cluster.
+    pub cluster: String,
+}
@@ -0,0 +1,84 @@
+//! Checks that route rules can be served by the proxy.
+//!
+//! The outbound index drops rules that fail these checks, and the status
+//! controller reports them on the route, so both agree on which rules are
+//! served.
+
+use std::str::FromStr;
+
+pub mod grpc;
+pub mod http;
+
+/// Describes why a route rule, or one of its backends, can't be served.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("{0}")]
+pub struct Unsupported(String);
+
+pub type Result<T = ()> = std::result::Result<T, Unsupported>;
+
+impl Unsupported {
+    fn new(error: impl ToString) -> Self {
+        Self(error.to_string())
+    }
+}
+
+/// Checks that a TCPRoute or TLSRoute can be served. Only routes with exactly
+/// one rule are supported; routes with any other number of rules are not
+/// served at all.
+pub fn validate_single_rule(kind: &str, rules: usize) -> Result {
+    if rules != 1 {
+        return Err(Unsupported(format!("{kind} needs to have one rule")));
+    }
+    Ok(())
+}
+
+fn validate_header_name(name: &str) -> Result {
+    ::http::HeaderName::from_str(name).map_err(Unsupported::new)?;
+    Ok(())
+}
+
+fn validate_header_value(value: &str) -> Result {
+    ::http::HeaderValue::from_str(value).map_err(Unsupported::new)?;
+    Ok(())
+}
+
+fn validate_regex(value: &str) -> Result {
+    regex::Regex::new(value).map_err(Unsupported::new)?;
+    Ok(())
+}
+
+/// Checks the headers added or set by a header modifier filter, and the names
+/// of the headers it removes.
+fn validate_headers<'h>(
+    add_set: impl IntoIterator<Item = (&'h str, &'h str)>,
+    remove: impl IntoIterator<Item = &'h String>,
+) -> Result {
+    for (name, value) in add_set {
+        validate_header_name(name)?;
+        validate_header_value(value)?;
+    }
+    for name in remove {
+        validate_header_name(name)?;
+    }
+    Ok(())
+}
+
+/// Checks a header modifier filter. Every Gateway API header modifier type
+/// has the same fields, so this applies to all of them.
+macro_rules! validate_header_modifier {
+    ($modifier:expr) => {{
+        let modifier = $modifier;
+        let add = modifier
+            .add
+            .iter()
+            .flatten()
+            .map(|header| (header.name.as_str(), header.value.as_str()));
+        let set = modifier
+            .set
+            .iter()
+            .flatten()
+            .map(|header| (header.name.as_str(), header.value.as_str()));
+        super::validate_headers(add.chain(set), modifier.remove.iter().flatten())
+    }};
+}
+use validate_header_modifier;
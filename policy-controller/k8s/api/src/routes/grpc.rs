@@ -0,0 +1,80 @@
+use super::{validate_header_name, validate_header_value, validate_regex, Result, Unsupported};
+use crate::gateway;
+
+/// Checks a GRPCRoute filter. Rule and backend filters have distinct types
+/// with the same fields.
+macro_rules! validate_filter {
+    ($filter:expr) => {{
+        let filter = $filter;
+        if let Some(modifier) = &filter.request_header_modifier {
+            return super::validate_header_modifier!(modifier);
+        }
+        if let Some(modifier) = &filter.response_header_modifier {
+            return super::validate_header_modifier!(modifier);
+        }
+        if filter.request_mirror.is_some() {
+            return Err(Unsupported(
+                "RequestMirror filter is not supported".to_string(),
+            ));
+        }
+        if filter.extension_ref.is_some() {
+            return Err(Unsupported(
+                "ExtensionRef filter is not supported".to_string(),
+            ));
+        }
+        Err(Unsupported("unknown filter".to_string()))
+    }};
+}
+
+/// Checks that a GRPCRoute rule's matches and filters are supported.
+pub fn validate_rule(rule: &gateway::GRPCRouteRules) -> Result {
+    for m in rule.matches.iter().flatten() {
+        validate_match(m)?;
+    }
+    for filter in rule.filters.iter().flatten() {
+        validate_filter(filter)?;
+    }
+    Ok(())
+}
+
+/// Checks that a backendRef's filters are supported. Backends with
+/// unsupported filters are served as invalid, so requests routed to them fail.
+pub fn validate_backend_filters(backend: &gateway::GRPCRouteRulesBackendRefs) -> Result {
+    for filter in backend.filters.iter().flatten() {
+        validate_backend_filter(filter)
+            .map_err(|error| Unsupported(format!("unsupported backend filter: {error}")))?;
+    }
+    Ok(())
+}
+
+pub fn validate_match(m: &gateway::GRPCRouteRulesMatches) -> Result {
+    for header in m.headers.iter().flatten() {
+        validate_header_name(&header.name)?;
+        match header.r#type {
+            Some(gateway::GRPCRouteRulesMatchesHeadersType::Exact) | None => {
+                validate_header_value(&header.value)?
+            }
+            Some(gateway::GRPCRouteRulesMatchesHeadersType::RegularExpression) => {
+                validate_regex(&header.value)?
+            }
+        }
+    }
+
+    if let Some(method) = &m.method {
+        if method.r#type == Some(gateway::GRPCRouteRulesMatchesMethodType::RegularExpression) {
+            return Err(Unsupported(
+                "unsupported GRPCRoute method match type: RegularExpression".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_filter(filter: &gateway::GRPCRouteRulesFilters) -> Result {
+    validate_filter!(filter)
+}
+
+pub fn validate_backend_filter(filter: &gateway::GRPCRouteRulesBackendRefsFilters) -> Result {
+    validate_filter!(filter)
+}
@@ -0,0 +1,220 @@
+use super::{validate_header_name, validate_header_value, validate_regex, Result, Unsupported};
+use crate::{gateway, policy::httproute::HttpRouteFilter, policy::httproute::HttpRouteRule};
+
+/// Checks a RequestRedirect filter's path modifier and status code.
+macro_rules! validate_redirect {
+    ($redirect:expr) => {{
+        let redirect = $redirect;
+        if let Some(path) = &redirect.path {
+            let path = path
+                .replace_full_path
+                .as_deref()
+                .or(path.replace_prefix_match.as_deref())
+                .ok_or_else(|| {
+                    Unsupported(
+                        "RequestRedirect filter must contain either replace_full_path or replace_prefix_match".to_string(),
+                    )
+                })?;
+            if !path.starts_with('/') {
+                return Err(Unsupported(format!(
+                    "RequestRedirect filters may only contain absolute paths \
+                        (starting with '/'); {path:?} is not an absolute path"
+                )));
+            }
+        }
+        if let Some(code) = redirect.status_code {
+            let code = u16::try_from(code).map_err(Unsupported::new)?;
+            ::http::StatusCode::from_u16(code).map_err(Unsupported::new)?;
+        }
+        Ok(())
+    }};
+}
+
+/// Checks an HTTPRoute filter. Rule and backend filters have distinct types
+/// with the same fields.
+macro_rules! validate_filter {
+    ($filter:expr) => {{
+        let filter = $filter;
+        if let Some(modifier) = &filter.request_header_modifier {
+            return super::validate_header_modifier!(modifier);
+        }
+        if let Some(modifier) = &filter.response_header_modifier {
+            return super::validate_header_modifier!(modifier);
+        }
+        if let Some(redirect) = &filter.request_redirect {
+            return validate_redirect!(redirect);
+        }
+        if filter.request_mirror.is_some() {
+            return Err(Unsupported(
+                "RequestMirror filter is not supported".to_string(),
+            ));
+        }
+        if filter.url_rewrite.is_some() {
+            return Err(Unsupported(
+                "URLRewrite filter is not supported".to_string(),
+            ));
+        }
+        if filter.extension_ref.is_some() {
+            return Err(Unsupported(
+                "ExtensionRef filter is not supported".to_string(),
+            ));
+        }
+        Err(Unsupported("unknown filter".to_string()))
+    }};
+}
+
+/// Checks that a policy.linkerd.io HTTPRoute rule's matches and filters are
+/// supported.
+pub fn validate_linkerd_rule(rule: &HttpRouteRule) -> Result {
+    for m in rule.matches.iter().flatten() {
+        validate_match(m)?;
+    }
+    for filter in rule.filters.iter().flatten() {
+        validate_linkerd_filter(filter)?;
+    }
+    Ok(())
+}
+
+/// Checks that a gateway.networking.k8s.io HTTPRoute rule's matches and
+/// filters are supported.
+pub fn validate_gateway_rule(rule: &gateway::HTTPRouteRules) -> Result {
+    for m in rule.matches.iter().flatten() {
+        validate_match(m)?;
+    }
+    for filter in rule.filters.iter().flatten() {
+        validate_gateway_filter(filter)?;
+    }
+    Ok(())
+}
+
+/// Checks that a backendRef's filters are supported. Backends with
+/// unsupported filters are served as invalid, so requests routed to them fail.
+pub fn validate_backend_filters(backend: &gateway::HTTPRouteRulesBackendRefs) -> Result {
+    for filter in backend.filters.iter().flatten() {
+        validate_gateway_backend_filter(filter)
+            .map_err(|error| Unsupported(format!("unsupported backend filter: {error}")))?;
+    }
+    Ok(())
+}
+
+pub fn validate_match(m: &gateway::HTTPRouteRulesMatches) -> Result {
+    if let Some(path) = &m.path {
+        let value = path.value.as_deref().unwrap_or("/");
+        match path.r#type {
+            Some(gateway::HTTPRouteRulesMatchesPathType::RegularExpression) => {
+                validate_regex(value)?
+            }
+            Some(gateway::HTTPRouteRulesMatchesPathType::Exact)
+            | Some(gateway::HTTPRouteRulesMatchesPathType::PathPrefix)
+            | None => {
+                if !value.starts_with('/') {
+                    return Err(Unsupported(format!("HttpPathMatch paths must be absolute (begin with `/`); {value:?} is not an absolute path")));
+                }
+            }
+        }
+    }
+
+    for header in m.headers.iter().flatten() {
+        validate_header_name(&header.name)?;
+        match header.r#type {
+            Some(gateway::HTTPRouteRulesMatchesHeadersType::Exact) | None => {
+                validate_header_value(&header.value)?
+            }
+            Some(gateway::HTTPRouteRulesMatchesHeadersType::RegularExpression) => {
+                validate_regex(&header.value)?
+            }
+        }
+    }
+
+    for param in m.query_params.iter().flatten() {
+        if param.r#type == Some(gateway::HTTPRouteRulesMatchesQueryParamsType::RegularExpression) {
+            validate_regex(&param.value)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_linkerd_filter(filter: &HttpRouteFilter) -> Result {
+    match filter {
+        HttpRouteFilter::RequestHeaderModifier {
+            request_header_modifier,
+        } => super::validate_header_modifier!(request_header_modifier),
+        HttpRouteFilter::ResponseHeaderModifier {
+            response_header_modifier,
+        } => super::validate_header_modifier!(response_header_modifier),
+        HttpRouteFilter::RequestRedirect { request_redirect } => {
+            validate_redirect!(request_redirect)
+        }
+    }
+}
+
+pub fn validate_gateway_filter(filter: &gateway::HTTPRouteRulesFilters) -> Result {
+    validate_filter!(filter)
+}
+
+pub fn validate_gateway_backend_filter(
+    filter: &gateway::HTTPRouteRulesBackendRefsFilters,
+) -> Result {
+    validate_filter!(filter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_modifier_names_and_values() {
+        let filter = |name: &str, value: &str| gateway::HTTPRouteRulesFilters {
+            request_header_modifier: Some(gateway::HTTPRouteRulesFiltersRequestHeaderModifier {
+                set: Some(vec![
+                    gateway::HTTPRouteRulesFiltersRequestHeaderModifierSet {
+                        name: name.to_string(),
+                        value: value.to_string(),
+                    },
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(validate_gateway_filter(&filter("x-foo", "bar")), Ok(()));
+        assert!(validate_gateway_filter(&filter("x foo", "bar")).is_err());
+        assert!(validate_gateway_filter(&filter("x-foo", "bar\n")).is_err());
+    }
+
+    #[test]
+    fn redirect_paths_and_status_codes() {
+        let redirect =
+            |path: Option<&str>, status_code: Option<i64>| HttpRouteFilter::RequestRedirect {
+                request_redirect: gateway::HTTPRouteRulesFiltersRequestRedirect {
+                    path: path.map(|path| gateway::HTTPRouteRulesFiltersRequestRedirectPath {
+                        replace_full_path: Some(path.to_string()),
+                        ..Default::default()
+                    }),
+                    status_code,
+                    ..Default::default()
+                },
+            };
+        assert_eq!(
+            validate_linkerd_filter(&redirect(Some("/foo"), Some(301))),
+            Ok(())
+        );
+        assert!(validate_linkerd_filter(&redirect(Some("foo"), None)).is_err());
+        assert!(validate_linkerd_filter(&redirect(None, Some(99))).is_err());
+        assert!(validate_linkerd_filter(&redirect(None, Some(70000))).is_err());
+    }
+
+    #[test]
+    fn unsupported_filters() {
+        let filter = gateway::HTTPRouteRulesBackendRefsFilters {
+            url_rewrite: Some(Default::default()),
+            ..Default::default()
+        };
+        assert_eq!(
+            validate_gateway_backend_filter(&filter),
+            Err(Unsupported(
+                "URLRewrite filter is not supported".to_string()
+            ))
+        );
+    }
+}
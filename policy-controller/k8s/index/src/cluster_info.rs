@@ -1,6 +1,6 @@
 use std::{num::NonZeroU16, sync::Arc};
 
-use crate::{ports::PortSet, DefaultPolicy};
+use crate::{ports::PortSet, DefaultPolicy, NamespaceScope};
 use linkerd_policy_controller_core::IpNet;
 use tokio::time;
 
@@ -36,6 +36,9 @@ pub struct ClusterInfo {
     /// The namespace that is designated for egress configuration
     /// affecting all workloads across the cluster
     pub global_egress_network_namespace: Arc<String>,
+
+    /// The namespaces whose policy resources are indexed.
+    pub namespace_scope: NamespaceScope,
 }
 
 impl ClusterInfo {
@@ -56,4 +59,15 @@ impl ClusterInfo {
     pub(crate) fn service_dns_authority(&self, ns: &str, svc: &str, port: NonZeroU16) -> String {
         format!("{}.{}.svc.{}:{port}", svc, ns, self.dns_domain)
     }
+
+    /// The authority through which a multicluster `ServiceImport` is reached.
+    /// The `clusterset.local` zone is fixed by the Multi-Cluster Services API.
+    pub(crate) fn service_import_dns_authority(
+        &self,
+        ns: &str,
+        svc: &str,
+        port: NonZeroU16,
+    ) -> String {
+        format!("{svc}.{ns}.svc.clusterset.local:{port}")
+    }
 }
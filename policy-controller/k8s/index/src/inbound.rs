@@ -8,7 +8,7 @@ mod server;
 pub mod server_authorization;
 mod workload;
 
-pub use index::{metrics, Index, SharedIndex};
+pub use index::{metrics, InboundServerReport, Index, Rejection, SharedIndex};
 
 #[cfg(test)]
 mod tests;
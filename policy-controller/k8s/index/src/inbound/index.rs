@@ -1,10 +1,12 @@
 //! Keeps track of `Pod`, `Server`, and `ServerAuthorization` resources to
 //! provide a dynamic server configuration for all known ports on all pods.
 //!
-//! The `Index` type exposes a single public method: `Index::pod_server_rx`,
-//! which is used to lookup pod/ports (i.e. by the gRPC API). Otherwise, it
-//! implements `kubert::index::IndexNamespacedResource` for the indexed
-//! kubernetes resources.
+//! The `Index` type exposes `Index::pod_server_rx`, which is used to lookup
+//! pod/ports (i.e. by the gRPC API), and `Index::pod_server` and
+//! `Index::pod_server_report`, which describe a pod/port's server without
+//! watching it (i.e. for the admin server). Otherwise, it implements
+//! `kubert::index::IndexNamespacedResource` for the indexed kubernetes
+//! resources.
 
 use super::{
     authorization_policy, meshtls_authentication, network_authentication, ratelimit_policy,
@@ -23,13 +25,13 @@ use linkerd_policy_controller_core::{
         InboundRouteRule, InboundServer, Limit, Override, ProxyProtocol, RateLimit, RouteRef,
         ServerRef,
     },
-    routes::{GroupKindName, HttpRouteMatch, Method, PathMatch},
+    routes::{GroupKindName, GroupKindNamespaceName, HttpRouteMatch, Method, PathMatch},
     IdentityMatch, Ipv4Net, Ipv6Net, NetworkMatch,
 };
 use linkerd_policy_controller_k8s_api::{
     self as k8s, gateway,
     policy::server::{Port, Selector},
-    ResourceExt,
+    Resource, ResourceExt,
 };
 use parking_lot::RwLock;
 use std::{
@@ -54,6 +56,29 @@ pub struct Index {
     cluster_info: Arc<ClusterInfo>,
     namespaces: NamespaceIndex,
     authentications: AuthenticationNsIndex,
+
+    /// Policy resources that could not be indexed, with the reason they were
+    /// rejected.
+    rejections: HashMap<GroupKindNamespaceName, String>,
+}
+
+/// A policy resource that was not applied, along with the reason why.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rejection {
+    pub resource: GroupKindNamespaceName,
+    pub reason: String,
+}
+
+/// Describes the server computed for a pod/port.
+///
+/// The resources that contributed to the server are referenced by the server
+/// itself. `rejected` lists the policy resources in the pod's namespace that
+/// were not applied, either because they are invalid or because they could
+/// not be applied to this server.
+#[derive(Clone, Debug)]
+pub struct InboundServerReport {
+    pub server: InboundServer,
+    pub rejected: Vec<Rejection>,
 }
 
 /// Holds all `Pod`, `Server`, and `ServerAuthorization` indices by-namespace.
@@ -95,6 +120,9 @@ struct PodIndex {
 struct Pod {
     meta: workload::Meta,
 
+    /// The mTLS identity of the pod's proxy, derived from its ServiceAccount.
+    identity: String,
+
     /// The pod's named container ports. Used by `Server` port selectors.
     ///
     /// A pod may have multiple ports with the same name. E.g., each container
@@ -144,6 +172,9 @@ struct ExternalWorkloadIndex {
 struct ExternalWorkload {
     meta: workload::Meta,
 
+    /// The mTLS identity of the workload's proxy.
+    identity: String,
+
     // The workload's named container ports. Used by `Server` port selectors.
     //
     // A workload will not have multiple ports with the same name, e.g. two
@@ -192,6 +223,7 @@ impl Index {
                 by_ns: HashMap::default(),
             },
             authentications: AuthenticationNsIndex::default(),
+            rejections: HashMap::default(),
         }))
     }
 
@@ -221,6 +253,88 @@ impl Index {
             .subscribe())
     }
 
+    /// Returns a pod:port's current server.
+    ///
+    /// An error is returned if the pod is not found. If the port is not found,
+    /// the default server is returned; unlike `pod_server_rx`, the port is not
+    /// added to the index.
+    pub fn pod_server(
+        &self,
+        namespace: &str,
+        pod: &str,
+        port: NonZeroU16,
+    ) -> Result<InboundServer> {
+        let pod = self
+            .namespaces
+            .by_ns
+            .get(namespace)
+            .ok_or_else(|| anyhow::anyhow!("namespace not found: {namespace}"))?
+            .pods
+            .by_name
+            .get(pod)
+            .ok_or_else(|| anyhow::anyhow!("pod {pod}.{namespace} not found"))?;
+        Ok(pod.port_server(port, &self.cluster_info))
+    }
+
+    /// Describes a pod:port's server and the policy resources that were
+    /// rejected while computing it.
+    ///
+    /// An error is returned if the pod is not found. If the port is not found,
+    /// the default server is described.
+    pub fn pod_server_report(
+        &self,
+        namespace: &str,
+        pod: &str,
+        port: NonZeroU16,
+    ) -> Result<InboundServerReport> {
+        let server = self.pod_server(namespace, pod, port)?;
+
+        let mut rejected = self
+            .rejections
+            .iter()
+            .filter(|(resource, _)| resource.namespace == namespace)
+            .map(|(resource, reason)| Rejection {
+                resource: resource.clone(),
+                reason: reason.clone(),
+            })
+            .collect::<Vec<_>>();
+        if let ServerRef::Server(ref name) = server.reference {
+            if let Some(ns) = self.namespaces.by_ns.get(namespace) {
+                rejected.extend(ns.policy.server_rejections(name, &self.authentications));
+            }
+        }
+        rejected.sort_by(|a, b| {
+            (&a.resource.kind, &a.resource.name).cmp(&(&b.resource.kind, &b.resource.name))
+        });
+
+        Ok(InboundServerReport { server, rejected })
+    }
+
+    /// Returns the mTLS identity of a pod's proxy, if the pod is known.
+    pub fn pod_identity(&self, namespace: &str, pod: &str) -> Option<&str> {
+        let pod = self
+            .namespaces
+            .by_ns
+            .get(namespace)?
+            .pods
+            .by_name
+            .get(pod)?;
+        Some(&pod.identity)
+    }
+
+    /// Returns the mTLS identity of an external workload's proxy, if the
+    /// workload is known.
+    pub fn external_workload_identity(&self, namespace: &str, workload: &str) -> Option<&str> {
+        let workload = self
+            .namespaces
+            .by_ns
+            .get(namespace)?
+            .external_workloads
+            .by_name
+            .get(workload)?;
+        Some(&workload.identity)
+    }
+
     /// Obtains an external_workload:port's server receiver.
     ///
     /// An error is returned if the external workload is not found. If the port
@@ -257,6 +371,12 @@ impl Index {
         namespace: String,
         f: impl FnOnce(&mut Namespace) -> bool,
     ) {
+        // Pods in namespaces outside of the scope are served default policy,
+        // so their policy resources are not indexed.
+        if !self.cluster_info.namespace_scope.contains(&namespace) {
+            tracing::debug!(%namespace, "Ignoring policy outside of namespace scope");
+            return;
+        }
         self.namespaces
             .get_or_default_with_reindex(namespace, &self.authentications, f)
     }
@@ -268,6 +388,35 @@ impl Index {
         }
     }
 
+    /// Records that a resource could not be indexed.
+    fn reject<R>(&mut self, namespace: &str, name: &str, error: impl std::fmt::Display)
+    where
+        R: Resource<DynamicType = ()>,
+    {
+        let resource = name.gkn::<R>().namespaced(namespace.to_string());
+        self.rejections.insert(resource, error.to_string());
+    }
+
+    /// Clears a resource's rejection, if one was recorded.
+    fn unreject<R>(&mut self, namespace: &str, name: &str)
+    where
+        R: Resource<DynamicType = ()>,
+    {
+        let resource = name.gkn::<R>().namespaced(namespace.to_string());
+        self.rejections.remove(&resource);
+    }
+
+    /// Clears all rejections for a kind of resource. Used when the full set of
+    /// resources is reset.
+    fn unreject_all<R>(&mut self)
+    where
+        R: Resource<DynamicType = ()>,
+    {
+        let (group, kind) = (R::group(&()), R::kind(&()));
+        self.rejections
+            .retain(|resource, _| resource.group != group || resource.kind != kind);
+    }
+
     fn apply_http_route<R>(&mut self, route: R)
     where
         R: ResourceExt<DynamicType = ()>,
@@ -283,9 +432,11 @@ impl Index {
             Ok(binding) => binding,
             Err(error) => {
                 tracing::info!(%ns, %name, %error, "Ignoring HTTPRoute");
+                self.reject::<R>(&ns, &name, error);
                 return;
             }
         };
+        self.unreject::<R>(&ns, &name);
 
         self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_http_route(gkn, route_binding))
     }
@@ -297,6 +448,7 @@ impl Index {
         <RouteBinding<HttpRoute> as TryFrom<R>>::Error: std::fmt::Display,
     {
         let _span = info_span!("httproute reset").entered();
+        self.unreject_all::<R>();
 
         // Aggregate all of the updates by namespace so that we only reindex
         // once per namespace.
@@ -310,6 +462,7 @@ impl Index {
                 Ok(binding) => binding,
                 Err(error) => {
                     tracing::info!(ns = %namespace, %name, %error, "Ignoring HTTPRoute");
+                    self.reject::<R>(&namespace, &name, error);
                     continue;
                 }
             };
@@ -361,6 +514,7 @@ impl Index {
 
     fn delete_http_route(&mut self, ns: String, gkn: GroupKindName) {
         let _span = info_span!("delete httproute", %ns, route = ?gkn).entered();
+        self.rejections.remove(&gkn.clone().namespaced(ns.clone()));
         self.ns_with_reindex(ns, |ns| ns.policy.http_routes.remove(&gkn).is_some())
     }
 
@@ -374,9 +528,11 @@ impl Index {
             Ok(binding) => binding,
             Err(error) => {
                 tracing::info!(%ns, %name, %error, "Ignoring GrpcRoute");
+                self.reject::<gateway::GRPCRoute>(&ns, &name, error);
                 return;
             }
         };
+        self.unreject::<gateway::GRPCRoute>(&ns, &name);
 
         self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_grpc_route(gkn, route_binding))
     }
@@ -387,6 +543,8 @@ impl Index {
         routes: Vec<gateway::GRPCRoute>,
         deleted: HashMap<String, HashSet<String>>,
     ) {
+        self.unreject_all::<gateway::GRPCRoute>();
+
         // Aggregate all of the updates by namespace so that we only reindex
         // once per namespace.
         type Ns = NsUpdate<GroupKindName, RouteBinding<GrpcRoute>>;
@@ -399,6 +557,7 @@ impl Index {
                 Ok(binding) => binding,
                 Err(error) => {
                     tracing::info!(ns = %namespace, %name, %error, "Ignoring GrpcRoute");
+                    self.reject::<gateway::GRPCRoute>(&namespace, &name, error);
                     continue;
                 }
             };
@@ -446,6 +605,7 @@ impl Index {
 
     fn delete_grpc_route(&mut self, ns: String, gkn: GroupKindName) {
         let _span = info_span!("delete grpcroute", %ns, route = ?gkn).entered();
+        self.rejections.remove(&gkn.clone().namespaced(ns.clone()));
         self.ns_with_reindex(ns, |ns| ns.policy.grpc_routes.remove(&gkn).is_some())
     }
 }
@@ -467,13 +627,20 @@ impl kubert::index::IndexNamespacedResource<k8s::Pod> for Index {
             .map(workload::pod_http_probes)
             .unwrap_or_default();
 
+        let identity = self.cluster_info.service_account_identity(
+            &namespace,
+            pod.spec
+                .as_ref()
+                .and_then(|spec| spec.service_account_name.as_deref())
+                .unwrap_or("default"),
+        );
         let meta = workload::Meta::from_metadata(pod.metadata);
 
         // Add or update the pod. If the pod was not already present in the
         // index with the same metadata, index it against the policy resources,
         // updating its watches.
         let ns = self.namespaces.get_or_default(namespace);
-        match ns.pods.update(name, meta, port_names, probes) {
+        match ns.pods.update(name, meta, identity, port_names, probes) {
             Ok(None) => {}
             Ok(Some(pod)) => pod.reindex_servers(&ns.policy, &self.authentications),
             Err(error) => {
@@ -509,6 +676,7 @@ impl kubert::index::IndexNamespacedResource<k8s::external_workload::ExternalWork
         // Note: external workloads do not have any probe paths to synthesise
         // default policies for.
         let port_names = workload::external_tcp_ports_by_name(&ext_workload.spec);
+        let identity = ext_workload.spec.mesh_tls.identity.clone();
         let meta = workload::Meta::from_metadata(ext_workload.metadata);
 
         // Add or update the workload.
@@ -516,7 +684,10 @@ impl kubert::index::IndexNamespacedResource<k8s::external_workload::ExternalWork
         // If the resource is present in the index, but its metadata has
         // changed, then it means the watches need to get an update.
         let ns = self.namespaces.get_or_default(ns);
-        match ns.external_workloads.update(name, meta, port_names) {
+        match ns
+            .external_workloads
+            .update(name, meta, identity, port_names)
+        {
             // No update
             Ok(None) => {}
             // Update, so re-index
@@ -622,15 +793,22 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::ServerAuthorization> fo
         let _span = info_span!("apply", %ns, %name).entered();
 
         match server_authorization::ServerAuthz::from_resource(saz, &self.cluster_info) {
-            Ok(meta) => self.ns_or_default_with_reindex(ns, move |ns| {
-                ns.policy.update_server_authz(name, meta)
-            }),
-            Err(error) => tracing::warn!(%error, "Illegal server authorization update"),
+            Ok(meta) => {
+                self.unreject::<k8s::policy::ServerAuthorization>(&ns, &name);
+                self.ns_or_default_with_reindex(ns, move |ns| {
+                    ns.policy.update_server_authz(name, meta)
+                })
+            }
+            Err(error) => {
+                tracing::warn!(%error, "Illegal server authorization update");
+                self.reject::<k8s::policy::ServerAuthorization>(&ns, &name, error);
+            }
         }
     }
 
     fn delete(&mut self, ns: String, name: String) {
         let _span = info_span!("delete", %ns, %name).entered();
+        self.unreject::<k8s::policy::ServerAuthorization>(&ns, &name);
         self.ns_with_reindex(ns, |ns| {
             ns.policy.server_authorizations.remove(&name).is_some()
         })
@@ -642,6 +820,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::ServerAuthorization> fo
         deleted: HashMap<String, HashSet<String>>,
     ) {
         let _span = info_span!("reset");
+        self.unreject_all::<k8s::policy::ServerAuthorization>();
 
         // Aggregate all of the updates by namespace so that we only reindex
         // once per namespace.
@@ -659,7 +838,8 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::ServerAuthorization> fo
                     .added
                     .push((name, saz)),
                 Err(error) => {
-                    tracing::warn!(ns = %namespace, %name, %error, "Illegal server authorization update")
+                    tracing::warn!(ns = %namespace, %name, %error, "Illegal server authorization update");
+                    self.reject::<k8s::policy::ServerAuthorization>(&namespace, &name, error);
                 }
             }
         }
@@ -706,9 +886,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::AuthorizationPolicy> fo
             Ok(spec) => spec,
             Err(error) => {
                 tracing::warn!(%error, "Invalid authorization policy");
+                self.reject::<k8s::policy::AuthorizationPolicy>(&ns, &name, error);
                 return;
             }
         };
+        self.unreject::<k8s::policy::AuthorizationPolicy>(&ns, &name);
 
         self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_authz_policy(name, spec))
     }
@@ -716,6 +898,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::AuthorizationPolicy> fo
     fn delete(&mut self, ns: String, ap: String) {
         let _span = info_span!("delete", %ns, %ap).entered();
         tracing::trace!(name = %ap, "Delete");
+        self.unreject::<k8s::policy::AuthorizationPolicy>(&ns, &ap);
         self.ns_with_reindex(ns, |ns| {
             ns.policy.authorization_policies.remove(&ap).is_some()
         })
@@ -729,6 +912,8 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::AuthorizationPolicy> fo
         let _span = info_span!("reset");
 
         tracing::trace!(?deleted, ?policies, "Reset");
+        self.unreject_all::<k8s::policy::AuthorizationPolicy>();
+
         // Aggregate all of the updates by namespace so that we only reindex
         // once per namespace.
         type Ns = NsUpdate<String, authorization_policy::Spec>;
@@ -745,7 +930,8 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::AuthorizationPolicy> fo
                     .added
                     .push((name, spec)),
                 Err(error) => {
-                    tracing::warn!(ns = %namespace, %name, %error, "Illegal server authorization update")
+                    tracing::warn!(ns = %namespace, %name, %error, "Illegal server authorization update");
+                    self.reject::<k8s::policy::AuthorizationPolicy>(&namespace, &name, error);
                 }
             }
         }
@@ -795,9 +981,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::MeshTLSAuthentication>
             Ok(spec) => spec,
             Err(error) => {
                 tracing::warn!(%error, "Invalid MeshTLSAuthentication");
+                self.reject::<k8s::policy::MeshTLSAuthentication>(&ns, &name, error);
                 return;
             }
         };
+        self.unreject::<k8s::policy::MeshTLSAuthentication>(&ns, &name);
 
         if self.authentications.update_meshtls(ns, name, spec) {
             self.reindex_all();
@@ -807,6 +995,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::MeshTLSAuthentication>
     fn delete(&mut self, ns: String, name: String) {
         let _span = info_span!("delete", %ns, %name).entered();
 
+        self.unreject::<k8s::policy::MeshTLSAuthentication>(&ns, &name);
         if let Entry::Occupied(mut ns) = self.authentications.by_ns.entry(ns) {
             tracing::debug!("Deleting MeshTLSAuthentication");
             ns.get_mut().network.remove(&name);
@@ -825,6 +1014,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::MeshTLSAuthentication>
         deleted: HashMap<String, HashSet<String>>,
     ) {
         let _span = info_span!("reset");
+        self.unreject_all::<k8s::policy::MeshTLSAuthentication>();
 
         let mut changed = false;
 
@@ -840,6 +1030,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::MeshTLSAuthentication>
                 Ok(spec) => spec,
                 Err(error) => {
                     tracing::warn!(ns = %namespace, %name, %error, "Invalid MeshTLSAuthentication");
+                    self.reject::<k8s::policy::MeshTLSAuthentication>(&namespace, &name, error);
                     continue;
                 }
             };
@@ -872,9 +1063,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::NetworkAuthentication>
             Ok(spec) => spec,
             Err(error) => {
                 tracing::warn!(%error, "Invalid NetworkAuthentication");
+                self.reject::<k8s::policy::NetworkAuthentication>(&ns, &name, error);
                 return;
             }
         };
+        self.unreject::<k8s::policy::NetworkAuthentication>(&ns, &name);
 
         if self.authentications.update_network(ns, name, spec) {
             self.reindex_all();
@@ -884,6 +1077,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::NetworkAuthentication>
     fn delete(&mut self, ns: String, name: String) {
         let _span = info_span!("delete", %ns, %name).entered();
 
+        self.unreject::<k8s::policy::NetworkAuthentication>(&ns, &name);
         if let Entry::Occupied(mut ns) = self.authentications.by_ns.entry(ns) {
             tracing::debug!("Deleting MeshTLSAuthentication");
 
@@ -903,6 +1097,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::NetworkAuthentication>
         deleted: HashMap<String, HashSet<String>>,
     ) {
         let _span = info_span!("reset");
+        self.unreject_all::<k8s::policy::NetworkAuthentication>();
 
         let mut changed = false;
 
@@ -915,6 +1110,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::NetworkAuthentication>
                 Ok(spec) => spec,
                 Err(error) => {
                     tracing::warn!(ns = %namespace, %name, %error, "Invalid NetworkAuthentication");
+                    self.reject::<k8s::policy::NetworkAuthentication>(&namespace, &name, error);
                     return;
                 }
             };
@@ -947,9 +1143,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::HttpLocalRateLimitPolic
             Ok(spec) => spec,
             Err(error) => {
                 tracing::warn!(%error, "Invalid rate limit policy");
+                self.reject::<k8s::policy::HttpLocalRateLimitPolicy>(&ns, &name, error);
                 return;
             }
         };
+        self.unreject::<k8s::policy::HttpLocalRateLimitPolicy>(&ns, &name);
 
         self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_ratelimit_policy(name, spec))
     }
@@ -957,6 +1155,7 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::HttpLocalRateLimitPolic
     fn delete(&mut self, ns: String, ap: String) {
         let _span = info_span!("delete", %ns, %ap).entered();
         tracing::trace!(name = %ap, "Delete");
+        self.unreject::<k8s::policy::HttpLocalRateLimitPolicy>(&ns, &ap);
         self.ns_with_reindex(ns, |ns| ns.policy.ratelimit_policies.remove(&ap).is_some())
     }
 
@@ -968,6 +1167,8 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::HttpLocalRateLimitPolic
         let _span = info_span!("reset");
 
         tracing::trace!(?deleted, ?policies, "Reset");
+        self.unreject_all::<k8s::policy::HttpLocalRateLimitPolicy>();
+
         // Aggregate all of the updates by namespace so that we only reindex
         // once per namespace.
         type Ns = NsUpdate<String, ratelimit_policy::Spec>;
@@ -984,7 +1185,8 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::HttpLocalRateLimitPolic
                     .added
                     .push((name, spec)),
                 Err(error) => {
-                    tracing::warn!(ns = %namespace, %name, %error, "Illegal server ratelimit update")
+                    tracing::warn!(ns = %namespace, %name, %error, "Illegal server ratelimit update");
+                    self.reject::<k8s::policy::HttpLocalRateLimitPolicy>(&namespace, &name, error);
                 }
             }
         }
@@ -1176,12 +1378,14 @@ impl PodIndex {
         &mut self,
         name: String,
         meta: workload::Meta,
+        identity: String,
         port_names: HashMap<String, PortSet>,
         probes: PortMap<BTreeSet<String>>,
     ) -> Result<Option<&mut Pod>> {
         let pod = match self.by_name.entry(name.clone()) {
             Entry::Vacant(entry) => entry.insert(Pod {
                 meta,
+                identity,
                 port_names,
                 port_servers: PortMap::default(),
                 probes,
@@ -1195,6 +1399,9 @@ impl PodIndex {
                 if pod.port_names != port_names {
                     bail!("pod {name} port names must not change");
                 }
+                if pod.identity != identity {
+                    bail!("pod {name} service account must not change");
+                }
 
                 // If there aren't meaningful changes, then don't bother doing
                 // any more work.
@@ -1407,6 +1614,24 @@ impl Pod {
             }
         }
     }
+
+    /// Returns the port's current server, or the server it would have by
+    /// default if it is not yet known.
+    fn port_server(&self, port: NonZeroU16, config: &ClusterInfo) -> InboundServer {
+        match self.port_servers.get(&port) {
+            Some(ps) => ps.watch.borrow().clone(),
+            None => PolicyIndex::default_inbound_server(
+                port,
+                &self.meta.settings,
+                self.probes
+                    .get(&port)
+                    .into_iter()
+                    .flatten()
+                    .map(|p| p.as_str()),
+                config,
+            ),
+        }
+    }
 }
 
 // === impl ExternalWorkloadIndex ===
@@ -1429,17 +1654,22 @@ impl ExternalWorkloadIndex {
         &mut self,
         name: String,
         meta: workload::Meta,
+        identity: String,
         port_names: HashMap<String, NonZeroU16>,
     ) -> Result<Option<&mut ExternalWorkload>> {
         let workload = match self.by_name.entry(name.clone()) {
             Entry::Vacant(entry) => entry.insert(ExternalWorkload {
                 meta,
+                identity,
                 port_names,
                 port_servers: PortMap::default(),
             }),
             Entry::Occupied(entry) => {
                 let workload = entry.into_mut();
 
+                // The identity does not affect the workload's servers.
+                workload.identity = identity;
+
                 if workload.meta == meta && workload.port_names == port_names {
                     tracing::debug!(external_workload = %name, "No changes");
                     return Ok(None);
@@ -1883,6 +2113,75 @@ impl PolicyIndex {
         authzs
     }
 
+    /// Returns the policy resources that target a server (or its routes) but
+    /// could not be applied to it.
+    fn server_rejections(
+        &self,
+        server_name: &str,
+        authentications: &AuthenticationNsIndex,
+    ) -> Vec<Rejection> {
+        let rejection = |resource: GroupKindName, reason: String| Rejection {
+            resource: resource.namespaced(self.namespace.clone()),
+            reason,
+        };
+        let mut rejected = Vec::new();
+
+        let http_routes = self
+            .http_routes
+            .iter()
+            .filter(|(_, route)| route.selects_server(server_name))
+            .map(|(gkn, route)| (gkn, route.accepted_by_server(server_name)));
+        let grpc_routes = self
+            .grpc_routes
+            .iter()
+            .filter(|(_, route)| route.selects_server(server_name))
+            .map(|(gkn, route)| (gkn, route.accepted_by_server(server_name)));
+        let mut routes = Vec::new();
+        for (gkn, accepted) in http_routes.chain(grpc_routes) {
+            if accepted {
+                routes.push(gkn);
+            } else {
+                rejected.push(rejection(
+                    gkn.clone(),
+                    format!("route is not accepted by server {server_name}"),
+                ));
+            }
+        }
+
+        for (name, spec) in self.authorization_policies.iter() {
+            let targets_server = match &spec.target {
+                authorization_policy::Target::Server(name) => name == server_name,
+                authorization_policy::Target::Namespace => true,
+                authorization_policy::Target::HttpRoute(gkn)
+                | authorization_policy::Target::GrpcRoute(gkn) => {
+                    routes.iter().any(|r| r.eq_ignore_ascii_case(gkn))
+                }
+            };
+            if !targets_server {
+                continue;
+            }
+            if let Err(error) = self.policy_client_authz(spec, authentications) {
+                rejected.push(rejection(
+                    name.gkn::<k8s::policy::AuthorizationPolicy>(),
+                    error.to_string(),
+                ));
+            }
+        }
+
+        for (name, spec) in self.ratelimit_policies.iter() {
+            if matches!(spec.target, ratelimit_policy::Target::Server(ref n) if n == server_name)
+                && !spec.accepted_by_server(server_name)
+            {
+                rejected.push(rejection(
+                    name.gkn::<k8s::policy::HttpLocalRateLimitPolicy>(),
+                    format!("policy is not accepted by server {server_name}"),
+                ));
+            }
+        }
+
+        rejected
+    }
+
     fn client_ratelimit(&self, server_name: &str) -> Option<RateLimit> {
         use ratelimit_policy::{ClientRef, Target};
 
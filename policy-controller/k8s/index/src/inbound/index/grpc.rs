@@ -23,17 +23,11 @@ impl TryFrom<gateway::GRPCRoute> for RouteBinding<GrpcRoute> {
             .map(crate::routes::host_match)
             .collect();
 
-        let rules = route
-            .spec
-            .rules
-            .into_iter()
-            .flatten()
-            .map(
-                |gateway::GRPCRouteRules {
-                     matches, filters, ..
-                 }| { try_grpc_rule(matches, filters, try_grpc_filter) },
-            )
-            .collect::<Result<_>>()?;
+        let rules = crate::routes::valid_rules(route.spec.rules.into_iter().flatten().map(
+            |gateway::GRPCRouteRules {
+                 matches, filters, ..
+             }| { try_grpc_rule(matches, filters, try_grpc_filter) },
+        ))?;
 
         let statuses = route
             .status
@@ -23,17 +23,11 @@ impl TryFrom<gateway::HTTPRoute> for RouteBinding<HttpRoute> {
             .map(crate::routes::host_match)
             .collect();
 
-        let rules = route
-            .spec
-            .rules
-            .into_iter()
-            .flatten()
-            .map(
-                |gateway::HTTPRouteRules {
-                     matches, filters, ..
-                 }| try_http_rule(matches, filters, try_gateway_filter),
-            )
-            .collect::<Result<_>>()?;
+        let rules = crate::routes::valid_rules(route.spec.rules.into_iter().flatten().map(
+            |gateway::HTTPRouteRules {
+                 matches, filters, ..
+             }| try_http_rule(matches, filters, try_gateway_filter),
+        ))?;
 
         let statuses = route
             .status
@@ -67,17 +61,11 @@ impl TryFrom<policy::HttpRoute> for RouteBinding<HttpRoute> {
             .map(crate::routes::host_match)
             .collect();
 
-        let rules = route
-            .spec
-            .rules
-            .into_iter()
-            .flatten()
-            .map(
-                |policy::httproute::HttpRouteRule {
-                     matches, filters, ..
-                 }| { try_http_rule(matches, filters, try_policy_filter) },
-            )
-            .collect::<Result<_>>()?;
+        let rules = crate::routes::valid_rules(route.spec.rules.into_iter().flatten().map(
+            |policy::httproute::HttpRouteRule {
+                 matches, filters, ..
+             }| { try_http_rule(matches, filters, try_policy_filter) },
+        ))?;
 
         let statuses = route
             .status
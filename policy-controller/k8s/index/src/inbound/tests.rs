@@ -9,7 +9,7 @@ use crate::{
     defaults::DefaultPolicy,
     inbound::index::{Index, SharedIndex},
     inbound::server_authorization::ServerSelector,
-    ClusterInfo,
+    ClusterInfo, NamespaceScope,
 };
 use ahash::AHashMap as HashMap;
 use kubert::index::IndexNamespacedResource;
@@ -39,6 +39,36 @@ fn pod_must_exist_for_lookup() {
         .expect_err("pod-0.ns-0 must not exist");
 }
 
+#[test]
+fn ignores_server_outside_namespace_scope() {
+    let test =
+        TestConfig::default().with_namespace_scope(NamespaceScope::new([], ["ns-0".to_string()]));
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    let mut rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(*rx.borrow_and_update(), test.default_server());
+
+    // Pods in excluded namespaces keep the default policy.
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-8080",
+        Port::Number(8080.try_into().unwrap()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    ));
+    assert!(!rx.has_changed().unwrap());
+    assert_eq!(*rx.borrow(), test.default_server());
+}
+
 struct TestConfig {
     index: SharedIndex,
     detect_timeout: time::Duration,
@@ -227,6 +257,7 @@ impl TestConfig {
             default_opaque_ports: Default::default(),
             probe_networks,
             global_egress_network_namespace: Arc::new("linkerd-egress".to_string()),
+            namespace_scope: Default::default(),
         };
         let index = Index::shared(cluster.clone());
         Self {
@@ -238,6 +269,12 @@ impl TestConfig {
         }
     }
 
+    fn with_namespace_scope(mut self, namespace_scope: NamespaceScope) -> Self {
+        self.cluster.namespace_scope = namespace_scope;
+        self.index = Index::shared(self.cluster.clone());
+        self
+    }
+
     fn default_server(&self) -> InboundServer {
         InboundServer {
             reference: ServerRef::Default(self.default_policy.as_str()),
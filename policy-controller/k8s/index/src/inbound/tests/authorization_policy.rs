@@ -404,6 +404,94 @@ fn authorization_policy_prevents_index_deletion() {
     );
 }
 
+#[test]
+fn reports_rejected_authorization_policies() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-8080",
+        Port::Number(8080.try_into().unwrap()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    ));
+
+    // This policy is valid, but it references an authentication that does
+    // not exist, so it cannot be applied to the server.
+    test.index.write().apply(mk_authorization_policy(
+        "ns-0",
+        "authz-foo",
+        Some("srv-8080"),
+        vec![NamespacedTargetRef {
+            group: Some("policy.linkerd.io".to_string()),
+            kind: "MeshTLSAuthentication".to_string(),
+            name: "mtls-foo".to_string(),
+            namespace: None,
+        }],
+    ));
+
+    // This policy is invalid, so it cannot be indexed at all.
+    let mut invalid = mk_authorization_policy("ns-0", "authz-bar", Some("srv-8080"), vec![]);
+    invalid.spec.target_ref.kind = "Pod".to_string();
+    test.index.write().apply(invalid);
+
+    let report = test
+        .index
+        .write()
+        .pod_server_report("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(
+        report.server.reference,
+        ServerRef::Server("srv-8080".to_string())
+    );
+    assert!(report.server.authorizations.is_empty());
+    let rejected = report
+        .rejected
+        .iter()
+        .map(|r| (r.resource.name.as_ref(), r.reason.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(rejected.len(), 2, "{rejected:?}");
+    assert_eq!(rejected[0].0, "authz-bar");
+    assert_eq!(
+        rejected[1],
+        (
+            "authz-foo",
+            "could not find MeshTLSAuthentication mtls-foo in namespace ns-0"
+        )
+    );
+
+    // Once the authentication exists, the policy applies and the invalid
+    // policy's rejection is cleared when it is deleted.
+    test.index.write().apply(mk_meshtls_authentication(
+        "ns-0",
+        "mtls-foo",
+        Some("foo.bar".to_string()),
+        None,
+    ));
+    <Index as IndexNamespacedResource<k8s::policy::AuthorizationPolicy>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "authz-bar".to_string(),
+    );
+    let report = test
+        .index
+        .write()
+        .pod_server_report("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert!(report.rejected.is_empty(), "{:?}", report.rejected);
+    assert!(report
+        .server
+        .authorizations
+        .contains_key(&AuthorizationRef::AuthorizationPolicy(
+            "authz-foo".to_string()
+        )));
+}
+
 fn mk_authorization_policy(
     ns: impl ToString,
     name: impl ToString,
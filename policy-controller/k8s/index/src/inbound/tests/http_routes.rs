@@ -1,5 +1,5 @@
 use super::*;
-use crate::routes::ExplicitGKN;
+use crate::{inbound::routes::RouteBinding, routes::ExplicitGKN};
 use linkerd_policy_controller_core::{
     routes::{HttpRouteMatch, Method, PathMatch},
     POLICY_CONTROLLER_NAME,
@@ -205,6 +205,28 @@ fn routes_created_for_probes() {
         .contains_key(&RouteRef::Default("probes")));
 }
 
+#[test]
+fn route_with_invalid_rule_keeps_valid_rules() {
+    let mut route = mk_route("ns-0", "route-foo", "srv-8080");
+    let rules = route.spec.rules.as_mut().unwrap();
+    let mut invalid = rules[0].clone();
+    invalid.matches.as_mut().unwrap()[0]
+        .path
+        .as_mut()
+        .unwrap()
+        .value = Some("foo".to_string());
+    rules.push(invalid.clone());
+
+    // Only the invalid rule is dropped.
+    let binding = RouteBinding::<HttpRoute>::try_from(route.clone())
+        .expect("route with a valid rule should be accepted");
+    assert_eq!(binding.route.rules.len(), 1);
+
+    // A route with no valid rules is rejected.
+    route.spec.rules = Some(vec![invalid]);
+    assert!(RouteBinding::<HttpRoute>::try_from(route).is_err());
+}
+
 fn mk_route(
     ns: impl ToString,
     name: impl ToString,
@@ -26,6 +26,7 @@
 mod cluster_info;
 mod defaults;
 pub mod inbound;
+mod namespace_scope;
 pub mod outbound;
 pub mod ports;
 pub mod routes;
@@ -33,3 +34,4 @@ pub mod routes;
 pub use cluster_info::ClusterInfo;
 pub use defaults::DefaultPolicy;
 pub use inbound::authorization_policy;
+pub use namespace_scope::NamespaceScope;
@@ -0,0 +1,62 @@
+/// Limits the namespaces whose policy resources are indexed.
+///
+/// Workloads in namespaces outside of the scope are still indexed, so that
+/// they are served default policy.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NamespaceScope {
+    /// When non-empty, only these namespaces are in scope.
+    include: Vec<String>,
+
+    /// Namespaces that are never in scope.
+    exclude: Vec<String>,
+}
+
+impl NamespaceScope {
+    pub fn new(
+        include: impl IntoIterator<Item = String>,
+        exclude: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self {
+            include: include.into_iter().collect(),
+            exclude: exclude.into_iter().collect(),
+        }
+    }
+
+    /// Returns true if policy resources in `namespace` should be indexed.
+    pub fn contains(&self, namespace: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|ns| ns == namespace))
+            && !self.exclude.iter().any(|ns| ns == namespace)
+    }
+
+    /// The namespaces that are never in scope.
+    pub fn excluded(&self) -> &[String] {
+        &self.exclude
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains() {
+        let all = NamespaceScope::default();
+        assert!(all.contains("ns-0"));
+
+        let include = NamespaceScope::new(["ns-0".to_string()], []);
+        assert!(include.contains("ns-0"));
+        assert!(!include.contains("ns-1"));
+
+        let exclude = NamespaceScope::new([], ["ns-0".to_string()]);
+        assert!(!exclude.contains("ns-0"));
+        assert!(exclude.contains("ns-1"));
+
+        let both = NamespaceScope::new(
+            ["ns-0".to_string(), "ns-1".to_string()],
+            ["ns-1".to_string()],
+        );
+        assert!(both.contains("ns-0"));
+        assert!(!both.contains("ns-1"));
+        assert!(!both.contains("ns-2"));
+    }
+}
@@ -1,6 +1,6 @@
 pub mod index;
 
-pub use index::{metrics, Index, ResourceRef, SharedIndex};
+pub use index::{metrics, Index, OutboundPolicyReport, ResourceRef, RouteRejection, SharedIndex};
 
 #[cfg(test)]
 mod tests;
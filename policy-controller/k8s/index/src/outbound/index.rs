@@ -16,10 +16,12 @@ use linkerd_policy_controller_core::{
 };
 use linkerd_policy_controller_k8s_api::{
     gateway,
+    multicluster::ServiceImport,
     policy::{self as linkerd_k8s_api, Cidr},
-    ResourceExt, Service,
+    EndpointSlice, Resource, ResourceExt, Service,
 };
 use parking_lot::RwLock;
+use reference_grant::{ReferenceGrants, Referrer};
 use std::{
     collections::hash_map::Entry, hash::Hash, net::IpAddr, num::NonZeroU16, str::FromStr,
     sync::Arc, time,
@@ -31,10 +33,24 @@ use tokio::sync::watch;
 pub struct Index {
     namespaces: NamespaceIndex,
     services_by_ip: HashMap<IpAddr, ServicePorts>,
+    // headless Services have no cluster IP, so they are indexed by the
+    // addresses of their endpoints instead. An endpoint may be selected by
+    // more than one headless Service, so each address holds the ports of
+    // every Service that selects it.
+    headless_services_by_ip: HashMap<IpAddr, HashMap<ResourceRef, PortSet>>,
+    headless_service_ports: HashMap<ResourceRef, PortSet>,
+    // endpoint addresses of headless Services, by EndpointSlice name.
+    headless_endpoints: HashMap<ResourceRef, HashMap<String, Vec<IpAddr>>>,
     egress_networks_by_ref: HashMap<ResourceRef, EgressNetwork>,
-    // holds information about resources. currently EgressNetworks and Services
+    // holds information about resources. currently EgressNetworks, Services
+    // and ServiceImports
     resource_info: HashMap<ResourceRef, ResourceInfo>,
 
+    reference_grants: ReferenceGrants,
+    // routes with backends in other namespaces, which are reindexed when
+    // ReferenceGrants change
+    cross_namespace_routes: HashMap<GroupKindNamespaceName, CrossNamespaceRoute>,
+
     cluster_networks: Vec<linkerd_k8s_api::Cidr>,
     global_egress_network_namespace: Arc<String>,
 
@@ -49,6 +65,7 @@ pub mod egress_network;
 pub mod grpc;
 pub mod http;
 pub mod metrics;
+mod reference_grant;
 pub mod tcp;
 pub(crate) mod tls;
 
@@ -58,6 +75,7 @@ pub type SharedIndex = Arc<RwLock<Index>>;
 pub enum ResourceKind {
     EgressNetwork,
     Service,
+    ServiceImport,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -67,6 +85,29 @@ pub struct ResourceRef {
     pub namespace: String,
 }
 
+/// Describes the outbound policy for a resource:port, along with the routes
+/// that target the resource but were not applied to it.
+#[derive(Clone, Debug)]
+pub struct OutboundPolicyReport {
+    pub policy: OutboundPolicy,
+    pub rejected_routes: Vec<RouteRejection>,
+}
+
+/// A route that was not applied to a parent, along with the reason why.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteRejection {
+    pub route: GroupKindNamespaceName,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug)]
+enum CrossNamespaceRoute {
+    Http(HttpRouteResource),
+    Grpc(gateway::GRPCRoute),
+    Tls(gateway::TLSRoute),
+    Tcp(gateway::TCPRoute),
+}
+
 #[derive(Debug)]
 struct ServicePorts {
     service: ResourceRef,
@@ -94,9 +135,16 @@ struct Namespace {
     service_grpc_routes: HashMap<String, RouteSet<GrpcRoute>>,
     service_tls_routes: HashMap<String, RouteSet<TlsRoute>>,
     service_tcp_routes: HashMap<String, RouteSet<TcpRoute>>,
+    /// Stores the routes that target a resource in this namespace but were
+    /// not applied to it, with the reason why, by the resource's name.
+    rejected_routes: HashMap<GroupKindNamespaceName, HashMap<String, String>>,
     namespace: Arc<String>,
 }
 
+/// The reason recorded for routes whose status shows they were not accepted by
+/// a parent.
+const NOT_ACCEPTED: &str = "route is not accepted by its parent";
+
 #[derive(Debug)]
 struct ResourceInfo {
     app_protocols: PortMap<AppProtocol>,
@@ -105,6 +153,7 @@ struct ResourceInfo {
     grpc_retry: Option<RouteRetry<GrpcRetryCondition>>,
     timeouts: RouteTimeouts,
     traffic_policy: Option<TrafficPolicy>,
+    external_name: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -152,6 +201,7 @@ impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::HttpRoute> for Inde
             .gkn::<linkerd_k8s_api::HttpRoute>()
             .namespaced(namespace);
         tracing::debug!(?gknn, "deleting route");
+        self.cross_namespace_routes.remove(&gknn);
         for ns_index in self.namespaces.by_ns.values_mut() {
             ns_index.delete_http_route(&gknn);
         }
@@ -165,6 +215,7 @@ impl kubert::index::IndexNamespacedResource<gateway::HTTPRoute> for Index {
 
     fn delete(&mut self, namespace: String, name: String) {
         let gknn = name.gkn::<gateway::HTTPRoute>().namespaced(namespace);
+        self.cross_namespace_routes.remove(&gknn);
         for ns_index in self.namespaces.by_ns.values_mut() {
             ns_index.delete_http_route(&gknn);
         }
@@ -178,6 +229,7 @@ impl kubert::index::IndexNamespacedResource<gateway::GRPCRoute> for Index {
 
     fn delete(&mut self, namespace: String, name: String) {
         let gknn = name.gkn::<gateway::GRPCRoute>().namespaced(namespace);
+        self.cross_namespace_routes.remove(&gknn);
         for ns_index in self.namespaces.by_ns.values_mut() {
             ns_index.delete_grpc_route(&gknn);
         }
@@ -191,6 +243,7 @@ impl kubert::index::IndexNamespacedResource<gateway::TLSRoute> for Index {
 
     fn delete(&mut self, namespace: String, name: String) {
         let gknn = name.gkn::<gateway::TLSRoute>().namespaced(namespace);
+        self.cross_namespace_routes.remove(&gknn);
         for ns_index in self.namespaces.by_ns.values_mut() {
             ns_index.delete_tls_route(&gknn);
         }
@@ -204,12 +257,48 @@ impl kubert::index::IndexNamespacedResource<gateway::TCPRoute> for Index {
 
     fn delete(&mut self, namespace: String, name: String) {
         let gknn = name.gkn::<gateway::TCPRoute>().namespaced(namespace);
+        self.cross_namespace_routes.remove(&gknn);
         for ns_index in self.namespaces.by_ns.values_mut() {
             ns_index.delete_tcp_route(&gknn);
         }
     }
 }
 
+impl kubert::index::IndexNamespacedResource<gateway::ReferenceGrant> for Index {
+    fn apply(&mut self, grant: gateway::ReferenceGrant) {
+        if self.reference_grants.apply(grant) {
+            self.reindex_cross_namespace_routes();
+        }
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        if self.reference_grants.delete(&namespace, &name) {
+            self.reindex_cross_namespace_routes();
+        }
+    }
+
+    fn reset(
+        &mut self,
+        grants: Vec<gateway::ReferenceGrant>,
+        deleted: kubert::index::NamespacedRemoved,
+    ) {
+        let mut changed = false;
+        for grant in grants.into_iter() {
+            changed |= self.reference_grants.apply(grant);
+        }
+        for (namespace, names) in deleted.into_iter() {
+            for name in names.into_iter() {
+                changed |= self.reference_grants.delete(&namespace, &name);
+            }
+        }
+        // Grants are only enforced once the watch has synced.
+        changed |= self.reference_grants.set_synced();
+        if changed {
+            self.reindex_cross_namespace_routes();
+        }
+    }
+}
+
 impl kubert::index::IndexNamespacedResource<Service> for Index {
     fn apply(&mut self, service: Service) {
         let name = service.name_unchecked();
@@ -269,12 +358,19 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
             tracing::warn!(%error, service=name, namespace=ns, "Failed to parse grpc retry")
         }).unwrap_or_default();
 
+        let external_name = service
+            .spec
+            .as_ref()
+            .filter(|spec| spec.type_.as_deref() == Some("ExternalName"))
+            .and_then(|spec| spec.external_name.clone());
+
         let service_ref = ResourceRef {
             kind: ResourceKind::Service,
             name: name.clone(),
             namespace: ns.clone(),
         };
         self.services_by_ip.retain(|_, v| v.service != service_ref);
+        self.headless_service_ports.remove(&service_ref);
         if let Some(cluster_ips) = service
             .spec
             .as_ref()
@@ -282,6 +378,14 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
         {
             for cluster_ip in cluster_ips {
                 if cluster_ip == "None" {
+                    let ports = service
+                        .spec
+                        .iter()
+                        .flat_map(|spec| spec.ports.iter().flatten())
+                        .filter_map(|port| NonZeroU16::new(u16::try_from(port.port).ok()?))
+                        .collect();
+                    self.headless_service_ports
+                        .insert(service_ref.clone(), ports);
                     continue;
                 }
                 match cluster_ip.parse() {
@@ -317,6 +421,7 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
                 }
             }
         }
+        self.index_headless_endpoints(&service_ref);
 
         let service_info = ResourceInfo {
             app_protocols,
@@ -325,6 +430,7 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
             grpc_retry,
             timeouts,
             traffic_policy: None,
+            external_name,
         };
 
         self.namespaces
@@ -336,6 +442,7 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
                 service_tls_routes: Default::default(),
                 service_tcp_routes: Default::default(),
                 resource_port_routes: Default::default(),
+                rejected_routes: Default::default(),
                 namespace: Arc::new(ns),
             })
             .update_resource(
@@ -364,12 +471,129 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
             namespace,
         };
         self.resource_info.remove(&service_ref);
+        self.headless_service_ports.remove(&service_ref);
+        self.index_headless_endpoints(&service_ref);
         self.services_by_ip.retain(|_, v| v.service != service_ref);
 
         self.reindex_resources();
     }
 }
 
+impl kubert::index::IndexNamespacedResource<EndpointSlice> for Index {
+    fn apply(&mut self, slice: EndpointSlice) {
+        let name = slice.name_unchecked();
+        let ns = slice
+            .namespace()
+            .expect("EndpointSlice must have a namespace");
+        tracing::debug!(name, ns, "indexing headless service endpoints");
+
+        let Some(service) = slice.labels().get("kubernetes.io/service-name").cloned() else {
+            tracing::debug!(name, ns, "EndpointSlice has no service");
+            return;
+        };
+
+        // FQDN endpoints have no address that a client may target.
+        let addrs = if slice.address_type == "FQDN" {
+            Vec::new()
+        } else {
+            slice
+                .endpoints
+                .iter()
+                .flat_map(|endpoint| endpoint.addresses.iter())
+                .filter_map(|addr| match addr.parse() {
+                    Ok(ip) => Some(ip),
+                    Err(error) => {
+                        tracing::warn!(%error, service, ip = addr, "Invalid endpoint ip");
+                        None
+                    }
+                })
+                .collect()
+        };
+
+        let service_ref = ResourceRef {
+            kind: ResourceKind::Service,
+            name: service,
+            namespace: ns.clone(),
+        };
+        // The slice may have previously belonged to another Service.
+        for prior in self.remove_headless_endpoint_slice(&ns, &name) {
+            if prior != service_ref {
+                self.index_headless_endpoints(&prior);
+            }
+        }
+        self.headless_endpoints
+            .entry(service_ref.clone())
+            .or_default()
+            .insert(name, addrs);
+        self.index_headless_endpoints(&service_ref);
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        tracing::debug!(name, namespace, "deleting headless service endpoints");
+        for service_ref in self.remove_headless_endpoint_slice(&namespace, &name) {
+            self.index_headless_endpoints(&service_ref);
+        }
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<ServiceImport> for Index {
+    fn apply(&mut self, service_import: ServiceImport) {
+        let name = service_import.name_unchecked();
+        let ns = service_import
+            .namespace()
+            .expect("ServiceImport must have a namespace");
+        tracing::debug!(name, ns, "indexing ServiceImport");
+
+        // ServiceImports are only referenced as route backends, which are
+        // resolved through their cluster-set authority. The remaining
+        // client configuration is owned by the exporting clusters.
+        let app_protocols = service_import
+            .spec
+            .ports
+            .iter()
+            .filter_map(|port| {
+                let app_protocol = port.app_protocol.as_ref()?;
+                Some((
+                    NonZeroU16::new(u16::try_from(port.port).ok()?)?,
+                    AppProtocol::from_str(app_protocol).expect("Infalliable"),
+                ))
+            })
+            .collect();
+        let service_import_info = ResourceInfo {
+            app_protocols,
+            accrual: None,
+            http_retry: None,
+            grpc_retry: None,
+            timeouts: Default::default(),
+            traffic_policy: None,
+            external_name: None,
+        };
+
+        self.resource_info.insert(
+            ResourceRef {
+                kind: ResourceKind::ServiceImport,
+                name,
+                namespace: ns,
+            },
+            service_import_info,
+        );
+
+        self.reindex_resources();
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        tracing::debug!(name, namespace, "deleting ServiceImport");
+        let service_import_ref = ResourceRef {
+            kind: ResourceKind::ServiceImport,
+            name,
+            namespace,
+        };
+        self.resource_info.remove(&service_import_ref);
+
+        self.reindex_resources();
+    }
+}
+
 impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::EgressNetwork> for Index {
     fn apply(&mut self, egress_network: linkerd_k8s_api::EgressNetwork) {
         let name = egress_network.name_unchecked();
@@ -377,6 +601,9 @@ impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::EgressNetwork> for
             .namespace()
             .expect("EgressNetwork must have a namespace");
         tracing::debug!(name, ns, "indexing EgressNetwork");
+        if !self.in_scope(&ns) {
+            return;
+        }
         let accrual = parse_accrual_config(egress_network.annotations())
             .map_err(|error| tracing::warn!(%error, service=name, namespace=ns, "Failed to parse accrual config"))
             .unwrap_or_default();
@@ -425,6 +652,7 @@ impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::EgressNetwork> for
             grpc_retry,
             timeouts,
             traffic_policy,
+            external_name: None,
         };
 
         let ns = Arc::new(ns);
@@ -437,6 +665,7 @@ impl kubert::index::IndexNamespacedResource<linkerd_k8s_api::EgressNetwork> for
                 service_tls_routes: Default::default(),
                 service_tcp_routes: Default::default(),
                 resource_port_routes: Default::default(),
+                rejected_routes: Default::default(),
                 namespace: ns.clone(),
             })
             .update_resource(
@@ -480,8 +709,13 @@ impl Index {
                 cluster_info,
             },
             services_by_ip: HashMap::default(),
+            headless_services_by_ip: HashMap::default(),
+            headless_service_ports: HashMap::default(),
+            headless_endpoints: HashMap::default(),
             egress_networks_by_ref: HashMap::default(),
             resource_info: HashMap::default(),
+            reference_grants: ReferenceGrants::default(),
+            cross_namespace_routes: HashMap::default(),
             cluster_networks: cluster_networks.into_iter().map(Cidr::from).collect(),
             fallback_polcy_tx,
             global_egress_network_namespace,
@@ -494,6 +728,14 @@ impl Index {
             .any(|net| net.contains(&addr.into()))
     }
 
+    /// Records whether the ReferenceGrant API is served. Cross-namespace
+    /// backends are permitted without a grant until it is.
+    pub fn set_reference_grants_served(&mut self, served: bool) {
+        if self.reference_grants.set_served(served) {
+            self.reindex_cross_namespace_routes();
+        }
+    }
+
     pub fn fallback_policy_rx(&self) -> watch::Receiver<()> {
         self.fallback_polcy_tx.subscribe()
     }
@@ -517,7 +759,7 @@ impl Index {
 
         let kind = match kind {
             Kind::EgressNetwork { .. } => ResourceKind::EgressNetwork,
-            Kind::Service => ResourceKind::Service,
+            Kind::Service | Kind::HeadlessService(_) => ResourceKind::Service,
         };
 
         let ns = self
@@ -531,6 +773,7 @@ impl Index {
                 service_tls_routes: Default::default(),
                 service_tcp_routes: Default::default(),
                 resource_port_routes: Default::default(),
+                rejected_routes: Default::default(),
             });
 
         let key = ResourcePort { kind, name, port };
@@ -545,6 +788,84 @@ impl Index {
         Ok(watch.watch.subscribe())
     }
 
+    /// Returns the current outbound policy for a Service port, as observed
+    /// from the given source namespace.
+    ///
+    /// Unlike `outbound_policy_rx`, an error is returned if the Service is not
+    /// known to the index, and the Service port is not added to the index.
+    pub fn service_policy(
+        &self,
+        namespace: &str,
+        name: &str,
+        port: NonZeroU16,
+        source_namespace: &str,
+    ) -> Result<OutboundPolicy> {
+        let service = ResourceRef {
+            kind: ResourceKind::Service,
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+        };
+        ensure!(
+            self.resource_info.contains_key(&service),
+            "service {name}.{namespace} not found"
+        );
+        let rp = ResourcePort {
+            kind: ResourceKind::Service,
+            name: service.name,
+            port,
+        };
+        let cluster = &self.namespaces.cluster_info;
+        let policy = match self.namespaces.by_ns.get(namespace) {
+            Some(ns) => ns.resource_policy(rp, source_namespace, cluster, &self.resource_info),
+            None => Namespace::new(namespace).resource_policy(
+                rp,
+                source_namespace,
+                cluster,
+                &self.resource_info,
+            ),
+        };
+        Ok(policy)
+    }
+
+    /// Describes the outbound policy for a Service port, as observed from the
+    /// given source namespace, and the routes that target the Service but were
+    /// not applied to it.
+    pub fn service_policy_report(
+        &self,
+        namespace: &str,
+        name: &str,
+        port: NonZeroU16,
+        source_namespace: &str,
+    ) -> Result<OutboundPolicyReport> {
+        let policy = self.service_policy(namespace, name, port, source_namespace)?;
+
+        let mut rejected_routes = self
+            .namespaces
+            .by_ns
+            .get(namespace)
+            .into_iter()
+            .flat_map(|ns| ns.rejected_routes.iter())
+            .filter_map(|(route, parents)| {
+                Some(RouteRejection {
+                    route: route.clone(),
+                    reason: parents.get(name)?.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+        rejected_routes.sort_by(|a, b| {
+            (&a.route.namespace, &a.route.kind, &a.route.name).cmp(&(
+                &b.route.namespace,
+                &b.route.kind,
+                &b.route.name,
+            ))
+        });
+
+        Ok(OutboundPolicyReport {
+            policy,
+            rejected_routes,
+        })
+    }
+
     pub fn lookup_service(
         &self,
         addr: IpAddr,
@@ -553,7 +874,9 @@ impl Index {
     ) -> Option<OutboundDiscoverTarget> {
         tracing::debug!(?addr, "looking up service");
 
-        let service = self.services_by_ip.get(&addr)?;
+        let Some(service) = self.services_by_ip.get(&addr) else {
+            return self.lookup_headless_service(addr, port, source_namespace);
+        };
         tracing::debug!(service=?service.service, "found service");
         if service.ports.contains(&port) {
             Some(OutboundDiscoverTarget::Resource(ResourceTarget {
@@ -574,6 +897,44 @@ impl Index {
         }
     }
 
+    /// Looks up a headless Service by the address of one of its endpoints.
+    ///
+    /// Endpoints of a headless Service may serve ports that the Service does
+    /// not declare, so only declared ports are resolved. When more than one
+    /// headless Service selecting the endpoint declares the port, the first
+    /// by namespace and name is used.
+    fn lookup_headless_service(
+        &self,
+        addr: IpAddr,
+        port: NonZeroU16,
+        source_namespace: String,
+    ) -> Option<OutboundDiscoverTarget> {
+        let service = self
+            .headless_services_by_ip
+            .get(&addr)?
+            .iter()
+            .filter(|(_, ports)| ports.contains(&port))
+            .map(|(service, _)| service)
+            .min_by_key(|service| (&service.namespace, &service.name))?;
+        tracing::debug!(?service, "found headless service");
+        Some(OutboundDiscoverTarget::Resource(ResourceTarget {
+            name: service.name.clone(),
+            namespace: service.namespace.clone(),
+            port,
+            source_namespace,
+            kind: Kind::HeadlessService(std::net::SocketAddr::new(addr, port.get())),
+        }))
+    }
+
+    /// Returns true if a Service with the given name is indexed.
+    pub fn service_exists(&self, namespace: &str, name: &str) -> bool {
+        self.resource_info.contains_key(&ResourceRef {
+            kind: ResourceKind::Service,
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+        })
+    }
+
     pub fn lookup_egress_network(
         &self,
         addr: IpAddr,
@@ -590,6 +951,10 @@ impl Index {
 
     fn apply_http(&mut self, route: HttpRouteResource) {
         tracing::debug!(name = route.name(), "indexing httproute");
+        if !self.in_scope(&route.namespace()) {
+            return;
+        }
+        self.track_cross_namespace_route(CrossNamespaceRoute::Http(route.clone()));
 
         // For each parent_ref, create a namespace index for it if it doesn't
         // already exist.
@@ -609,6 +974,7 @@ impl Index {
                     service_tls_routes: Default::default(),
                     service_tcp_routes: Default::default(),
                     resource_port_routes: Default::default(),
+                    rejected_routes: Default::default(),
                 });
         }
 
@@ -620,12 +986,17 @@ impl Index {
                 route.clone(),
                 &self.namespaces.cluster_info,
                 &self.resource_info,
+                &self.reference_grants,
             );
         });
     }
 
     fn apply_grpc(&mut self, route: gateway::GRPCRoute) {
         tracing::debug!(name = route.name_unchecked(), "indexing grpcroute");
+        if !self.in_scope(&route.namespace().expect("GrpcRoute must have a namespace")) {
+            return;
+        }
+        self.track_cross_namespace_route(CrossNamespaceRoute::Grpc(route.clone()));
 
         // For each parent_ref, create a namespace index for it if it doesn't
         // already exist.
@@ -645,6 +1016,7 @@ impl Index {
                     service_tls_routes: Default::default(),
                     service_tcp_routes: Default::default(),
                     resource_port_routes: Default::default(),
+                    rejected_routes: Default::default(),
                 });
         }
 
@@ -656,12 +1028,17 @@ impl Index {
                 route.clone(),
                 &self.namespaces.cluster_info,
                 &self.resource_info,
+                &self.reference_grants,
             );
         }
     }
 
     fn apply_tls(&mut self, route: gateway::TLSRoute) {
         tracing::debug!(name = route.name_unchecked(), "indexing tlsroute");
+        if !self.in_scope(&route.namespace().expect("TlsRoute must have a namespace")) {
+            return;
+        }
+        self.track_cross_namespace_route(CrossNamespaceRoute::Tls(route.clone()));
 
         // For each parent_ref, create a namespace index for it if it doesn't
         // already exist.
@@ -681,6 +1058,7 @@ impl Index {
                     service_tls_routes: Default::default(),
                     service_tcp_routes: Default::default(),
                     resource_port_routes: Default::default(),
+                    rejected_routes: Default::default(),
                 });
         }
 
@@ -692,12 +1070,17 @@ impl Index {
                 route.clone(),
                 &self.namespaces.cluster_info,
                 &self.resource_info,
+                &self.reference_grants,
             );
         }
     }
 
     fn apply_tcp(&mut self, route: gateway::TCPRoute) {
         tracing::debug!(name = route.name_unchecked(), "indexing tcproute");
+        if !self.in_scope(&route.namespace().expect("TcpRoute must have a namespace")) {
+            return;
+        }
+        self.track_cross_namespace_route(CrossNamespaceRoute::Tcp(route.clone()));
 
         // For each parent_ref, create a namespace index for it if it doesn't
         // already exist.
@@ -717,6 +1100,7 @@ impl Index {
                     service_tls_routes: Default::default(),
                     service_tcp_routes: Default::default(),
                     resource_port_routes: Default::default(),
+                    rejected_routes: Default::default(),
                 });
         }
 
@@ -728,10 +1112,86 @@ impl Index {
                 route.clone(),
                 &self.namespaces.cluster_info,
                 &self.resource_info,
+                &self.reference_grants,
             );
         }
     }
 
+    /// Returns true if policy resources in `namespace` should be indexed.
+    /// Services in namespaces outside of the scope are served default policy.
+    fn in_scope(&self, namespace: &str) -> bool {
+        let in_scope = self
+            .namespaces
+            .cluster_info
+            .namespace_scope
+            .contains(namespace);
+        if !in_scope {
+            tracing::debug!(%namespace, "Ignoring policy outside of namespace scope");
+        }
+        in_scope
+    }
+
+    /// Records whether a route refers to backends outside of its namespace, so
+    /// that it can be reindexed when the ReferenceGrants that permit those
+    /// references change.
+    fn track_cross_namespace_route(&mut self, route: CrossNamespaceRoute) {
+        let gknn = route.gknn();
+        if route.is_cross_namespace() {
+            self.cross_namespace_routes.insert(gknn, route);
+        } else {
+            self.cross_namespace_routes.remove(&gknn);
+        }
+    }
+
+    fn reindex_cross_namespace_routes(&mut self) {
+        let routes = self
+            .cross_namespace_routes
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        for route in routes {
+            match route {
+                CrossNamespaceRoute::Http(route) => self.apply_http(route),
+                CrossNamespaceRoute::Grpc(route) => self.apply_grpc(route),
+                CrossNamespaceRoute::Tls(route) => self.apply_tls(route),
+                CrossNamespaceRoute::Tcp(route) => self.apply_tcp(route),
+            }
+        }
+    }
+
+    fn index_headless_endpoints(&mut self, service: &ResourceRef) {
+        self.headless_services_by_ip.retain(|_, services| {
+            services.remove(service);
+            !services.is_empty()
+        });
+        let (Some(ports), Some(slices)) = (
+            self.headless_service_ports.get(service),
+            self.headless_endpoints.get(service),
+        ) else {
+            return;
+        };
+        for addr in slices.values().flatten() {
+            tracing::debug!(%addr, service = service.name, "inserting headless service into ip index");
+            self.headless_services_by_ip
+                .entry(*addr)
+                .or_default()
+                .insert(service.clone(), ports.clone());
+        }
+    }
+
+    /// Removes an EndpointSlice from the headless endpoints index, returning
+    /// the Services that it belonged to.
+    fn remove_headless_endpoint_slice(&mut self, namespace: &str, name: &str) -> Vec<ResourceRef> {
+        let mut services = Vec::new();
+        self.headless_endpoints.retain(|service, slices| {
+            if service.namespace == namespace && slices.remove(name).is_some() {
+                services.push(service.clone());
+            }
+            !slices.is_empty()
+        });
+        services
+    }
+
     fn reindex_resources(&mut self) {
         for ns in self.namespaces.by_ns.values_mut() {
             ns.reindex_resources(&self.resource_info);
@@ -747,30 +1207,123 @@ impl Index {
     }
 }
 
+impl CrossNamespaceRoute {
+    fn gknn(&self) -> GroupKindNamespaceName {
+        match self {
+            Self::Http(route) => route.gknn(),
+            Self::Grpc(route) => route
+                .gkn()
+                .namespaced(route.namespace().expect("GRPCRoute must have a namespace")),
+            Self::Tls(route) => route
+                .gkn()
+                .namespaced(route.namespace().expect("TLSRoute must have a namespace")),
+            Self::Tcp(route) => route
+                .gkn()
+                .namespaced(route.namespace().expect("TCPRoute must have a namespace")),
+        }
+    }
+
+    /// Returns true if any of the route's backends are in another namespace.
+    fn is_cross_namespace(&self) -> bool {
+        fn any_other<'a>(
+            namespace: Option<String>,
+            backend_namespaces: impl IntoIterator<Item = &'a Option<String>>,
+        ) -> bool {
+            backend_namespaces
+                .into_iter()
+                .flatten()
+                .any(|backend_ns| Some(backend_ns) != namespace.as_ref())
+        }
+
+        match self {
+            Self::Http(HttpRouteResource::LinkerdHttp(route)) => any_other(
+                route.namespace(),
+                route
+                    .spec
+                    .rules
+                    .iter()
+                    .flatten()
+                    .flat_map(|rule| rule.backend_refs.iter().flatten())
+                    .map(|backend| &backend.namespace),
+            ),
+            Self::Http(HttpRouteResource::GatewayHttp(route)) => any_other(
+                route.namespace(),
+                route
+                    .spec
+                    .rules
+                    .iter()
+                    .flatten()
+                    .flat_map(|rule| rule.backend_refs.iter().flatten())
+                    .map(|backend| &backend.namespace),
+            ),
+            Self::Grpc(route) => any_other(
+                route.namespace(),
+                route
+                    .spec
+                    .rules
+                    .iter()
+                    .flatten()
+                    .flat_map(|rule| rule.backend_refs.iter().flatten())
+                    .map(|backend| &backend.namespace),
+            ),
+            Self::Tls(route) => any_other(
+                route.namespace(),
+                route
+                    .spec
+                    .rules
+                    .iter()
+                    .flat_map(|rule| rule.backend_refs.iter().flatten())
+                    .map(|backend| &backend.namespace),
+            ),
+            Self::Tcp(route) => any_other(
+                route.namespace(),
+                route
+                    .spec
+                    .rules
+                    .iter()
+                    .flat_map(|rule| rule.backend_refs.iter().flatten())
+                    .map(|backend| &backend.namespace),
+            ),
+        }
+    }
+}
+
 impl Namespace {
+    fn new(namespace: &str) -> Self {
+        Self {
+            namespace: Arc::new(namespace.to_string()),
+            service_http_routes: Default::default(),
+            service_grpc_routes: Default::default(),
+            service_tls_routes: Default::default(),
+            service_tcp_routes: Default::default(),
+            resource_port_routes: Default::default(),
+            rejected_routes: Default::default(),
+        }
+    }
+
     fn apply_http_route(
         &mut self,
         route: HttpRouteResource,
         cluster_info: &ClusterInfo,
         resource_info: &HashMap<ResourceRef, ResourceInfo>,
+        grants: &ReferenceGrants,
     ) {
         tracing::debug!(?route);
 
-        let outbound_route = match http::convert_route(
+        let outbound_route = http::convert_route(
             &self.namespace,
             route.clone(),
             cluster_info,
             resource_info,
-        ) {
-            Ok(route) => route,
-            Err(error) => {
-                tracing::warn!(%error, "Failed to convert route");
-                return;
-            }
-        };
+            grants,
+        );
+        if let Err(error) = &outbound_route {
+            tracing::warn!(%error, "Failed to convert route");
+        }
 
         tracing::debug!(?outbound_route);
 
+        self.rejected_routes.remove(&route.gknn());
         for parent_ref in route.parent_refs().iter().flatten() {
             let parent_kind = if is_parent_service(&parent_ref.kind, &parent_ref.group) {
                 ResourceKind::Service
@@ -785,6 +1338,14 @@ impl Namespace {
                 continue;
             }
 
+            let outbound_route = match &outbound_route {
+                Ok(route) => route,
+                Err(error) => {
+                    self.reject_route(&route.gknn(), &parent_ref.name, error);
+                    continue;
+                }
+            };
+
             let port = parent_ref
                 .port
                 .and_then(|p| p.try_into().ok())
@@ -798,6 +1359,7 @@ impl Namespace {
                 };
 
                 if !http::route_accepted_by_resource_port(route.status(), &resource_port) {
+                    self.reject_route(&route.gknn(), &parent_ref.name, NOT_ACCEPTED);
                     continue;
                 }
 
@@ -813,6 +1375,7 @@ impl Namespace {
                 service_routes.apply_http_route(route.gknn(), outbound_route.clone());
             } else {
                 if !http::route_accepted_by_service(route.status(), &parent_ref.name) {
+                    self.reject_route(&route.gknn(), &parent_ref.name, NOT_ACCEPTED);
                     continue;
                 }
                 // If the parent_ref doesn't include a port, apply this route
@@ -852,26 +1415,26 @@ impl Namespace {
         route: gateway::GRPCRoute,
         cluster_info: &ClusterInfo,
         resource_info: &HashMap<ResourceRef, ResourceInfo>,
+        grants: &ReferenceGrants,
     ) {
         tracing::debug!(?route);
-        let outbound_route = match grpc::convert_route(
+        let outbound_route = grpc::convert_route(
             &self.namespace,
             route.clone(),
             cluster_info,
             resource_info,
-        ) {
-            Ok(route) => route,
-            Err(error) => {
-                tracing::warn!(%error, "Failed to convert route");
-                return;
-            }
-        };
+            grants,
+        );
+        if let Err(error) = &outbound_route {
+            tracing::warn!(%error, "Failed to convert route");
+        }
         let gknn = route
             .gkn()
             .namespaced(route.namespace().expect("Route must have namespace"));
 
         tracing::debug!(?outbound_route);
 
+        self.rejected_routes.remove(&gknn);
         for parent_ref in route.spec.parent_refs.iter().flatten() {
             let parent_kind = if is_parent_service(&parent_ref.kind, &parent_ref.group) {
                 ResourceKind::Service
@@ -886,6 +1449,14 @@ impl Namespace {
                 continue;
             }
 
+            let outbound_route = match &outbound_route {
+                Ok(route) => route,
+                Err(error) => {
+                    self.reject_route(&gknn, &parent_ref.name, error);
+                    continue;
+                }
+            };
+
             let port = parent_ref
                 .port
                 .and_then(|p| p.try_into().ok())
@@ -899,6 +1470,7 @@ impl Namespace {
                 };
 
                 if !grpc::route_accepted_by_resource_port(route.status.as_ref(), &port) {
+                    self.reject_route(&gknn, &parent_ref.name, NOT_ACCEPTED);
                     continue;
                 }
 
@@ -914,6 +1486,7 @@ impl Namespace {
                 service_routes.apply_grpc_route(gknn.clone(), outbound_route.clone());
             } else {
                 if !grpc::route_accepted_by_service(route.status.as_ref(), &parent_ref.name) {
+                    self.reject_route(&gknn, &parent_ref.name, NOT_ACCEPTED);
                     continue;
                 }
                 // If the parent_ref doesn't include a port, apply this route
@@ -953,16 +1526,19 @@ impl Namespace {
         route: gateway::TLSRoute,
         cluster_info: &ClusterInfo,
         resource_info: &HashMap<ResourceRef, ResourceInfo>,
+        grants: &ReferenceGrants,
     ) {
         tracing::debug!(?route);
-        let outbound_route =
-            match tls::convert_route(&self.namespace, route.clone(), cluster_info, resource_info) {
-                Ok(route) => route,
-                Err(error) => {
-                    tracing::warn!(%error, "Failed to convert route");
-                    return;
-                }
-            };
+        let outbound_route = tls::convert_route(
+            &self.namespace,
+            route.clone(),
+            cluster_info,
+            resource_info,
+            grants,
+        );
+        if let Err(error) = &outbound_route {
+            tracing::warn!(%error, "Failed to convert route");
+        }
 
         tracing::debug!(?outbound_route);
 
@@ -971,6 +1547,7 @@ impl Namespace {
             .namespaced(route.namespace().expect("Route must have namespace"));
         let status = route.status.as_ref();
 
+        self.rejected_routes.remove(&gknn);
         for parent_ref in route.spec.parent_refs.iter().flatten() {
             let parent_kind = if is_parent_service(&parent_ref.kind, &parent_ref.group) {
                 ResourceKind::Service
@@ -985,6 +1562,14 @@ impl Namespace {
                 continue;
             }
 
+            let outbound_route = match &outbound_route {
+                Ok(route) => route,
+                Err(error) => {
+                    self.reject_route(&gknn, &parent_ref.name, error);
+                    continue;
+                }
+            };
+
             let port = parent_ref
                 .port
                 .and_then(|p| p.try_into().ok())
@@ -998,6 +1583,7 @@ impl Namespace {
                 };
 
                 if !tls::route_accepted_by_resource_port(status, &port) {
+                    self.reject_route(&gknn, &parent_ref.name, NOT_ACCEPTED);
                     continue;
                 }
 
@@ -1013,6 +1599,7 @@ impl Namespace {
                 resource_routes.apply_tls_route(gknn.clone(), outbound_route.clone());
             } else {
                 if !tls::route_accepted_by_service(status, &parent_ref.name) {
+                    self.reject_route(&gknn, &parent_ref.name, NOT_ACCEPTED);
                     continue;
                 }
                 // If the parent_ref doesn't include a port, apply this route
@@ -1052,16 +1639,19 @@ impl Namespace {
         route: gateway::TCPRoute,
         cluster_info: &ClusterInfo,
         resource_info: &HashMap<ResourceRef, ResourceInfo>,
+        grants: &ReferenceGrants,
     ) {
         tracing::debug!(?route);
-        let outbound_route =
-            match tcp::convert_route(&self.namespace, route.clone(), cluster_info, resource_info) {
-                Ok(route) => route,
-                Err(error) => {
-                    tracing::warn!(%error, "Failed to convert route");
-                    return;
-                }
-            };
+        let outbound_route = tcp::convert_route(
+            &self.namespace,
+            route.clone(),
+            cluster_info,
+            resource_info,
+            grants,
+        );
+        if let Err(error) = &outbound_route {
+            tracing::warn!(%error, "Failed to convert route");
+        }
 
         tracing::debug!(?outbound_route);
 
@@ -1070,6 +1660,7 @@ impl Namespace {
             .namespaced(route.namespace().expect("Route must have namespace"));
         let status = route.status.as_ref();
 
+        self.rejected_routes.remove(&gknn);
         for parent_ref in route.spec.parent_refs.iter().flatten() {
             let parent_kind = if is_parent_service(&parent_ref.kind, &parent_ref.group) {
                 ResourceKind::Service
@@ -1084,6 +1675,14 @@ impl Namespace {
                 continue;
             }
 
+            let outbound_route = match &outbound_route {
+                Ok(route) => route,
+                Err(error) => {
+                    self.reject_route(&gknn, &parent_ref.name, error);
+                    continue;
+                }
+            };
+
             let port = parent_ref
                 .port
                 .and_then(|p| p.try_into().ok())
@@ -1097,6 +1696,7 @@ impl Namespace {
                 };
 
                 if !tcp::route_accepted_by_resource_port(status, &port) {
+                    self.reject_route(&gknn, &parent_ref.name, NOT_ACCEPTED);
                     continue;
                 }
 
@@ -1112,6 +1712,7 @@ impl Namespace {
                 resource_routes.apply_tcp_route(gknn.clone(), outbound_route.clone());
             } else {
                 if !tcp::route_accepted_by_service(status, &parent_ref.name) {
+                    self.reject_route(&gknn, &parent_ref.name, NOT_ACCEPTED);
                     continue;
                 }
                 // If the parent_ref doesn't include a port, apply this route
@@ -1146,6 +1747,19 @@ impl Namespace {
         }
     }
 
+    /// Records that a route was not applied to the named resource.
+    fn reject_route(
+        &mut self,
+        gknn: &GroupKindNamespaceName,
+        parent: &str,
+        reason: impl std::fmt::Display,
+    ) {
+        self.rejected_routes
+            .entry(gknn.clone())
+            .or_default()
+            .insert(parent.to_string(), reason.to_string());
+    }
+
     fn reindex_resources(&mut self, resource_info: &HashMap<ResourceRef, ResourceInfo>) {
         let update_backend = |backend: &mut Backend| {
             match backend {
@@ -1157,6 +1771,14 @@ impl Namespace {
                     };
                     svc.exists = resource_info.contains_key(&service_ref);
                 }
+                Backend::ServiceImport(svc) => {
+                    let service_import_ref = ResourceRef {
+                        kind: ResourceKind::ServiceImport,
+                        name: svc.name.clone(),
+                        namespace: svc.namespace.clone(),
+                    };
+                    svc.exists = resource_info.contains_key(&service_import_ref);
+                }
                 Backend::EgressNetwork(egress_net) => {
                     let egress_net_ref = ResourceRef {
                         kind: ResourceKind::EgressNetwork,
@@ -1170,7 +1792,24 @@ impl Namespace {
             };
         };
 
-        for routes in self.resource_port_routes.values_mut() {
+        for (resource_port, routes) in self.resource_port_routes.iter_mut() {
+            // A Service may become, or stop being, an ExternalName Service.
+            if resource_port.kind == ResourceKind::Service {
+                let new_external_name =
+                    service_external_name(resource_info, &self.namespace, &resource_port.name);
+                let parents = std::iter::once(&mut routes.parent_info).chain(
+                    routes
+                        .watches_by_ns
+                        .values_mut()
+                        .map(|watch| &mut watch.parent_info),
+                );
+                for parent_info in parents {
+                    if let ParentInfo::Service { external_name, .. } = parent_info {
+                        external_name.clone_from(&new_external_name);
+                    }
+                }
+            }
+
             for watch in routes.watches_by_ns.values_mut() {
                 let http_backends = watch
                     .http_routes
@@ -1261,6 +1900,7 @@ impl Namespace {
     }
 
     fn delete_http_route(&mut self, gknn: &GroupKindNamespaceName) {
+        self.rejected_routes.remove(gknn);
         for resource in self.resource_port_routes.values_mut() {
             resource.delete_http_route(gknn);
         }
@@ -1272,6 +1912,7 @@ impl Namespace {
     }
 
     fn delete_grpc_route(&mut self, gknn: &GroupKindNamespaceName) {
+        self.rejected_routes.remove(gknn);
         for resource in self.resource_port_routes.values_mut() {
             resource.delete_grpc_route(gknn);
         }
@@ -1283,6 +1924,7 @@ impl Namespace {
     }
 
     fn delete_tls_route(&mut self, gknn: &GroupKindNamespaceName) {
+        self.rejected_routes.remove(gknn);
         for resource in self.resource_port_routes.values_mut() {
             resource.delete_tls_route(gknn);
         }
@@ -1294,6 +1936,7 @@ impl Namespace {
     }
 
     fn delete_tcp_route(&mut self, gknn: &GroupKindNamespaceName) {
+        self.rejected_routes.remove(gknn);
         for resource in self.resource_port_routes.values_mut() {
             resource.delete_tcp_route(gknn);
         }
@@ -1310,219 +1953,249 @@ impl Namespace {
         cluster: &ClusterInfo,
         resource_info: &HashMap<ResourceRef, ResourceInfo>,
     ) -> &mut ResourceRoutes {
+        if !self.resource_port_routes.contains_key(&rp) {
+            let routes = self.new_resource_routes(rp.clone(), cluster, resource_info);
+            self.resource_port_routes.insert(rp.clone(), routes);
+        }
         self.resource_port_routes
-            .entry(rp.clone())
-            .or_insert_with(|| {
-                let resource_ref = ResourceRef {
-                    name: rp.name.clone(),
-                    namespace: self.namespace.to_string(),
-                    kind: rp.kind.clone(),
-                };
+            .get_mut(&rp)
+            .expect("resource routes must exist")
+    }
 
-                let mut parent_info = match rp.kind {
-                    ResourceKind::EgressNetwork => ParentInfo::EgressNetwork {
-                        traffic_policy: TrafficPolicy::Deny,
-                        name: resource_ref.name.clone(),
-                        namespace: resource_ref.namespace.clone(),
-                    },
-                    ResourceKind::Service => {
-                        let authority =
-                            cluster.service_dns_authority(&self.namespace, &rp.name, rp.port);
-                        ParentInfo::Service {
-                            authority,
-                            name: resource_ref.name.clone(),
-                            namespace: resource_ref.namespace.clone(),
-                        }
-                    }
-                };
-                let mut app_protocol = None;
-                let mut accrual = None;
-                let mut http_retry = None;
-                let mut grpc_retry = None;
-                let mut timeouts = Default::default();
-                if let Some(resource) = resource_info.get(&resource_ref) {
-                    app_protocol = resource.app_protocols.get(&rp.port).cloned();
-                    accrual = resource.accrual;
-                    http_retry = resource.http_retry.clone();
-                    grpc_retry = resource.grpc_retry.clone();
-                    timeouts = resource.timeouts.clone();
-
-                    if let Some(traffic_policy) = resource.traffic_policy {
-                        parent_info = ParentInfo::EgressNetwork {
-                            traffic_policy,
-                            name: resource_ref.name,
-                            namespace: resource_ref.namespace,
-                        }
-                    }
-                }
+    /// Returns the policy for a resource:port as observed from the given
+    /// namespace, without adding the resource:port to the index.
+    fn resource_policy(
+        &self,
+        rp: ResourcePort,
+        source_namespace: &str,
+        cluster: &ClusterInfo,
+        resource_info: &HashMap<ResourceRef, ResourceInfo>,
+    ) -> OutboundPolicy {
+        match self.resource_port_routes.get(&rp) {
+            Some(routes) => routes.policy_for_ns(source_namespace),
+            None => self
+                .new_resource_routes(rp, cluster, resource_info)
+                .policy_for_ns(source_namespace),
+        }
+    }
 
-                // The routes which target this Resource but don't specify
-                // a port apply to all ports. Therefore, we include them.
-                let http_routes = self
-                    .service_http_routes
-                    .get(&rp.name)
-                    .cloned()
-                    .unwrap_or_default();
-                let grpc_routes = self
-                    .service_grpc_routes
-                    .get(&rp.name)
-                    .cloned()
-                    .unwrap_or_default();
-                let tls_routes = self
-                    .service_tls_routes
-                    .get(&rp.name)
-                    .cloned()
-                    .unwrap_or_default();
-                let tcp_routes = self
-                    .service_tcp_routes
-                    .get(&rp.name)
-                    .cloned()
-                    .unwrap_or_default();
-
-                let mut resource_routes = ResourceRoutes {
-                    parent_info,
-                    app_protocol,
-                    accrual,
-                    http_retry,
-                    grpc_retry,
-                    timeouts,
-                    port: rp.port,
-                    namespace: self.namespace.clone(),
-                    watches_by_ns: Default::default(),
-                };
+    fn new_resource_routes(
+        &self,
+        rp: ResourcePort,
+        cluster: &ClusterInfo,
+        resource_info: &HashMap<ResourceRef, ResourceInfo>,
+    ) -> ResourceRoutes {
+        let resource_ref = ResourceRef {
+            name: rp.name.clone(),
+            namespace: self.namespace.to_string(),
+            kind: rp.kind.clone(),
+        };
 
-                // Producer routes are routes in the same namespace as
-                // their parent service. Consumer routes are routes in
-                // other namespaces.
-                let (producer_http_routes, consumer_http_routes): (Vec<_>, Vec<_>) = http_routes
-                    .into_iter()
-                    .partition(|(gknn, _)| gknn.namespace == *self.namespace);
-                let (producer_grpc_routes, consumer_grpc_routes): (Vec<_>, Vec<_>) = grpc_routes
-                    .into_iter()
-                    .partition(|(gknn, _)| gknn.namespace == *self.namespace);
-                let (producer_tls_routes, consumer_tls_routes): (Vec<_>, Vec<_>) = tls_routes
-                    .into_iter()
-                    .partition(|(gknn, _)| gknn.namespace == *self.namespace);
-                let (producer_tcp_routes, consumer_tcp_routes): (Vec<_>, Vec<_>) = tcp_routes
-                    .into_iter()
-                    .partition(|(gknn, _)| gknn.namespace == *self.namespace);
-
-                for (consumer_gknn, consumer_route) in consumer_http_routes {
-                    // Consumer routes should only apply to watches from the
-                    // consumer namespace.
-                    let consumer_watch = resource_routes
-                        .watch_for_ns_or_default(consumer_gknn.namespace.to_string());
-
-                    consumer_watch.insert_http_route(consumer_gknn.clone(), consumer_route.clone());
+        let mut parent_info = match rp.kind {
+            ResourceKind::EgressNetwork => ParentInfo::EgressNetwork {
+                traffic_policy: TrafficPolicy::Deny,
+                name: resource_ref.name.clone(),
+                namespace: resource_ref.namespace.clone(),
+            },
+            ResourceKind::Service => {
+                let authority = cluster.service_dns_authority(&self.namespace, &rp.name, rp.port);
+                ParentInfo::Service {
+                    authority,
+                    name: resource_ref.name.clone(),
+                    namespace: resource_ref.namespace.clone(),
+                    external_name: service_external_name(resource_info, &self.namespace, &rp.name),
                 }
-                for (consumer_gknn, consumer_route) in consumer_grpc_routes {
-                    // Consumer routes should only apply to watches from the
-                    // consumer namespace.
-                    let consumer_watch = resource_routes
-                        .watch_for_ns_or_default(consumer_gknn.namespace.to_string());
-
-                    consumer_watch.insert_grpc_route(consumer_gknn.clone(), consumer_route.clone());
+            }
+            ResourceKind::ServiceImport => {
+                let authority =
+                    cluster.service_import_dns_authority(&self.namespace, &rp.name, rp.port);
+                ParentInfo::Service {
+                    authority,
+                    name: resource_ref.name.clone(),
+                    namespace: resource_ref.namespace.clone(),
+                    external_name: None,
                 }
-                for (consumer_gknn, consumer_route) in consumer_tls_routes {
-                    // Consumer routes should only apply to watches from the
-                    // consumer namespace.
-                    let consumer_watch = resource_routes
-                        .watch_for_ns_or_default(consumer_gknn.namespace.to_string());
-
-                    consumer_watch.insert_tls_route(consumer_gknn.clone(), consumer_route.clone());
+            }
+        };
+        let mut app_protocol = None;
+        let mut accrual = None;
+        let mut http_retry = None;
+        let mut grpc_retry = None;
+        let mut timeouts = Default::default();
+        if let Some(resource) = resource_info.get(&resource_ref) {
+            app_protocol = resource.app_protocols.get(&rp.port).cloned();
+            accrual = resource.accrual;
+            http_retry = resource.http_retry.clone();
+            grpc_retry = resource.grpc_retry.clone();
+            timeouts = resource.timeouts.clone();
+
+            if let Some(traffic_policy) = resource.traffic_policy {
+                parent_info = ParentInfo::EgressNetwork {
+                    traffic_policy,
+                    name: resource_ref.name,
+                    namespace: resource_ref.namespace,
                 }
+            }
+        }
+
+        // The routes which target this Resource but don't specify
+        // a port apply to all ports. Therefore, we include them.
+        let http_routes = self
+            .service_http_routes
+            .get(&rp.name)
+            .cloned()
+            .unwrap_or_default();
+        let grpc_routes = self
+            .service_grpc_routes
+            .get(&rp.name)
+            .cloned()
+            .unwrap_or_default();
+        let tls_routes = self
+            .service_tls_routes
+            .get(&rp.name)
+            .cloned()
+            .unwrap_or_default();
+        let tcp_routes = self
+            .service_tcp_routes
+            .get(&rp.name)
+            .cloned()
+            .unwrap_or_default();
 
-                for (consumer_gknn, consumer_route) in consumer_tcp_routes {
-                    // Consumer routes should only apply to watches from the
-                    // consumer namespace.
-                    let consumer_watch = resource_routes
-                        .watch_for_ns_or_default(consumer_gknn.namespace.to_string());
+        let mut resource_routes = ResourceRoutes {
+            parent_info,
+            app_protocol,
+            accrual,
+            http_retry,
+            grpc_retry,
+            timeouts,
+            port: rp.port,
+            namespace: self.namespace.clone(),
+            watches_by_ns: Default::default(),
+        };
 
-                    consumer_watch.insert_tcp_route(consumer_gknn.clone(), consumer_route.clone());
-                }
+        // Producer routes are routes in the same namespace as
+        // their parent service. Consumer routes are routes in
+        // other namespaces.
+        let (producer_http_routes, consumer_http_routes): (Vec<_>, Vec<_>) = http_routes
+            .into_iter()
+            .partition(|(gknn, _)| gknn.namespace == *self.namespace);
+        let (producer_grpc_routes, consumer_grpc_routes): (Vec<_>, Vec<_>) = grpc_routes
+            .into_iter()
+            .partition(|(gknn, _)| gknn.namespace == *self.namespace);
+        let (producer_tls_routes, consumer_tls_routes): (Vec<_>, Vec<_>) = tls_routes
+            .into_iter()
+            .partition(|(gknn, _)| gknn.namespace == *self.namespace);
+        let (producer_tcp_routes, consumer_tcp_routes): (Vec<_>, Vec<_>) = tcp_routes
+            .into_iter()
+            .partition(|(gknn, _)| gknn.namespace == *self.namespace);
 
-                for (producer_gknn, producer_route) in producer_http_routes {
-                    // Insert the route into the producer namespace.
-                    let producer_watch = resource_routes
-                        .watch_for_ns_or_default(producer_gknn.namespace.to_string());
+        for (consumer_gknn, consumer_route) in consumer_http_routes {
+            // Consumer routes should only apply to watches from the
+            // consumer namespace.
+            let consumer_watch =
+                resource_routes.watch_for_ns_or_default(consumer_gknn.namespace.to_string());
 
-                    producer_watch.insert_http_route(producer_gknn.clone(), producer_route.clone());
+            consumer_watch.insert_http_route(consumer_gknn.clone(), consumer_route.clone());
+        }
+        for (consumer_gknn, consumer_route) in consumer_grpc_routes {
+            // Consumer routes should only apply to watches from the
+            // consumer namespace.
+            let consumer_watch =
+                resource_routes.watch_for_ns_or_default(consumer_gknn.namespace.to_string());
 
-                    // Producer routes apply to clients in all namespaces, so
-                    // apply it to watches for all other namespaces too.
-                    resource_routes
-                        .watches_by_ns
-                        .iter_mut()
-                        .filter(|(namespace, _)| {
-                            namespace.as_str() != producer_gknn.namespace.as_ref()
-                        })
-                        .for_each(|(_, watch)| {
-                            watch.insert_http_route(producer_gknn.clone(), producer_route.clone())
-                        });
-                }
+            consumer_watch.insert_grpc_route(consumer_gknn.clone(), consumer_route.clone());
+        }
+        for (consumer_gknn, consumer_route) in consumer_tls_routes {
+            // Consumer routes should only apply to watches from the
+            // consumer namespace.
+            let consumer_watch =
+                resource_routes.watch_for_ns_or_default(consumer_gknn.namespace.to_string());
 
-                for (producer_gknn, producer_route) in producer_grpc_routes {
-                    // Insert the route into the producer namespace.
-                    let producer_watch = resource_routes
-                        .watch_for_ns_or_default(producer_gknn.namespace.to_string());
+            consumer_watch.insert_tls_route(consumer_gknn.clone(), consumer_route.clone());
+        }
 
-                    producer_watch.insert_grpc_route(producer_gknn.clone(), producer_route.clone());
+        for (consumer_gknn, consumer_route) in consumer_tcp_routes {
+            // Consumer routes should only apply to watches from the
+            // consumer namespace.
+            let consumer_watch =
+                resource_routes.watch_for_ns_or_default(consumer_gknn.namespace.to_string());
 
-                    // Producer routes apply to clients in all namespaces, so
-                    // apply it to watches for all other namespaces too.
-                    resource_routes
-                        .watches_by_ns
-                        .iter_mut()
-                        .filter(|(namespace, _)| {
-                            namespace.as_str() != producer_gknn.namespace.as_ref()
-                        })
-                        .for_each(|(_, watch)| {
-                            watch.insert_grpc_route(producer_gknn.clone(), producer_route.clone())
-                        });
-                }
+            consumer_watch.insert_tcp_route(consumer_gknn.clone(), consumer_route.clone());
+        }
 
-                for (producer_gknn, producer_route) in producer_tls_routes {
-                    // Insert the route into the producer namespace.
-                    let producer_watch = resource_routes
-                        .watch_for_ns_or_default(producer_gknn.namespace.to_string());
+        for (producer_gknn, producer_route) in producer_http_routes {
+            // Insert the route into the producer namespace.
+            let producer_watch =
+                resource_routes.watch_for_ns_or_default(producer_gknn.namespace.to_string());
 
-                    producer_watch.insert_tls_route(producer_gknn.clone(), producer_route.clone());
+            producer_watch.insert_http_route(producer_gknn.clone(), producer_route.clone());
 
-                    // Producer routes apply to clients in all namespaces, so
-                    // apply it to watches for all other namespaces too.
-                    resource_routes
-                        .watches_by_ns
-                        .iter_mut()
-                        .filter(|(namespace, _)| {
-                            namespace.as_str() != producer_gknn.namespace.as_ref()
-                        })
-                        .for_each(|(_, watch)| {
-                            watch.insert_tls_route(producer_gknn.clone(), producer_route.clone())
-                        });
-                }
+            // Producer routes apply to clients in all namespaces, so
+            // apply it to watches for all other namespaces too.
+            resource_routes
+                .watches_by_ns
+                .iter_mut()
+                .filter(|(namespace, _)| namespace.as_str() != producer_gknn.namespace.as_ref())
+                .for_each(|(_, watch)| {
+                    watch.insert_http_route(producer_gknn.clone(), producer_route.clone())
+                });
+        }
 
-                for (producer_gknn, producer_route) in producer_tcp_routes {
-                    // Insert the route into the producer namespace.
-                    let producer_watch = resource_routes
-                        .watch_for_ns_or_default(producer_gknn.namespace.to_string());
+        for (producer_gknn, producer_route) in producer_grpc_routes {
+            // Insert the route into the producer namespace.
+            let producer_watch =
+                resource_routes.watch_for_ns_or_default(producer_gknn.namespace.to_string());
 
-                    producer_watch.insert_tcp_route(producer_gknn.clone(), producer_route.clone());
+            producer_watch.insert_grpc_route(producer_gknn.clone(), producer_route.clone());
 
-                    // Producer routes apply to clients in all namespaces, so
-                    // apply it to watches for all other namespaces too.
-                    resource_routes
-                        .watches_by_ns
-                        .iter_mut()
-                        .filter(|(namespace, _)| {
-                            namespace.as_str() != producer_gknn.namespace.as_ref()
-                        })
-                        .for_each(|(_, watch)| {
-                            watch.insert_tcp_route(producer_gknn.clone(), producer_route.clone())
-                        });
-                }
+            // Producer routes apply to clients in all namespaces, so
+            // apply it to watches for all other namespaces too.
+            resource_routes
+                .watches_by_ns
+                .iter_mut()
+                .filter(|(namespace, _)| namespace.as_str() != producer_gknn.namespace.as_ref())
+                .for_each(|(_, watch)| {
+                    watch.insert_grpc_route(producer_gknn.clone(), producer_route.clone())
+                });
+        }
 
-                resource_routes
-            })
+        for (producer_gknn, producer_route) in producer_tls_routes {
+            // Insert the route into the producer namespace.
+            let producer_watch =
+                resource_routes.watch_for_ns_or_default(producer_gknn.namespace.to_string());
+
+            producer_watch.insert_tls_route(producer_gknn.clone(), producer_route.clone());
+
+            // Producer routes apply to clients in all namespaces, so
+            // apply it to watches for all other namespaces too.
+            resource_routes
+                .watches_by_ns
+                .iter_mut()
+                .filter(|(namespace, _)| namespace.as_str() != producer_gknn.namespace.as_ref())
+                .for_each(|(_, watch)| {
+                    watch.insert_tls_route(producer_gknn.clone(), producer_route.clone())
+                });
+        }
+
+        for (producer_gknn, producer_route) in producer_tcp_routes {
+            // Insert the route into the producer namespace.
+            let producer_watch =
+                resource_routes.watch_for_ns_or_default(producer_gknn.namespace.to_string());
+
+            producer_watch.insert_tcp_route(producer_gknn.clone(), producer_route.clone());
+
+            // Producer routes apply to clients in all namespaces, so
+            // apply it to watches for all other namespaces too.
+            resource_routes
+                .watches_by_ns
+                .iter_mut()
+                .filter(|(namespace, _)| namespace.as_str() != producer_gknn.namespace.as_ref())
+                .for_each(|(_, watch)| {
+                    watch.insert_tcp_route(producer_gknn.clone(), producer_route.clone())
+                });
+        }
+
+        resource_routes
     }
 }
 
@@ -1535,6 +2208,14 @@ fn is_service(group: Option<&str>, kind: &str) -> bool {
         && kind.eq_ignore_ascii_case("Service")
 }
 
+#[inline]
+fn is_service_import(group: Option<&str>, kind: &str) -> bool {
+    group
+        .map(|g| g.eq_ignore_ascii_case("multicluster.x-k8s.io"))
+        .unwrap_or(false)
+        && kind.eq_ignore_ascii_case("ServiceImport")
+}
+
 #[inline]
 fn is_egress_network(group: Option<&str>, kind: &str) -> bool {
     // If the group is not specified or empty, assume it's 'policy.linkerd.io'.
@@ -1573,6 +2254,25 @@ impl ResourceRoutes {
     }
 
     fn watch_for_ns_or_default(&mut self, namespace: String) -> &mut RoutesWatch {
+        if !self.watches_by_ns.contains_key(&namespace) {
+            let watch = self.new_watch();
+            self.watches_by_ns.insert(namespace.clone(), watch);
+        }
+        self.watches_by_ns
+            .get_mut(&namespace)
+            .expect("routes watch must exist")
+    }
+
+    /// Returns the policy observed from the given namespace, without adding a
+    /// watch for the namespace.
+    fn policy_for_ns(&self, namespace: &str) -> OutboundPolicy {
+        match self.watches_by_ns.get(namespace) {
+            Some(watch) => watch.watch.borrow().clone(),
+            None => self.new_watch().watch.borrow().clone(),
+        }
+    }
+
+    fn new_watch(&self) -> RoutesWatch {
         // The routes from the producer namespace apply to watches in all
         // namespaces, so we copy them.
         let http_routes = self
@@ -1598,35 +2298,33 @@ impl ResourceRoutes {
             .map(|watch| watch.tcp_routes.clone())
             .unwrap_or_default();
 
-        self.watches_by_ns.entry(namespace).or_insert_with(|| {
-            let (sender, _) = watch::channel(OutboundPolicy {
-                parent_info: self.parent_info.clone(),
-                port: self.port,
-                app_protocol: self.app_protocol.clone(),
-                accrual: self.accrual,
-                http_retry: self.http_retry.clone(),
-                grpc_retry: self.grpc_retry.clone(),
-                timeouts: self.timeouts.clone(),
-                http_routes: http_routes.clone(),
-                grpc_routes: grpc_routes.clone(),
-                tls_routes: tls_routes.clone(),
-                tcp_routes: tcp_routes.clone(),
-            });
+        let (sender, _) = watch::channel(OutboundPolicy {
+            parent_info: self.parent_info.clone(),
+            port: self.port,
+            app_protocol: self.app_protocol.clone(),
+            accrual: self.accrual,
+            http_retry: self.http_retry.clone(),
+            grpc_retry: self.grpc_retry.clone(),
+            timeouts: self.timeouts.clone(),
+            http_routes: http_routes.clone(),
+            grpc_routes: grpc_routes.clone(),
+            tls_routes: tls_routes.clone(),
+            tcp_routes: tcp_routes.clone(),
+        });
 
-            RoutesWatch {
-                parent_info: self.parent_info.clone(),
-                http_routes,
-                grpc_routes,
-                tls_routes,
-                tcp_routes,
-                watch: sender,
-                app_protocol: self.app_protocol.clone(),
-                accrual: self.accrual,
-                http_retry: self.http_retry.clone(),
-                grpc_retry: self.grpc_retry.clone(),
-                timeouts: self.timeouts.clone(),
-            }
-        })
+        RoutesWatch {
+            parent_info: self.parent_info.clone(),
+            http_routes,
+            grpc_routes,
+            tls_routes,
+            tcp_routes,
+            watch: sender,
+            app_protocol: self.app_protocol.clone(),
+            accrual: self.accrual,
+            http_retry: self.http_retry.clone(),
+            grpc_retry: self.grpc_retry.clone(),
+            timeouts: self.timeouts.clone(),
+        }
     }
 
     fn apply_http_route(&mut self, gknn: GroupKindNamespaceName, route: HttpRoute) {
@@ -1898,6 +2596,54 @@ impl RoutesWatch {
     }
 }
 
+/// Returns an invalid backend if a route refers to a backend in another
+/// namespace without a ReferenceGrant that permits it.
+///
+/// Backends that don't specify a namespace are never cross-namespace
+/// references.
+fn ref_not_permitted(
+    referrer: Referrer<'_>,
+    kind: &ResourceKind,
+    namespace: Option<&str>,
+    name: &str,
+    weight: Option<i32>,
+) -> Option<Backend> {
+    let namespace = namespace?;
+    let (group, kind) = match kind {
+        ResourceKind::Service => (Service::group(&()), Service::kind(&())),
+        ResourceKind::ServiceImport => (ServiceImport::group(&()), ServiceImport::kind(&())),
+        ResourceKind::EgressNetwork => (
+            linkerd_k8s_api::EgressNetwork::group(&()),
+            linkerd_k8s_api::EgressNetwork::kind(&()),
+        ),
+    };
+    if referrer.permits(&group, &kind, namespace, name) {
+        return None;
+    }
+    Some(Backend::Invalid {
+        weight: weight.unwrap_or(1) as u32,
+        message: format!(
+            "RefNotPermitted: no ReferenceGrant permits a reference to {kind} {namespace}/{name}"
+        ),
+    })
+}
+
+/// Returns the target DNS name of an `ExternalName` Service, without a
+/// trailing dot.
+fn service_external_name(
+    resource_info: &HashMap<ResourceRef, ResourceInfo>,
+    namespace: &str,
+    name: &str,
+) -> Option<String> {
+    let service_ref = ResourceRef {
+        kind: ResourceKind::Service,
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+    };
+    let external_name = resource_info.get(&service_ref)?.external_name.as_deref()?;
+    Some(external_name.trim_end_matches('.').to_string())
+}
+
 pub fn parse_accrual_config(
     annotations: &std::collections::BTreeMap<String, String>,
 ) -> Result<Option<FailureAccrual>> {
@@ -2,7 +2,9 @@ use std::num::NonZeroU16;
 use std::time;
 
 use super::{
-    parse_duration, parse_timeouts, ResourceInfo, ResourceKind, ResourcePort, ResourceRef,
+    parse_duration, parse_timeouts, ref_not_permitted,
+    reference_grant::{ReferenceGrants, Referrer},
+    ResourceInfo, ResourceKind, ResourcePort, ResourceRef,
 };
 use crate::{routes, ClusterInfo};
 use ahash::AHashMap as HashMap;
@@ -13,16 +15,27 @@ use linkerd_policy_controller_core::outbound::{
     WeightedEgressNetwork, WeightedService,
 };
 use linkerd_policy_controller_core::{outbound::OutboundRouteRule, routes::GrpcRouteMatch};
-use linkerd_policy_controller_k8s_api::{gateway, policy, Resource, Service, Time};
+use linkerd_policy_controller_k8s_api::{
+    gateway,
+    multicluster::ServiceImport,
+    policy,
+    routes::grpc::{validate_backend_filters, validate_rule},
+    Resource, Service, Time,
+};
 
 pub(super) fn convert_route(
     ns: &str,
     route: gateway::GRPCRoute,
     cluster: &ClusterInfo,
     resource_info: &HashMap<ResourceRef, ResourceInfo>,
+    grants: &ReferenceGrants,
 ) -> Result<GrpcRoute> {
     let timeouts = parse_timeouts(route.annotations())?;
     let retry = parse_grpc_retry(route.annotations())?;
+    let group = gateway::GRPCRoute::group(&());
+    let kind = gateway::GRPCRoute::kind(&());
+    let route_ns = route.namespace().expect("route must have a namespace");
+    let referrer = grants.referrer(&group, &kind, &route_ns);
 
     let hostnames = route
         .spec
@@ -32,22 +45,17 @@ pub(super) fn convert_route(
         .map(routes::host_match)
         .collect();
 
-    let rules = route
-        .spec
-        .rules
-        .into_iter()
-        .flatten()
-        .map(|rule| {
-            convert_rule(
-                ns,
-                rule,
-                cluster,
-                resource_info,
-                timeouts.clone(),
-                retry.clone(),
-            )
-        })
-        .collect::<Result<_>>()?;
+    let rules = crate::routes::valid_rules(route.spec.rules.into_iter().flatten().map(|rule| {
+        convert_rule(
+            ns,
+            referrer,
+            rule,
+            cluster,
+            resource_info,
+            timeouts.clone(),
+            retry.clone(),
+        )
+    }))?;
 
     let creation_timestamp = route.metadata.creation_timestamp.map(|Time(t)| t);
 
@@ -60,12 +68,15 @@ pub(super) fn convert_route(
 
 fn convert_rule(
     ns: &str,
+    referrer: Referrer<'_>,
     rule: gateway::GRPCRouteRules,
     cluster: &ClusterInfo,
     resource_info: &HashMap<ResourceRef, ResourceInfo>,
     timeouts: RouteTimeouts,
     retry: Option<RouteRetry<GrpcRetryCondition>>,
 ) -> Result<OutboundRouteRule<GrpcRouteMatch, GrpcRetryCondition>> {
+    validate_rule(&rule)?;
+
     let matches = rule
         .matches
         .into_iter()
@@ -77,7 +88,7 @@ fn convert_rule(
         .backend_refs
         .into_iter()
         .flatten()
-        .filter_map(|b| convert_backend(ns, b, cluster, resource_info))
+        .filter_map(|b| convert_backend(ns, referrer, b, cluster, resource_info))
         .collect();
 
     let filters = rule
@@ -98,6 +109,7 @@ fn convert_rule(
 
 pub(super) fn convert_backend(
     ns: &str,
+    referrer: Referrer<'_>,
     backend: gateway::GRPCRouteRulesBackendRefs,
     cluster: &ClusterInfo,
     resources: &HashMap<ResourceRef, ResourceInfo>,
@@ -116,6 +128,23 @@ pub(super) fn convert_backend(
         }
     };
 
+    if let Some(invalid) = ref_not_permitted(
+        referrer,
+        &backend_kind,
+        backend.namespace.as_deref(),
+        &backend.name,
+        backend.weight,
+    ) {
+        return Some(invalid);
+    }
+
+    if let Err(error) = validate_backend_filters(&backend) {
+        return Some(Backend::Invalid {
+            weight: backend.weight.unwrap_or(1) as u32,
+            message: error.to_string(),
+        });
+    }
+
     let filters = backend.filters;
 
     let backend_ref = ResourceRef {
@@ -172,6 +201,33 @@ pub(super) fn convert_backend(
                 exists: resources.contains_key(&backend_ref),
             }))
         }
+        ResourceKind::ServiceImport => {
+            // As with Services, the port is required to build the
+            // cluster-set authority.
+            let port = match port {
+                Some(port) => port,
+                None => {
+                    return Some(Backend::Invalid {
+                        weight,
+                        message: format!("missing port for backend ServiceImport {name}"),
+                    })
+                }
+            };
+
+            Some(Backend::ServiceImport(WeightedService {
+                weight,
+                authority: cluster.service_import_dns_authority(
+                    &backend_ref.namespace,
+                    &name,
+                    port,
+                ),
+                name,
+                namespace: backend_ref.namespace.to_string(),
+                port,
+                filters,
+                exists: resources.contains_key(&backend_ref),
+            }))
+        }
         ResourceKind::EgressNetwork => Some(Backend::EgressNetwork(WeightedEgressNetwork {
             weight,
             name,
@@ -279,6 +335,7 @@ pub(super) fn route_accepted_by_resource_port(
 ) -> bool {
     let (kind, group) = match resource_port.kind {
         ResourceKind::Service => (Service::kind(&()), Service::group(&())),
+        ResourceKind::ServiceImport => (ServiceImport::kind(&()), ServiceImport::group(&())),
         ResourceKind::EgressNetwork => (
             policy::EgressNetwork::kind(&()),
             policy::EgressNetwork::group(&()),
@@ -347,6 +404,8 @@ pub(crate) fn backend_kind(backend: &gateway::GRPCRouteRulesBackendRefs) -> Opti
     let kind = backend.kind.as_deref().unwrap_or("Service");
     if super::is_service(group, kind) {
         Some(ResourceKind::Service)
+    } else if super::is_service_import(group, kind) {
+        Some(ResourceKind::ServiceImport)
     } else if super::is_egress_network(group, kind) {
         Some(ResourceKind::EgressNetwork)
     } else {
@@ -1,7 +1,9 @@
 use std::{num::NonZeroU16, time};
 
 use super::{
-    parse_duration, parse_timeouts, ResourceInfo, ResourceKind, ResourcePort, ResourceRef,
+    parse_duration, parse_timeouts, ref_not_permitted,
+    reference_grant::{ReferenceGrants, Referrer},
+    ResourceInfo, ResourceKind, ResourcePort, ResourceRef,
 };
 use crate::{
     routes::{self, HttpRouteResource},
@@ -17,16 +19,27 @@ use linkerd_policy_controller_core::{
     },
     routes::HttpRouteMatch,
 };
-use linkerd_policy_controller_k8s_api::{gateway, policy, Resource, Service, Time};
+use linkerd_policy_controller_k8s_api::{
+    gateway,
+    multicluster::ServiceImport,
+    policy,
+    routes::http::{validate_backend_filters, validate_gateway_rule, validate_linkerd_rule},
+    Resource, Service, Time,
+};
 
 pub(super) fn convert_route(
     ns: &str,
     route: HttpRouteResource,
     cluster: &ClusterInfo,
     resource_info: &HashMap<ResourceRef, ResourceInfo>,
+    grants: &ReferenceGrants,
 ) -> Result<OutboundRoute<HttpRouteMatch, HttpRetryCondition>> {
     match route {
         HttpRouteResource::LinkerdHttp(route) => {
+            let group = policy::HttpRoute::group(&());
+            let kind = policy::HttpRoute::kind(&());
+            let route_ns = route.namespace().expect("route must have a namespace");
+            let referrer = grants.referrer(&group, &kind, &route_ns);
             let timeouts = parse_timeouts(route.annotations())?;
             let retry = parse_http_retry(route.annotations())?;
 
@@ -38,22 +51,18 @@ pub(super) fn convert_route(
                 .map(routes::host_match)
                 .collect();
 
-            let rules = route
-                .spec
-                .rules
-                .into_iter()
-                .flatten()
-                .map(|r| {
+            let rules =
+                crate::routes::valid_rules(route.spec.rules.into_iter().flatten().map(|r| {
                     convert_linkerd_rule(
                         ns,
+                        referrer,
                         r,
                         cluster,
                         resource_info,
                         timeouts.clone(),
                         retry.clone(),
                     )
-                })
-                .collect::<Result<_>>()?;
+                }))?;
 
             let creation_timestamp = route.metadata.creation_timestamp.map(|Time(t)| t);
 
@@ -64,6 +73,10 @@ pub(super) fn convert_route(
             })
         }
         HttpRouteResource::GatewayHttp(route) => {
+            let group = gateway::HTTPRoute::group(&());
+            let kind = gateway::HTTPRoute::kind(&());
+            let route_ns = route.namespace().expect("route must have a namespace");
+            let referrer = grants.referrer(&group, &kind, &route_ns);
             let timeouts = parse_timeouts(route.annotations())?;
             let retry = parse_http_retry(route.annotations())?;
 
@@ -75,22 +88,18 @@ pub(super) fn convert_route(
                 .map(routes::host_match)
                 .collect();
 
-            let rules = route
-                .spec
-                .rules
-                .into_iter()
-                .flatten()
-                .map(|r| {
+            let rules =
+                crate::routes::valid_rules(route.spec.rules.into_iter().flatten().map(|r| {
                     convert_gateway_rule(
                         ns,
+                        referrer,
                         r,
                         cluster,
                         resource_info,
                         timeouts.clone(),
                         retry.clone(),
                     )
-                })
-                .collect::<Result<_>>()?;
+                }))?;
 
             let creation_timestamp = route.metadata.creation_timestamp.map(|Time(t)| t);
 
@@ -103,14 +112,18 @@ pub(super) fn convert_route(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn convert_linkerd_rule(
     ns: &str,
+    referrer: Referrer<'_>,
     rule: policy::httproute::HttpRouteRule,
     cluster: &ClusterInfo,
     resource_info: &HashMap<ResourceRef, ResourceInfo>,
     mut timeouts: RouteTimeouts,
     retry: Option<RouteRetry<HttpRetryCondition>>,
 ) -> Result<OutboundRouteRule<HttpRouteMatch, HttpRetryCondition>> {
+    validate_linkerd_rule(&rule)?;
+
     let matches = rule
         .matches
         .into_iter()
@@ -122,7 +135,7 @@ fn convert_linkerd_rule(
         .backend_refs
         .into_iter()
         .flatten()
-        .filter_map(|b| convert_backend(ns, b, cluster, resource_info))
+        .filter_map(|b| convert_backend(ns, referrer, b, cluster, resource_info))
         .collect();
 
     let filters = rule
@@ -154,14 +167,18 @@ fn convert_linkerd_rule(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn convert_gateway_rule(
     ns: &str,
+    referrer: Referrer<'_>,
     rule: gateway::HTTPRouteRules,
     cluster: &ClusterInfo,
     resource_info: &HashMap<ResourceRef, ResourceInfo>,
     mut timeouts: RouteTimeouts,
     retry: Option<RouteRetry<HttpRetryCondition>>,
 ) -> Result<OutboundRouteRule<HttpRouteMatch, HttpRetryCondition>> {
+    validate_gateway_rule(&rule)?;
+
     let matches = rule
         .matches
         .into_iter()
@@ -173,7 +190,7 @@ fn convert_gateway_rule(
         .backend_refs
         .into_iter()
         .flatten()
-        .filter_map(|b| convert_backend(ns, b, cluster, resource_info))
+        .filter_map(|b| convert_backend(ns, referrer, b, cluster, resource_info))
         .collect();
 
     let filters = rule
@@ -207,6 +224,7 @@ fn convert_gateway_rule(
 
 pub(super) fn convert_backend(
     ns: &str,
+    referrer: Referrer<'_>,
     backend: gateway::HTTPRouteRulesBackendRefs,
     cluster: &ClusterInfo,
     resources: &HashMap<ResourceRef, ResourceInfo>,
@@ -225,6 +243,23 @@ pub(super) fn convert_backend(
         }
     };
 
+    if let Some(invalid) = ref_not_permitted(
+        referrer,
+        &backend_kind,
+        backend.namespace.as_deref(),
+        &backend.name,
+        backend.weight,
+    ) {
+        return Some(invalid);
+    }
+
+    if let Err(error) = validate_backend_filters(&backend) {
+        return Some(Backend::Invalid {
+            weight: backend.weight.unwrap_or(1) as u32,
+            message: error.to_string(),
+        });
+    }
+
     let filters = backend.filters;
 
     let backend_ref = ResourceRef {
@@ -281,6 +316,33 @@ pub(super) fn convert_backend(
                 exists: resources.contains_key(&backend_ref),
             }))
         }
+        ResourceKind::ServiceImport => {
+            // As with Services, the port is required to build the
+            // cluster-set authority.
+            let port = match port {
+                Some(port) => port,
+                None => {
+                    return Some(Backend::Invalid {
+                        weight,
+                        message: format!("missing port for backend ServiceImport {name}"),
+                    })
+                }
+            };
+
+            Some(Backend::ServiceImport(WeightedService {
+                weight,
+                authority: cluster.service_import_dns_authority(
+                    &backend_ref.namespace,
+                    &name,
+                    port,
+                ),
+                name,
+                namespace: backend_ref.namespace.to_string(),
+                port,
+                filters,
+                exists: resources.contains_key(&backend_ref),
+            }))
+        }
         ResourceKind::EgressNetwork => Some(Backend::EgressNetwork(WeightedEgressNetwork {
             weight,
             name,
@@ -450,6 +512,7 @@ pub(super) fn route_accepted_by_resource_port(
 ) -> bool {
     let (kind, group) = match resource_port.kind {
         ResourceKind::Service => (Service::kind(&()), Service::group(&())),
+        ResourceKind::ServiceImport => (ServiceImport::kind(&()), ServiceImport::group(&())),
         ResourceKind::EgressNetwork => (
             policy::EgressNetwork::kind(&()),
             policy::EgressNetwork::group(&()),
@@ -518,6 +581,8 @@ pub(crate) fn backend_kind(backend: &gateway::HTTPRouteRulesBackendRefs) -> Opti
     let kind = backend.kind.as_deref().unwrap_or("Service");
     if super::is_service(group, kind) {
         Some(ResourceKind::Service)
+    } else if super::is_service_import(group, kind) {
+        Some(ResourceKind::ServiceImport)
     } else if super::is_egress_network(group, kind) {
         Some(ResourceKind::EgressNetwork)
     } else {
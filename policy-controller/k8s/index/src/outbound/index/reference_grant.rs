@@ -0,0 +1,234 @@
+//! Cross-namespace references permitted by Gateway API `ReferenceGrant`s.
+//!
+//! A route may only refer to a backend in another namespace if a
+//! `ReferenceGrant` in the backend's namespace allows references from the
+//! route's kind and namespace.
+//!
+//! Grants are only enforced once the `ReferenceGrant` API is served and its
+//! watch has synced, so that clusters without the CRD installed continue to
+//! permit cross-namespace references.
+
+use linkerd_policy_controller_k8s_api::{
+    gateway::{ReferenceGrant, ReferenceGrantSpec},
+    ResourceExt,
+};
+use std::collections::HashMap;
+
+/// Indexes `ReferenceGrant` specs by namespace and name.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ReferenceGrants {
+    grants: HashMap<String, HashMap<String, ReferenceGrantSpec>>,
+    served: bool,
+    synced: bool,
+}
+
+/// A resource that refers to other resources, checked against
+/// [`ReferenceGrants`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Referrer<'a> {
+    grants: &'a ReferenceGrants,
+    group: &'a str,
+    kind: &'a str,
+    namespace: &'a str,
+}
+
+// === impl ReferenceGrants ===
+
+impl ReferenceGrants {
+    /// Indexes a grant, returning true if the permitted references changed.
+    pub(crate) fn apply(&mut self, grant: ReferenceGrant) -> bool {
+        let namespace = grant
+            .namespace()
+            .expect("ReferenceGrant must have a namespace");
+        let name = grant.name_unchecked();
+        let grants = self.grants.entry(namespace).or_default();
+        if grants.get(&name) == Some(&grant.spec) {
+            return false;
+        }
+        grants.insert(name, grant.spec);
+        true
+    }
+
+    /// Removes a grant, returning true if the permitted references changed.
+    pub(crate) fn delete(&mut self, namespace: &str, name: &str) -> bool {
+        let Some(grants) = self.grants.get_mut(namespace) else {
+            return false;
+        };
+        let removed = grants.remove(name).is_some();
+        if grants.is_empty() {
+            self.grants.remove(namespace);
+        }
+        removed
+    }
+
+    /// Records whether the `ReferenceGrant` API is served, returning true if
+    /// the permitted references changed.
+    ///
+    /// Once the API is served, grants are not enforced until the watch has
+    /// synced.
+    pub(crate) fn set_served(&mut self, served: bool) -> bool {
+        let enforced = self.is_enforced();
+        self.served = served;
+        self.synced = false;
+        enforced != self.is_enforced()
+    }
+
+    /// Records that the watch has synced, returning true if the permitted
+    /// references changed.
+    pub(crate) fn set_synced(&mut self) -> bool {
+        let enforced = self.is_enforced();
+        self.synced = true;
+        enforced != self.is_enforced()
+    }
+
+    /// Returns true if cross-namespace references must be permitted by a
+    /// grant.
+    pub(crate) fn is_enforced(&self) -> bool {
+        self.served && self.synced
+    }
+
+    /// Returns a handle for checking the references made by a resource of the
+    /// given group and kind in `namespace`.
+    pub(crate) fn referrer<'a>(
+        &'a self,
+        group: &'a str,
+        kind: &'a str,
+        namespace: &'a str,
+    ) -> Referrer<'a> {
+        Referrer {
+            grants: self,
+            group,
+            kind,
+            namespace,
+        }
+    }
+}
+
+// === impl Referrer ===
+
+impl Referrer<'_> {
+    /// Returns true if the referrer may refer to the named resource.
+    ///
+    /// References within the referrer's namespace are always permitted, as
+    /// are all references while grants are not enforced.
+    pub(crate) fn permits(&self, group: &str, kind: &str, namespace: &str, name: &str) -> bool {
+        if namespace == self.namespace || !self.grants.is_enforced() {
+            return true;
+        }
+
+        let Some(grants) = self.grants.grants.get(namespace) else {
+            return false;
+        };
+        grants.values().any(|spec| {
+            let from = spec.from.iter().any(|from| {
+                eq_group(&from.group, self.group)
+                    && from.kind == self.kind
+                    && from.namespace == self.namespace
+            });
+            let to = spec.to.iter().any(|to| {
+                eq_group(&to.group, group)
+                    && to.kind == kind
+                    && to.name.as_deref().is_none_or(|n| n == name)
+            });
+            from && to
+        })
+    }
+}
+
+/// The core API group may be written as either an empty string or `core`.
+fn eq_group(a: &str, b: &str) -> bool {
+    fn core(group: &str) -> &str {
+        if group == "core" {
+            ""
+        } else {
+            group
+        }
+    }
+    core(a) == core(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkerd_policy_controller_k8s_api::{
+        gateway::{ReferenceGrantFrom, ReferenceGrantTo},
+        ObjectMeta,
+    };
+
+    fn grant(name: Option<&str>) -> ReferenceGrant {
+        ReferenceGrant {
+            metadata: ObjectMeta {
+                namespace: Some("backend-ns".to_string()),
+                name: Some("grant".to_string()),
+                ..Default::default()
+            },
+            spec: ReferenceGrantSpec {
+                from: vec![ReferenceGrantFrom {
+                    group: "gateway.networking.k8s.io".to_string(),
+                    kind: "HTTPRoute".to_string(),
+                    namespace: "route-ns".to_string(),
+                }],
+                to: vec![ReferenceGrantTo {
+                    group: "".to_string(),
+                    kind: "Service".to_string(),
+                    name: name.map(Into::into),
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn permits_granted_references() {
+        let mut grants = ReferenceGrants::default();
+        assert!(!grants.set_served(true));
+        assert!(grants.set_synced());
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(route.permits("core", "Service", "route-ns", "svc"));
+        assert!(!route.permits("core", "Service", "backend-ns", "svc"));
+
+        assert!(grants.apply(grant(None)));
+        assert!(!grants.apply(grant(None)));
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(route.permits("core", "Service", "backend-ns", "svc"));
+        assert!(!route.permits("", "Service", "other-ns", "svc"));
+
+        let grpc = grants.referrer("gateway.networking.k8s.io", "GRPCRoute", "route-ns");
+        assert!(!grpc.permits("", "Service", "backend-ns", "svc"));
+
+        assert!(grants.apply(grant(Some("other"))));
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(!route.permits("", "Service", "backend-ns", "svc"));
+        assert!(route.permits("", "Service", "backend-ns", "other"));
+
+        assert!(grants.delete("backend-ns", "grant"));
+        assert!(!grants.delete("backend-ns", "grant"));
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(!route.permits("", "Service", "backend-ns", "other"));
+    }
+
+    #[test]
+    fn permits_all_references_until_enforced() {
+        let mut grants = ReferenceGrants::default();
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(route.permits("", "Service", "backend-ns", "svc"));
+
+        // The watch may sync when the API isn't served, when a removed API's
+        // index is reset.
+        assert!(!grants.set_synced());
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(route.permits("", "Service", "backend-ns", "svc"));
+
+        // Grants are not enforced until the watch syncs after the API is
+        // served.
+        assert!(!grants.set_served(true));
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(route.permits("", "Service", "backend-ns", "svc"));
+        assert!(grants.set_synced());
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(!route.permits("", "Service", "backend-ns", "svc"));
+
+        assert!(grants.set_served(false));
+        let route = grants.referrer("gateway.networking.k8s.io", "HTTPRoute", "route-ns");
+        assert!(route.permits("", "Service", "backend-ns", "svc"));
+    }
+}
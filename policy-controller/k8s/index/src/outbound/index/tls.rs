@@ -1,25 +1,35 @@
 use std::num::NonZeroU16;
 
-use super::{ResourceInfo, ResourceKind, ResourcePort, ResourceRef};
+use super::{
+    ref_not_permitted,
+    reference_grant::{ReferenceGrants, Referrer},
+    ResourceInfo, ResourceKind, ResourcePort, ResourceRef,
+};
 use crate::{routes, ClusterInfo};
 use ahash::AHashMap as HashMap;
-use anyhow::{bail, Result};
+use anyhow::Result;
 use linkerd_policy_controller_core::outbound::{
     Backend, TcpRouteRule, TlsRoute, WeightedEgressNetwork, WeightedService,
 };
-use linkerd_policy_controller_k8s_api::{gateway, policy, Resource, Service, Time};
+use linkerd_policy_controller_k8s_api::{
+    gateway, multicluster::ServiceImport, policy, routes::validate_single_rule, Resource,
+    ResourceExt, Service, Time,
+};
 
 pub(super) fn convert_route(
     ns: &str,
     route: gateway::TLSRoute,
     cluster: &ClusterInfo,
     resource_info: &HashMap<ResourceRef, ResourceInfo>,
+    grants: &ReferenceGrants,
 ) -> Result<TlsRoute> {
-    if route.spec.rules.len() != 1 {
-        bail!("TLSRoute needs to have one rule");
-    }
+    validate_single_rule(&gateway::TLSRoute::kind(&()), route.spec.rules.len())?;
 
     let rule = route.spec.rules.first().expect("already checked");
+    let group = gateway::TLSRoute::group(&());
+    let kind = gateway::TLSRoute::kind(&());
+    let route_ns = route.namespace().expect("route must have a namespace");
+    let referrer = grants.referrer(&group, &kind, &route_ns);
 
     let hostnames = route
         .spec
@@ -34,7 +44,7 @@ pub(super) fn convert_route(
         .clone()
         .into_iter()
         .flatten()
-        .filter_map(|b| convert_backend(ns, b, cluster, resource_info))
+        .filter_map(|b| convert_backend(ns, referrer, b, cluster, resource_info))
         .collect();
 
     let creation_timestamp = route.metadata.creation_timestamp.map(|Time(t)| t);
@@ -48,6 +58,7 @@ pub(super) fn convert_route(
 
 pub(super) fn convert_backend(
     ns: &str,
+    referrer: Referrer<'_>,
     backend: gateway::TLSRouteRulesBackendRefs,
     cluster: &ClusterInfo,
     resources: &HashMap<ResourceRef, ResourceInfo>,
@@ -66,6 +77,16 @@ pub(super) fn convert_backend(
         }
     };
 
+    if let Some(invalid) = ref_not_permitted(
+        referrer,
+        &backend_kind,
+        backend.namespace.as_deref(),
+        &backend.name,
+        backend.weight,
+    ) {
+        return Some(invalid);
+    }
+
     let backend_ref = ResourceRef {
         name: backend.name.clone(),
         namespace: backend.namespace.unwrap_or_else(|| ns.to_string()),
@@ -105,6 +126,33 @@ pub(super) fn convert_backend(
                 exists: resources.contains_key(&backend_ref),
             }))
         }
+        ResourceKind::ServiceImport => {
+            // As with Services, the port is required to build the
+            // cluster-set authority.
+            let port = match port {
+                Some(port) => port,
+                None => {
+                    return Some(Backend::Invalid {
+                        weight,
+                        message: format!("missing port for backend ServiceImport {name}"),
+                    })
+                }
+            };
+
+            Some(Backend::ServiceImport(WeightedService {
+                weight,
+                authority: cluster.service_import_dns_authority(
+                    &backend_ref.namespace,
+                    &name,
+                    port,
+                ),
+                name,
+                namespace: backend_ref.namespace.to_string(),
+                port,
+                filters: vec![],
+                exists: resources.contains_key(&backend_ref),
+            }))
+        }
         ResourceKind::EgressNetwork => Some(Backend::EgressNetwork(WeightedEgressNetwork {
             weight,
             name,
@@ -122,6 +170,7 @@ pub(super) fn route_accepted_by_resource_port(
 ) -> bool {
     let (kind, group) = match resource_port.kind {
         ResourceKind::Service => (Service::kind(&()), Service::group(&())),
+        ResourceKind::ServiceImport => (ServiceImport::kind(&()), ServiceImport::group(&())),
         ResourceKind::EgressNetwork => (
             policy::EgressNetwork::kind(&()),
             policy::EgressNetwork::group(&()),
@@ -190,6 +239,8 @@ pub(crate) fn backend_kind(backend: &gateway::TLSRouteRulesBackendRefs) -> Optio
     let kind = backend.kind.as_deref().unwrap_or("Service");
     if super::is_service(group, kind) {
         Some(ResourceKind::Service)
+    } else if super::is_service_import(group, kind) {
+        Some(ResourceKind::ServiceImport)
     } else if super::is_egress_network(group, kind) {
         Some(ResourceKind::EgressNetwork)
     } else {
@@ -3,13 +3,13 @@ use std::{sync::Arc, vec};
 use crate::{
     defaults::DefaultPolicy,
     outbound::index::{Index, SharedIndex},
-    ClusterInfo,
+    ClusterInfo, NamespaceScope,
 };
 use k8s_openapi::chrono::Utc;
 use kubert::index::IndexNamespacedResource;
 use linkerd_policy_controller_core::{outbound, IpNet};
 use linkerd_policy_controller_core::{
-    outbound::{Kind, ResourceTarget},
+    outbound::{Kind, OutboundDiscoverTarget, ResourceTarget},
     POLICY_CONTROLLER_NAME,
 };
 use linkerd_policy_controller_k8s_api::{
@@ -43,6 +43,43 @@ pub fn mk_service(ns: impl ToString, name: impl ToString, port: i32) -> k8s::Ser
     }
 }
 
+fn mk_headless_service(ns: impl ToString, name: impl ToString, port: i32) -> k8s::Service {
+    let mut svc = mk_service(ns, name, port);
+    let spec = svc.spec.as_mut().unwrap();
+    spec.cluster_ip = Some("None".to_string());
+    spec.cluster_ips = Some(vec!["None".to_string()]);
+    svc
+}
+
+fn mk_endpoint_slice(
+    ns: impl ToString,
+    name: impl ToString,
+    service: impl ToString,
+    addrs: &[&str],
+) -> k8s::EndpointSlice {
+    k8s::EndpointSlice {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            labels: Some(
+                [(
+                    "kubernetes.io/service-name".to_string(),
+                    service.to_string(),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            ..Default::default()
+        },
+        address_type: "IPv4".to_string(),
+        endpoints: vec![k8s::api::discovery::v1::Endpoint {
+            addresses: addrs.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        }],
+        ports: None,
+    }
+}
+
 pub fn mk_egress_network(ns: impl ToString, name: impl ToString) -> policy::EgressNetwork {
     policy::EgressNetwork {
         metadata: k8s::ObjectMeta {
@@ -75,6 +112,22 @@ impl TestConfig {
     fn from_default_policy_with_probes(
         default_policy: DefaultPolicy,
         probe_networks: Vec<IpNet>,
+    ) -> Self {
+        Self::from_cluster_info(default_policy, probe_networks, Default::default())
+    }
+
+    fn from_namespace_scope(namespace_scope: NamespaceScope) -> Self {
+        let default_policy = DefaultPolicy::Allow {
+            authenticated_only: false,
+            cluster_only: true,
+        };
+        Self::from_cluster_info(default_policy, vec![], namespace_scope)
+    }
+
+    fn from_cluster_info(
+        default_policy: DefaultPolicy,
+        probe_networks: Vec<IpNet>,
+        namespace_scope: NamespaceScope,
     ) -> Self {
         let cluster_net = "192.0.2.0/24".parse().unwrap();
         let detect_timeout = time::Duration::from_secs(1);
@@ -88,6 +141,7 @@ impl TestConfig {
             default_opaque_ports: Default::default(),
             probe_networks,
             global_egress_network_namespace: Arc::new("linkerd-egress".to_string()),
+            namespace_scope,
         };
         let index = Index::shared(Arc::new(cluster));
         Self { index }
@@ -219,6 +273,177 @@ fn fallback_rx_closed_when_egress_net_deleted() {
     assert!(fallback_rx.has_changed().is_err());
 }
 
+#[test]
+fn lookup_headless_service_by_endpoint_ip() {
+    let test = TestConfig::default();
+
+    test.index
+        .write()
+        .apply(mk_headless_service("ns", "headless", 8080));
+    test.index.write().apply(mk_endpoint_slice(
+        "ns",
+        "headless-abcde",
+        "headless",
+        &["192.0.2.10"],
+    ));
+
+    let addr = "192.0.2.10".parse().unwrap();
+    let Some(OutboundDiscoverTarget::Resource(target)) =
+        test.index
+            .read()
+            .lookup_service(addr, 8080.try_into().unwrap(), "ns".to_string())
+    else {
+        panic!("headless service should resolve by endpoint ip");
+    };
+    assert_eq!(target.name, "headless");
+    assert_eq!(
+        target.kind,
+        Kind::HeadlessService("192.0.2.10:8080".parse().unwrap())
+    );
+
+    // Ports that the service does not declare are not resolved.
+    assert!(test
+        .index
+        .read()
+        .lookup_service(addr, 9090.try_into().unwrap(), "ns".to_string())
+        .is_none());
+
+    IndexNamespacedResource::<k8s::EndpointSlice>::delete(
+        &mut *test.index.write(),
+        "ns".to_string(),
+        "headless-abcde".to_string(),
+    );
+    assert!(test
+        .index
+        .read()
+        .lookup_service(addr, 8080.try_into().unwrap(), "ns".to_string())
+        .is_none());
+}
+
+#[test]
+fn lookup_endpoint_selected_by_two_headless_services() {
+    let test = TestConfig::default();
+
+    test.index
+        .write()
+        .apply(mk_headless_service("ns", "web", 8080));
+    test.index
+        .write()
+        .apply(mk_headless_service("ns", "admin", 9990));
+    let addr = "192.0.2.10";
+    test.index
+        .write()
+        .apply(mk_endpoint_slice("ns", "web-abcde", "web", &[addr]));
+    test.index
+        .write()
+        .apply(mk_endpoint_slice("ns", "admin-fghij", "admin", &[addr]));
+
+    let lookup = |port: u16| match test.index.read().lookup_service(
+        addr.parse().unwrap(),
+        port.try_into().unwrap(),
+        "ns".to_string(),
+    ) {
+        Some(OutboundDiscoverTarget::Resource(target)) => Some(target.name),
+        _ => None,
+    };
+
+    // Each port resolves to the Service that declares it.
+    assert_eq!(lookup(8080).as_deref(), Some("web"));
+    assert_eq!(lookup(9990).as_deref(), Some("admin"));
+
+    // Deleting one Service's endpoints leaves the other Service indexed.
+    IndexNamespacedResource::<k8s::EndpointSlice>::delete(
+        &mut *test.index.write(),
+        "ns".to_string(),
+        "web-abcde".to_string(),
+    );
+    assert_eq!(lookup(8080), None);
+    assert_eq!(lookup(9990).as_deref(), Some("admin"));
+
+    // As does deleting one of the Services.
+    test.index
+        .write()
+        .apply(mk_endpoint_slice("ns", "web-abcde", "web", &[addr]));
+    IndexNamespacedResource::<k8s::Service>::delete(
+        &mut *test.index.write(),
+        "ns".to_string(),
+        "admin".to_string(),
+    );
+    assert_eq!(lookup(8080).as_deref(), Some("web"));
+    assert_eq!(lookup(9990), None);
+}
+
+#[test]
+fn external_name_service_parent() {
+    let test = TestConfig::default();
+
+    let mut svc = mk_service("ns", "db", 5432);
+    svc.metadata.annotations = Some(
+        [("timeout.linkerd.io/idle".to_string(), "30s".to_string())]
+            .into_iter()
+            .collect(),
+    );
+    let spec = svc.spec.as_mut().unwrap();
+    spec.type_ = Some("ExternalName".to_string());
+    spec.external_name = Some("db.example.com".to_string());
+    test.index.write().apply(svc.clone());
+
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(ResourceTarget {
+            name: "db".to_string(),
+            namespace: "ns".to_string(),
+            port: 5432.try_into().unwrap(),
+            source_namespace: "ns".to_string(),
+            kind: Kind::Service,
+        })
+        .expect("db.ns should exist");
+
+    {
+        let policy = rx.borrow_and_update();
+        let outbound::ParentInfo::Service {
+            authority,
+            external_name,
+            ..
+        } = &policy.parent_info
+        else {
+            panic!("parent should be a Service");
+        };
+        assert_eq!(authority, "db.ns.svc.cluster.example.com:5432");
+        assert_eq!(external_name.as_deref(), Some("db.example.com"));
+        assert_eq!(policy.timeouts.idle, Some(time::Duration::from_secs(30)));
+    }
+
+    // Updating the external name updates the parent's forward target.
+    svc.spec.as_mut().unwrap().external_name = Some("db2.example.com.".to_string());
+    test.index.write().apply(svc);
+    assert!(rx.has_changed().unwrap());
+    let policy = rx.borrow_and_update();
+    let outbound::ParentInfo::Service { external_name, .. } = &policy.parent_info else {
+        panic!("parent should be a Service");
+    };
+    assert_eq!(external_name.as_deref(), Some("db2.example.com"));
+}
+
+#[test]
+fn service_exists_only_for_indexed_services() {
+    let test = TestConfig::default();
+    test.index.write().apply(mk_service("ns", "web", 80));
+
+    assert!(test.index.read().service_exists("ns", "web"));
+    // Authorities such as `example.com` resolve to a name and namespace that
+    // don't refer to a Service.
+    assert!(!test.index.read().service_exists("com", "example"));
+
+    IndexNamespacedResource::<k8s::Service>::delete(
+        &mut *test.index.write(),
+        "ns".to_string(),
+        "web".to_string(),
+    );
+    assert!(!test.index.read().service_exists("ns", "web"));
+}
+
 #[test]
 fn update_backend_on_route_with_no_port() {
     tracing_subscriber::fmt()
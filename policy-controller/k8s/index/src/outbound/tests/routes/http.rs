@@ -171,6 +171,398 @@ fn backend_egress_network() {
     }
 }
 
+#[test]
+fn backend_service_import() {
+    tracing_subscriber::fmt()
+        .with_max_level(Level::TRACE)
+        .try_init()
+        .ok();
+
+    let test = TestConfig::default();
+
+    // Create apex service.
+    let apex = mk_service("ns", "apex", 8080);
+    test.index.write().apply(apex);
+
+    // Create httproute that sends traffic to a ServiceImport.
+    let mut route = mk_route(
+        "ns",
+        "route",
+        8080,
+        "apex",
+        "backend",
+        super::BackendKind::Service,
+    );
+    for backend_ref in route
+        .spec
+        .rules
+        .iter_mut()
+        .flatten()
+        .flat_map(|rule| rule.backend_refs.iter_mut().flatten())
+    {
+        backend_ref.group = Some("multicluster.x-k8s.io".to_string());
+        backend_ref.kind = Some("ServiceImport".to_string());
+    }
+    test.index.write().apply(route);
+
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(ResourceTarget {
+            name: "apex".to_string(),
+            namespace: "ns".to_string(),
+            port: 8080.try_into().unwrap(),
+            source_namespace: "ns".to_string(),
+            kind: Kind::Service,
+        })
+        .expect("apex.ns should exist");
+
+    let backend = |policy: &outbound::OutboundPolicy| {
+        policy
+            .http_routes
+            .get(&GroupKindNamespaceName {
+                group: k8s::policy::HttpRoute::group(&()),
+                kind: k8s::policy::HttpRoute::kind(&()),
+                namespace: "ns".into(),
+                name: "route".into(),
+            })
+            .expect("route should exist")
+            .rules
+            .first()
+            .expect("rule should exist")
+            .backends
+            .first()
+            .expect("backend should exist")
+            .clone()
+    };
+
+    {
+        let policy = rx.borrow_and_update();
+        let Backend::ServiceImport(svc) = backend(&policy) else {
+            panic!("backend should be a service import");
+        };
+        // Backend should not exist.
+        assert!(!svc.exists);
+        assert_eq!(svc.authority, "backend.ns.svc.clusterset.local:8080");
+    }
+
+    // Create backend service import.
+    let backend_import = k8s::multicluster::ServiceImport {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns".to_string()),
+            name: Some("backend".to_string()),
+            ..Default::default()
+        },
+        spec: k8s::multicluster::ServiceImportSpec {
+            ports: vec![k8s::multicluster::ServicePort {
+                name: None,
+                protocol: None,
+                app_protocol: None,
+                port: 8080,
+            }],
+            ips: Some(vec!["10.0.0.1".to_string()]),
+            type_: k8s::multicluster::ServiceImportType::ClusterSetIP,
+        },
+        status: None,
+    };
+    test.index.write().apply(backend_import);
+    assert!(rx.has_changed().unwrap());
+
+    {
+        let policy = rx.borrow_and_update();
+        let Backend::ServiceImport(svc) = backend(&policy) else {
+            panic!("backend should be a service import");
+        };
+        // Backend should exist.
+        assert!(svc.exists);
+    }
+}
+
+#[test]
+fn backend_service_in_other_namespace() {
+    let test = TestConfig::default();
+
+    // Create apex service.
+    let apex = mk_service("ns", "apex", 8080);
+    test.index.write().apply(apex);
+    test.index
+        .write()
+        .apply(mk_service("other-ns", "backend", 8080));
+
+    // The ReferenceGrant API is served and its watch has synced.
+    test.index.write().set_reference_grants_served(true);
+    <Index as kubert::index::IndexNamespacedResource<gateway::ReferenceGrant>>::reset(
+        &mut test.index.write(),
+        vec![],
+        Default::default(),
+    );
+
+    // Create httproute with a backend in another namespace.
+    let mut route = mk_route(
+        "ns",
+        "route",
+        8080,
+        "apex",
+        "backend",
+        super::BackendKind::Service,
+    );
+    route.spec.rules.as_mut().unwrap()[0]
+        .backend_refs
+        .as_mut()
+        .unwrap()[0]
+        .namespace = Some("other-ns".to_string());
+    test.index.write().apply(route);
+
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(ResourceTarget {
+            name: "apex".to_string(),
+            namespace: "ns".to_string(),
+            port: 8080.try_into().unwrap(),
+            source_namespace: "ns".to_string(),
+            kind: Kind::Service,
+        })
+        .expect("apex.ns should exist");
+
+    let backend = |policy: &linkerd_policy_controller_core::outbound::OutboundPolicy| {
+        policy
+            .http_routes
+            .get(&GroupKindNamespaceName {
+                group: k8s::policy::HttpRoute::group(&()),
+                kind: k8s::policy::HttpRoute::kind(&()),
+                namespace: "ns".into(),
+                name: "route".into(),
+            })
+            .expect("route should exist")
+            .rules
+            .first()
+            .expect("rule should exist")
+            .backends
+            .first()
+            .expect("backend should exist")
+            .clone()
+    };
+
+    // Without a ReferenceGrant, the backend is not permitted.
+    let invalid = backend(&rx.borrow_and_update());
+    assert!(
+        matches!(&invalid, Backend::Invalid { message, .. } if message.starts_with("RefNotPermitted")),
+        "backend should not be permitted, but got {invalid:?}"
+    );
+
+    // Grant HTTPRoutes in ns access to Services in other-ns.
+    let grant = gateway::ReferenceGrant {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("other-ns".to_string()),
+            name: Some("grant".to_string()),
+            ..Default::default()
+        },
+        spec: gateway::ReferenceGrantSpec {
+            from: vec![gateway::ReferenceGrantFrom {
+                group: "policy.linkerd.io".to_string(),
+                kind: "HTTPRoute".to_string(),
+                namespace: "ns".to_string(),
+            }],
+            to: vec![gateway::ReferenceGrantTo {
+                group: "".to_string(),
+                kind: "Service".to_string(),
+                name: None,
+            }],
+        },
+    };
+    test.index.write().apply(grant);
+    assert!(rx.has_changed().unwrap());
+    match backend(&rx.borrow_and_update()) {
+        Backend::Service(svc) => {
+            assert_eq!(svc.namespace, "other-ns");
+            assert!(svc.exists);
+        }
+        backend => panic!("backend should be a service, but got {backend:?}"),
+    }
+
+    // Removing the grant invalidates the backend again.
+    <Index as kubert::index::IndexNamespacedResource<gateway::ReferenceGrant>>::delete(
+        &mut test.index.write(),
+        "other-ns".to_string(),
+        "grant".to_string(),
+    );
+    assert!(rx.has_changed().unwrap());
+    assert!(matches!(
+        backend(&rx.borrow_and_update()),
+        Backend::Invalid { .. }
+    ));
+}
+
+#[test]
+fn backend_service_in_other_namespace_without_reference_grant_api() {
+    let test = TestConfig::default();
+
+    let apex = mk_service("ns", "apex", 8080);
+    test.index.write().apply(apex);
+    test.index
+        .write()
+        .apply(mk_service("other-ns", "backend", 8080));
+
+    let mut route = mk_route(
+        "ns",
+        "route",
+        8080,
+        "apex",
+        "backend",
+        super::BackendKind::Service,
+    );
+    route.spec.rules.as_mut().unwrap()[0]
+        .backend_refs
+        .as_mut()
+        .unwrap()[0]
+        .namespace = Some("other-ns".to_string());
+    test.index.write().apply(route);
+
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(ResourceTarget {
+            name: "apex".to_string(),
+            namespace: "ns".to_string(),
+            port: 8080.try_into().unwrap(),
+            source_namespace: "ns".to_string(),
+            kind: Kind::Service,
+        })
+        .expect("apex.ns should exist");
+
+    let backend = |policy: &linkerd_policy_controller_core::outbound::OutboundPolicy| {
+        policy
+            .http_routes
+            .get(&GroupKindNamespaceName {
+                group: k8s::policy::HttpRoute::group(&()),
+                kind: k8s::policy::HttpRoute::kind(&()),
+                namespace: "ns".into(),
+                name: "route".into(),
+            })
+            .expect("route should exist")
+            .rules
+            .first()
+            .expect("rule should exist")
+            .backends
+            .first()
+            .expect("backend should exist")
+            .clone()
+    };
+
+    // Without the ReferenceGrant API, cross-namespace backends are permitted.
+    match backend(&rx.borrow_and_update()) {
+        Backend::Service(svc) => assert_eq!(svc.namespace, "other-ns"),
+        backend => panic!("backend should be a service, but got {backend:?}"),
+    }
+
+    // Once the API is served and its watch has synced, a grant is required.
+    test.index.write().set_reference_grants_served(true);
+    assert!(!rx.has_changed().unwrap());
+    <Index as kubert::index::IndexNamespacedResource<gateway::ReferenceGrant>>::reset(
+        &mut test.index.write(),
+        vec![],
+        Default::default(),
+    );
+    assert!(rx.has_changed().unwrap());
+    assert!(matches!(
+        backend(&rx.borrow_and_update()),
+        Backend::Invalid { .. }
+    ));
+
+    // When the API is removed, the backend is permitted again.
+    test.index.write().set_reference_grants_served(false);
+    assert!(rx.has_changed().unwrap());
+    assert!(matches!(
+        backend(&rx.borrow_and_update()),
+        Backend::Service(_)
+    ));
+}
+
+#[test]
+fn route_outside_namespace_scope_is_ignored() {
+    let test = TestConfig::from_namespace_scope(NamespaceScope::new([], ["ns".to_string()]));
+
+    let apex = mk_service("ns", "apex", 8080);
+    test.index.write().apply(apex);
+
+    let route = mk_route(
+        "ns",
+        "route",
+        8080,
+        "apex",
+        "backend",
+        super::BackendKind::Service,
+    );
+    test.index.write().apply(route);
+
+    let rx = test
+        .index
+        .write()
+        .outbound_policy_rx(ResourceTarget {
+            name: "apex".to_string(),
+            namespace: "ns".to_string(),
+            port: 8080.try_into().unwrap(),
+            source_namespace: "ns".to_string(),
+            kind: Kind::Service,
+        })
+        .expect("apex.ns should exist");
+
+    // Routes in excluded namespaces are not indexed, so the service gets the
+    // default policy.
+    assert!(rx.borrow().http_routes.is_empty());
+}
+
+#[test]
+fn report_rejected_route() {
+    let test = TestConfig::default();
+
+    let apex = mk_service("ns", "apex", 8080);
+    test.index.write().apply(apex);
+
+    let mut route = mk_route(
+        "ns",
+        "route",
+        8080,
+        "apex",
+        "backend",
+        super::BackendKind::Service,
+    );
+    route.status = None;
+    test.index.write().apply(route.clone());
+
+    let report = test
+        .index
+        .read()
+        .service_policy_report("ns", "apex", 8080.try_into().unwrap(), "ns")
+        .expect("apex.ns should exist");
+    assert!(report.policy.http_routes.is_empty());
+    assert_eq!(report.rejected_routes.len(), 1);
+    assert_eq!(report.rejected_routes[0].route.name, "route");
+    assert_eq!(
+        report.rejected_routes[0].reason,
+        "route is not accepted by its parent"
+    );
+
+    // Once the route is accepted, it is no longer reported as rejected.
+    let accepted = mk_route(
+        "ns",
+        "route",
+        8080,
+        "apex",
+        "backend",
+        super::BackendKind::Service,
+    );
+    test.index.write().apply(accepted);
+    let report = test
+        .index
+        .read()
+        .service_policy_report("ns", "apex", 8080.try_into().unwrap(), "ns")
+        .expect("apex.ns should exist");
+    assert_eq!(report.policy.http_routes.len(), 1);
+    assert!(report.rejected_routes.is_empty());
+}
+
 fn mk_route(
     ns: impl ToString,
     name: impl ToString,
@@ -91,3 +91,26 @@ pub fn host_match(hostname: String) -> HostMatch {
         HostMatch::Exact(hostname)
     }
 }
+
+/// Collects a route's converted rules, skipping rules that could not be
+/// converted so that the route's other rules still apply. Fails only if the
+/// route has rules and none of them are valid.
+pub(crate) fn valid_rules<R>(
+    rules: impl IntoIterator<Item = anyhow::Result<R>>,
+) -> anyhow::Result<Vec<R>> {
+    let mut valid = Vec::new();
+    let mut invalid = None;
+    for rule in rules {
+        match rule {
+            Ok(rule) => valid.push(rule),
+            Err(error) => {
+                tracing::info!(%error, "Ignoring invalid route rule");
+                invalid.get_or_insert(error);
+            }
+        }
+    }
+    match invalid {
+        Some(error) if valid.is_empty() => Err(error),
+        _ => Ok(valid),
+    }
+}
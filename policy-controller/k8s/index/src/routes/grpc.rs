@@ -14,10 +14,7 @@ pub fn try_match(
     let method = method
         .map(|value| {
             if value.r#type == Some(gateway::GRPCRouteRulesMatchesMethodType::RegularExpression) {
-                bail!(
-                    "unsupported GRPCRoute method match type: {:?}",
-                    value.r#type
-                );
+                bail!("unsupported GRPCRoute method match type: RegularExpression");
             }
             Ok(routes::GrpcMethodMatch {
                 method: value.method,
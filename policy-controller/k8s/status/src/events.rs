@@ -0,0 +1,302 @@
+//! Kubernetes Events describing changes in the status of policy resources.
+//!
+//! Status conditions are only visible to someone inspecting the resource, so
+//! the status controller also publishes an Event when a resource starts
+//! reporting an error condition, and when it stops reporting errors. Events
+//! are rate limited per resource and reason so that a resource flapping
+//! between states does not flood the API server.
+//!
+//! Events are published by a background task so that a slow API server does
+//! not delay status patches.
+
+use crate::resource_id::NamespaceGroupKindName;
+use ahash::AHashMap as HashMap;
+use linkerd_policy_controller_core::POLICY_CONTROLLER_NAME;
+use linkerd_policy_controller_k8s_api::{
+    api::core::v1::ObjectReference,
+    events::{Event, EventType, Recorder},
+};
+use prometheus_client::metrics::counter::Counter;
+use tokio::{
+    sync::mpsc,
+    time::{self, Duration, Instant},
+};
+
+/// The action reported on every event we publish.
+const ACTION: &str = "UpdateStatus";
+
+/// The reason reported when a resource no longer has any error conditions.
+const RESOLVED: &str = "Resolved";
+
+/// The number of events that may wait to be published. Events are dropped
+/// while the queue is full.
+const QUEUE_CAPACITY: usize = 1_000;
+
+/// Queues events to be published by a background task.
+#[derive(Clone, Debug)]
+pub(crate) struct Publisher {
+    tx: mpsc::Sender<Queued>,
+    dropped: Counter,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct PublisherMetrics {
+    pub(crate) published: Counter,
+    pub(crate) errors: Counter,
+    pub(crate) dropped: Counter,
+}
+
+struct Queued {
+    id: NamespaceGroupKindName,
+    event: Event,
+    reference: ObjectReference,
+}
+
+/// Tracks the error conditions last written for each resource to determine
+/// which events should be published when a new status is written.
+#[derive(Debug)]
+pub(crate) struct Transitions {
+    /// Resources whose last written status reported errors.
+    errors: HashMap<NamespaceGroupKindName, Vec<ErrorCondition>>,
+    limiter: RateLimiter,
+}
+
+/// A condition that indicates a resource is not working as intended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ErrorCondition {
+    type_: String,
+    reason: String,
+    message: String,
+}
+
+/// Suppresses repeated events with the same reason for a resource.
+#[derive(Debug)]
+struct RateLimiter {
+    interval: Duration,
+    last: HashMap<(NamespaceGroupKindName, String), Instant>,
+    last_pruned: Option<Instant>,
+}
+
+// === impl Transitions ===
+
+impl Transitions {
+    /// Creates a tracker that publishes an event with a given reason for a
+    /// resource at most once per `interval`.
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            errors: HashMap::default(),
+            limiter: RateLimiter {
+                interval,
+                last: HashMap::default(),
+                last_pruned: None,
+            },
+        }
+    }
+
+    /// Forgets all observed statuses.
+    ///
+    /// Called when leadership is acquired, since statuses written by another
+    /// controller in the meantime were not observed.
+    pub(crate) fn clear(&mut self) {
+        self.errors.clear();
+    }
+
+    /// Forgets the status last written for a deleted resource.
+    pub(crate) fn forget(&mut self, id: &NamespaceGroupKindName) {
+        self.errors.remove(id);
+    }
+
+    /// Records that `status` was written for a resource, returning the events
+    /// that describe the change from the last status written for it.
+    pub(crate) fn observe(
+        &mut self,
+        id: &NamespaceGroupKindName,
+        status: &serde_json::Value,
+        now: Instant,
+    ) -> Vec<Event> {
+        let mut errors = Vec::new();
+        collect_errors(status, &mut errors);
+        let prior = self.errors.remove(id).unwrap_or_default();
+
+        let mut events = Vec::new();
+        if errors.is_empty() {
+            if !prior.is_empty() && self.limiter.allow(id, RESOLVED, now) {
+                events.push(Event {
+                    type_: EventType::Normal,
+                    reason: RESOLVED.to_string(),
+                    note: Some(format!("{} no longer reports errors", id.gkn.kind)),
+                    action: ACTION.to_string(),
+                    secondary: None,
+                });
+            }
+            return events;
+        }
+
+        for error in &errors {
+            let is_new = !prior
+                .iter()
+                .any(|p| p.type_ == error.type_ && p.reason == error.reason);
+            if is_new && self.limiter.allow(id, &error.reason, now) {
+                let note = if error.message.is_empty() {
+                    format!("{} condition is {}", error.type_, error.reason)
+                } else {
+                    error.message.clone()
+                };
+                events.push(Event {
+                    type_: EventType::Warning,
+                    reason: error.reason.clone(),
+                    note: Some(note),
+                    action: ACTION.to_string(),
+                    secondary: None,
+                });
+            }
+        }
+        self.errors.insert(id.clone(), errors);
+        events
+    }
+}
+
+/// Finds the error conditions in a status. Route statuses hold conditions for
+/// each parent, while other resources hold them at the top level.
+///
+/// Route parent statuses written by other controllers are ignored, since
+/// their conditions don't describe this controller's view of the route.
+fn collect_errors(status: &serde_json::Value, errors: &mut Vec<ErrorCondition>) {
+    let conditions = |value: &serde_json::Value| {
+        value
+            .get("conditions")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    let mut all = conditions(status);
+    let parents = status.get("parents").and_then(|p| p.as_array());
+    for parent in parents.into_iter().flatten() {
+        let controller = parent.get("controllerName").and_then(|c| c.as_str());
+        if controller == Some(POLICY_CONTROLLER_NAME) {
+            all.extend(conditions(parent));
+        }
+    }
+
+    for condition in &all {
+        if let Some(error) = to_error(condition) {
+            if !errors
+                .iter()
+                .any(|e| e.type_ == error.type_ && e.reason == error.reason)
+            {
+                errors.push(error);
+            }
+        }
+    }
+}
+
+fn to_error(condition: &serde_json::Value) -> Option<ErrorCondition> {
+    let field = |name: &str| condition.get(name).and_then(|v| v.as_str());
+    let type_ = field("type")?;
+    let status = field("status")?;
+    let is_error = match type_ {
+        "Accepted" | "ResolvedRefs" => status == "False",
+        "PartiallyInvalid" => status == "True",
+        _ => false,
+    };
+    if !is_error {
+        return None;
+    }
+    Some(ErrorCondition {
+        type_: type_.to_string(),
+        reason: field("reason").unwrap_or(type_).to_string(),
+        message: field("message").unwrap_or_default().to_string(),
+    })
+}
+
+// === impl Publisher ===
+
+impl Publisher {
+    /// Spawns a task that publishes queued events, waiting at most `timeout`
+    /// for each.
+    pub(crate) fn spawn(recorder: Recorder, timeout: Duration, metrics: PublisherMetrics) -> Self {
+        let (tx, rx) = mpsc::channel(QUEUE_CAPACITY);
+        let dropped = metrics.dropped.clone();
+        tokio::spawn(publish(recorder, timeout, rx, metrics));
+        Self { tx, dropped }
+    }
+
+    /// Queues an event to be published, dropping it if the queue is full.
+    pub(crate) fn publish(
+        &self,
+        id: &NamespaceGroupKindName,
+        event: Event,
+        reference: &ObjectReference,
+    ) {
+        let queued = Queued {
+            id: id.clone(),
+            event,
+            reference: reference.clone(),
+        };
+        if let Err(error) = self.tx.try_send(queued) {
+            let queued = match error {
+                mpsc::error::TrySendError::Full(queued) => queued,
+                mpsc::error::TrySendError::Closed(queued) => queued,
+            };
+            self.dropped.inc();
+            tracing::warn!(id = ?queued.id, reason = %queued.event.reason, "Dropped event");
+        }
+    }
+}
+
+async fn publish(
+    recorder: Recorder,
+    timeout: Duration,
+    mut rx: mpsc::Receiver<Queued>,
+    metrics: PublisherMetrics,
+) {
+    while let Some(Queued {
+        id,
+        event,
+        reference,
+    }) = rx.recv().await
+    {
+        match time::timeout(timeout, recorder.publish(&event, &reference)).await {
+            Ok(Ok(())) => {
+                metrics.published.inc();
+                tracing::debug!(?id, reason = %event.reason, "Published event");
+            }
+            Ok(Err(error)) => {
+                metrics.errors.inc();
+                tracing::warn!(?id, reason = %event.reason, %error, "Failed to publish event");
+            }
+            Err(_) => {
+                metrics.errors.inc();
+                tracing::warn!(?id, reason = %event.reason, "Timed out publishing event");
+            }
+        }
+    }
+}
+
+// === impl RateLimiter ===
+
+impl RateLimiter {
+    fn allow(&mut self, id: &NamespaceGroupKindName, reason: &str, now: Instant) -> bool {
+        let interval = self.interval;
+        if self
+            .last_pruned
+            .is_none_or(|pruned| now.saturating_duration_since(pruned) >= interval)
+        {
+            self.last
+                .retain(|_, last| now.saturating_duration_since(*last) < interval);
+            self.last_pruned = Some(now);
+        }
+
+        let key = (id.clone(), reason.to_string());
+        if let Some(last) = self.last.get(&key) {
+            if now.saturating_duration_since(*last) < interval {
+                return false;
+            }
+        }
+        self.last.insert(key, now);
+        true
+    }
+}
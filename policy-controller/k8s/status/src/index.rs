@@ -1,17 +1,21 @@
 use crate::{
+    events::{Publisher, PublisherMetrics, Transitions},
+    queue::{PatchReceiver, PatchSender, Pending},
     ratelimit,
+    reference_grant::ReferenceGrants,
     resource_id::{NamespaceGroupKindName, ResourceId},
     routes,
     service::Service,
+    shard::{Owned, Shards},
 };
 
 use ahash::{AHashMap as HashMap, AHashSet as HashSet};
 use chrono::{offset::Utc, DateTime};
-use kubert::lease::Claim;
 use linkerd_policy_controller_core::{routes::GroupKindName, IpNet, POLICY_CONTROLLER_NAME};
 use linkerd_policy_controller_k8s_api::{
-    self as k8s, gateway,
+    self as k8s, events, gateway,
     policy::{self, Cidr, Network},
+    routes::{grpc as grpc_rules, http as http_rules, validate_single_rule},
     NamespaceResourceScope, Resource, ResourceExt, Time,
 };
 use parking_lot::RwLock;
@@ -21,22 +25,27 @@ use prometheus_client::{
 };
 use serde::de::DeserializeOwned;
 use std::{collections::hash_map::Entry, sync::Arc};
-use tokio::{
-    sync::{mpsc, watch::Receiver},
-    time::{self, Duration},
-};
+use tokio::time::{self, Duration};
 
 pub(crate) const POLICY_API_GROUP: &str = "policy.linkerd.io";
 pub(crate) const GATEWAY_API_GROUP: &str = "gateway.networking.k8s.io";
 
+/// The delay before the first retry of a failed patch. Each later retry waits
+/// twice as long as the previous one, up to `MAX_RETRY_BACKOFF`.
+const MIN_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+
 mod conditions {
     pub const RESOLVED_REFS: &str = "ResolvedRefs";
     pub const ACCEPTED: &str = "Accepted";
+    pub const PARTIALLY_INVALID: &str = "PartiallyInvalid";
 }
 mod reasons {
     pub const RESOLVED_REFS: &str = "ResolvedRefs";
     pub const BACKEND_NOT_FOUND: &str = "BackendNotFound";
     pub const INVALID_KIND: &str = "InvalidKind";
+    pub const UNSUPPORTED_VALUE: &str = "UnsupportedValue";
+    pub const REF_NOT_PERMITTED: &str = "RefNotPermitted";
     pub const NO_MATCHING_PARENT: &str = "NoMatchingParent";
     pub const NO_MATCHING_TARGET: &str = "NoMatchingTarget";
     pub const ROUTE_REASON_CONFLICTED: &str = "RouteReasonConflicted";
@@ -52,12 +61,17 @@ mod cond_statuses {
 pub type SharedIndex = Arc<RwLock<Index>>;
 
 pub struct Controller {
-    claims: Receiver<Arc<Claim>>,
+    claims: Shards,
     client: k8s::Client,
     name: String,
-    updates: mpsc::Receiver<Update>,
+    updates: PatchReceiver,
     patch_timeout: Duration,
 
+    /// Publishes Kubernetes Events when a patch changes a resource's error
+    /// conditions.
+    events: Publisher,
+    transitions: Transitions,
+
     metrics: ControllerMetrics,
 }
 
@@ -68,17 +82,19 @@ pub struct ControllerMetrics {
     patch_duration: Histogram,
     patch_dequeues: Counter,
     patch_drops: Counter,
+    patch_retries: Counter,
+    events: PublisherMetrics,
 }
 
 pub struct Index {
-    /// Used to compare against the current claim's claimant to determine if
-    /// this policy controller is the leader.
+    /// Used to compare against the current claims' claimant to determine
+    /// which shards this policy controller owns.
     name: String,
 
-    /// Used in the IndexNamespacedResource trait methods to check who the
-    /// current leader is and if updates should be sent to the Controller.
-    claims: Receiver<Arc<Claim>>,
-    updates: mpsc::Sender<Update>,
+    /// Used to check which shards' statuses are reconciled by this policy
+    /// controller.
+    claims: Shards,
+    updates: PatchSender,
 
     /// Maps route ids to a list of their parent and backend refs,
     /// regardless of if those parents have accepted the route.
@@ -95,14 +111,19 @@ pub struct Index {
 
     servers: HashSet<ResourceId>,
     services: HashMap<ResourceId, Service>,
+    service_imports: HashSet<ResourceId>,
     cluster_networks: Vec<Cidr>,
 
+    /// Permits routes to refer to backends in other namespaces.
+    reference_grants: ReferenceGrants,
+
     metrics: IndexMetrics,
 }
 
 pub struct IndexMetrics {
     patch_enqueues: Counter,
-    patch_channel_full: Counter,
+    patch_superseded: Counter,
+    patch_skips: Counter,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -110,6 +131,10 @@ pub(crate) struct RouteRef<S> {
     pub(crate) parents: Vec<routes::ParentReference>,
     pub(crate) backends: Vec<routes::BackendReference>,
     pub(crate) statuses: Vec<S>,
+    /// The route's `metadata.generation`, reported as the observed generation
+    /// of each condition we write.
+    pub(crate) generation: Option<i64>,
+    pub(crate) invalid_rules: routes::InvalidRules,
 }
 
 pub(crate) type HTTPRouteRef = RouteRef<gateway::HTTPRouteStatus>;
@@ -122,12 +147,14 @@ struct HttpLocalRateLimitPolicyRef {
     creation_timestamp: Option<DateTime<Utc>>,
     target_ref: ratelimit::TargetReference,
     status_conditions: Vec<k8s::Condition>,
+    generation: Option<i64>,
 }
 
 #[derive(Clone, PartialEq, Debug)]
 struct EgressNetworkRef {
     networks: Vec<Network>,
     status_conditions: Vec<k8s::Condition>,
+    generation: Option<i64>,
 }
 
 impl EgressNetworkRef {
@@ -144,6 +171,13 @@ pub struct Update {
     pub patch: k8s::Patch<serde_json::Value>,
 }
 
+enum PatchResult {
+    /// The status was written to the referenced resource.
+    Patched(k8s::api::core::v1::ObjectReference),
+    Done,
+    Retry,
+}
+
 impl ControllerMetrics {
     pub fn register(prom: &mut Registry) -> Self {
         let patch_succeeded = Counter::default();
@@ -178,7 +212,7 @@ impl ControllerMetrics {
         let patch_dequeues = Counter::default();
         prom.register(
             "patch_dequeues",
-            "Count of patches dequeued from the updates channel",
+            "Count of patches dequeued from the updates queue",
             patch_dequeues.clone(),
         );
 
@@ -189,6 +223,34 @@ impl ControllerMetrics {
             patch_drops.clone(),
         );
 
+        let patch_retries = Counter::default();
+        prom.register(
+            "patch_retries",
+            "Count of failed patches queued to be retried",
+            patch_retries.clone(),
+        );
+
+        let events_published = Counter::default();
+        prom.register(
+            "events_published",
+            "Count of Kubernetes Events published for status changes",
+            events_published.clone(),
+        );
+
+        let event_errors = Counter::default();
+        prom.register(
+            "event_errors",
+            "Count of Kubernetes Events that could not be published",
+            event_errors.clone(),
+        );
+
+        let events_dropped = Counter::default();
+        prom.register(
+            "events_dropped",
+            "Count of Kubernetes Events dropped because too many were waiting to be published",
+            events_dropped.clone(),
+        );
+
         Self {
             patch_succeeded,
             patch_failed,
@@ -196,6 +258,12 @@ impl ControllerMetrics {
             patch_duration,
             patch_dequeues,
             patch_drops,
+            patch_retries,
+            events: PublisherMetrics {
+                published: events_published,
+                errors: event_errors,
+                dropped: events_dropped,
+            },
         }
     }
 }
@@ -205,61 +273,90 @@ impl IndexMetrics {
         let patch_enqueues = Counter::default();
         prom.register(
             "patch_enqueues",
-            "Count of patches enqueued to the updates channel",
+            "Count of patches enqueued to the updates queue",
             patch_enqueues.clone(),
         );
 
-        let patch_channel_full = Counter::default();
+        let patch_superseded = Counter::default();
         prom.register(
-            "patch_enqueue_overflows",
-            "Count of patches dropped because the updates channel is full",
-            patch_channel_full.clone(),
+            "patch_superseded",
+            "Count of queued patches replaced by a newer patch for the same resource",
+            patch_superseded.clone(),
+        );
+
+        let patch_skips = Counter::default();
+        prom.register(
+            "patch_skips",
+            "Count of patches not enqueued because the observed status already matches the desired status",
+            patch_skips.clone(),
         );
 
         Self {
             patch_enqueues,
-            patch_channel_full,
+            patch_superseded,
+            patch_skips,
         }
     }
 }
 
 impl Controller {
     pub fn new(
-        claims: Receiver<Arc<Claim>>,
+        claims: impl Into<Shards>,
         client: k8s::Client,
         name: String,
-        updates: mpsc::Receiver<Update>,
+        updates: PatchReceiver,
         patch_timeout: Duration,
+        event_interval: Duration,
         metrics: ControllerMetrics,
     ) -> Self {
+        let recorder = events::Recorder::new(
+            client.clone(),
+            events::Reporter {
+                controller: POLICY_CONTROLLER_NAME.to_string(),
+                instance: Some(name.clone()),
+            },
+        );
         Self {
-            claims,
+            claims: claims.into(),
             client,
             name,
             updates,
             patch_timeout,
+            events: Publisher::spawn(recorder, patch_timeout, metrics.events.clone()),
+            transitions: Transitions::new(event_interval),
             metrics,
         }
     }
 
     /// Process updates received from the index; each update is a patch that
     /// should be applied to update the status of a route. A patch should
-    /// only be applied if we are the holder of the write lease.
+    /// only be applied if we are the holder of the write lease for the
+    /// resource's shard.
     pub async fn run(mut self) {
-        // Select between the write lease claim changing and receiving updates
-        // from the index. If the lease claim changes, then check if we are
-        // now the leader. If so, we should apply the patches received;
-        // otherwise, we should drain the updates queue but not apply any
-        // patches since another policy controller is responsible for that.
-        let mut was_leader = false;
+        // Select between the write lease claims changing and receiving updates
+        // from the index. If a lease claim changes, then check which shards we
+        // now own. We should apply the patches received for resources in
+        // those shards; otherwise, we should drain the updates queue but not
+        // apply any patches since another policy controller is responsible
+        // for that.
+        let mut was_owned = Owned::default();
         loop {
-            // Refresh the state of the lease on each iteration to ensure we're
-            // checking expiration.
-            let is_leader = self.claims.borrow_and_update().is_current_for(&self.name);
-            if was_leader != is_leader {
-                tracing::info!(leader=%is_leader, "Status controller leadership change");
+            // Refresh the state of the leases on each iteration to ensure
+            // we're checking expiration.
+            let owned = self.claims.owned_and_update(&self.name);
+            if was_owned != owned {
+                tracing::info!(leader = owned.any(), shards = %owned, "Status controller leadership change");
+                // Statuses in newly acquired shards may have been written by
+                // another controller, so forget what we last wrote.
+                if owned.gained(&was_owned) {
+                    self.transitions.clear();
+                }
+            }
+            was_owned = owned.clone();
+
+            for id in self.updates.take_deleted() {
+                self.transitions.forget(&id);
             }
-            was_leader = is_leader;
 
             tokio::select! {
                 biased;
@@ -267,36 +364,75 @@ impl Controller {
                     res.expect("Claims watch must not be dropped");
                 }
 
-                Some(Update { id, patch}) = self.updates.recv() => {
+                pending = self.updates.recv() => {
                     self.metrics.patch_dequeues.inc();
-                    // If this policy controller is not the leader, it should
-                    // process through the updates queue but not actually patch
-                    // any resources.
-                    if is_leader {
-                        if id.is_a::<policy::HttpRoute>() {
-                            self.patch::<policy::HttpRoute>(&id.gkn.name, &id.namespace, patch).await;
-                        } else if id.is_a::<gateway::HTTPRoute>() {
-                            self.patch::<gateway::HTTPRoute>(&id.gkn.name, &id.namespace, patch).await;
-                        } else if id.is_a::<gateway::GRPCRoute>() {
-                            self.patch::<gateway::GRPCRoute>(&id.gkn.name, &id.namespace, patch).await;
-                        } else if id.is_a::<gateway::TCPRoute>() {
-                            self.patch::<gateway::TCPRoute>(&id.gkn.name, &id.namespace, patch).await;
-                        } else if id.is_a::<gateway::TLSRoute>() {
-                            self.patch::<gateway::TLSRoute>(&id.gkn.name, &id.namespace, patch).await;
-                        } else if id.is_a::<policy::HttpLocalRateLimitPolicy>() {
-                            self.patch::<policy::HttpLocalRateLimitPolicy>(&id.gkn.name, &id.namespace, patch).await;
-                        } else if id.is_a::<policy::EgressNetwork>() {
-                            self.patch::<policy::EgressNetwork>(&id.gkn.name, &id.namespace, patch).await;
-                        }
+                    // If this policy controller does not own the resource's
+                    // shard, it should process through the updates queue but
+                    // not actually patch the resource.
+                    if owned.contains(&pending.update.id.namespace) {
+                        self.patch_pending(pending).await;
                     } else {
-                        tracing::debug!(?id, "Dropping patch because we are not the leader");
+                        tracing::debug!(id = ?pending.update.id, "Dropping patch because we are not the leader");
                         self.metrics.patch_drops.inc();
+                        self.updates.complete(&pending);
                     }
                 }
             }
         }
     }
 
+    /// Applies a patch, queueing it to be retried with exponential backoff if
+    /// it fails with an error that may be transient.
+    async fn patch_pending(&mut self, pending: Pending) {
+        let Update { id, patch } = &pending.update;
+        let result = if id.is_a::<policy::HttpRoute>() {
+            self.patch::<policy::HttpRoute>(&id.gkn.name, &id.namespace, patch)
+                .await
+        } else if id.is_a::<gateway::HTTPRoute>() {
+            self.patch::<gateway::HTTPRoute>(&id.gkn.name, &id.namespace, patch)
+                .await
+        } else if id.is_a::<gateway::GRPCRoute>() {
+            self.patch::<gateway::GRPCRoute>(&id.gkn.name, &id.namespace, patch)
+                .await
+        } else if id.is_a::<gateway::TCPRoute>() {
+            self.patch::<gateway::TCPRoute>(&id.gkn.name, &id.namespace, patch)
+                .await
+        } else if id.is_a::<gateway::TLSRoute>() {
+            self.patch::<gateway::TLSRoute>(&id.gkn.name, &id.namespace, patch)
+                .await
+        } else if id.is_a::<policy::HttpLocalRateLimitPolicy>() {
+            self.patch::<policy::HttpLocalRateLimitPolicy>(&id.gkn.name, &id.namespace, patch)
+                .await
+        } else if id.is_a::<policy::EgressNetwork>() {
+            self.patch::<policy::EgressNetwork>(&id.gkn.name, &id.namespace, patch)
+                .await
+        } else {
+            PatchResult::Done
+        };
+
+        match result {
+            PatchResult::Patched(reference) => {
+                self.updates.complete(&pending);
+                let Update { id, patch } = &pending.update;
+                self.publish_events(id, patch, &reference);
+            }
+            PatchResult::Done => self.updates.complete(&pending),
+            PatchResult::Retry => {
+                let backoff = MIN_RETRY_BACKOFF
+                    .saturating_mul(2u32.saturating_pow(pending.attempts))
+                    .min(MAX_RETRY_BACKOFF);
+                tracing::debug!(
+                    ?id,
+                    attempts = pending.attempts + 1,
+                    ?backoff,
+                    "Retrying patch"
+                );
+                self.metrics.patch_retries.inc();
+                self.updates.retry(pending, backoff);
+            }
+        }
+    }
+
     #[tracing::instrument(
         level = tracing::Level::ERROR,
         skip(self, patch),
@@ -305,7 +441,12 @@ impl Controller {
             kind=%K::kind(&Default::default()),
         ),
     )]
-    async fn patch<K>(&self, name: &str, namespace: &str, patch: k8s::Patch<serde_json::Value>)
+    async fn patch<K>(
+        &self,
+        name: &str,
+        namespace: &str,
+        patch: &k8s::Patch<serde_json::Value>,
+    ) -> PatchResult
     where
         K: Resource<Scope = NamespaceResourceScope>,
         <K as Resource>::DynamicType: Default,
@@ -317,42 +458,93 @@ impl Controller {
         let start = time::Instant::now();
         let result = time::timeout(
             self.patch_timeout,
-            api.patch_status(name, &patch_params, &patch),
+            api.patch_status(name, &patch_params, patch),
         )
         .await;
         let elapsed = start.elapsed();
         tracing::trace!(?elapsed);
         match result {
-            Ok(Ok(_)) => {
+            Ok(Ok(resource)) => {
                 self.metrics.patch_succeeded.inc();
                 self.metrics.patch_duration.observe(elapsed.as_secs_f64());
                 tracing::info!("Patched status");
+                PatchResult::Patched(resource.object_ref(&Default::default()))
             }
             Ok(Err(error)) => {
                 self.metrics.patch_failed.inc();
                 self.metrics.patch_duration.observe(elapsed.as_secs_f64());
                 tracing::error!(%error);
+                if is_retryable(&error) {
+                    PatchResult::Retry
+                } else {
+                    PatchResult::Done
+                }
             }
             Err(_) => {
                 self.metrics.patch_timeout.inc();
                 tracing::error!("Timed out");
+                PatchResult::Retry
             }
         }
     }
+
+    /// Queues events describing how a written status changed the resource's
+    /// error conditions.
+    fn publish_events(
+        &mut self,
+        id: &NamespaceGroupKindName,
+        patch: &k8s::Patch<serde_json::Value>,
+        reference: &k8s::api::core::v1::ObjectReference,
+    ) {
+        let k8s::Patch::Merge(patch) = patch else {
+            return;
+        };
+        let Some(status) = patch.get("status") else {
+            return;
+        };
+        for event in self.transitions.observe(id, status, time::Instant::now()) {
+            self.events.publish(id, event, reference);
+        }
+    }
+}
+
+/// Identifies a `K`-typed resource for patching.
+fn resource_gkn<K>(id: &ResourceId) -> NamespaceGroupKindName
+where
+    K: Resource<DynamicType = ()>,
+{
+    NamespaceGroupKindName {
+        namespace: id.namespace.clone(),
+        gkn: GroupKindName {
+            group: K::group(&()),
+            kind: K::kind(&()),
+            name: id.name.clone().into(),
+        },
+    }
+}
+
+/// Server errors, throttling, and errors that occur before the API server
+/// responds may be transient. Other API errors (e.g. when the resource no
+/// longer exists) will not succeed on retry.
+fn is_retryable(error: &k8s::Error) -> bool {
+    match error {
+        k8s::Error::Api(rsp) => rsp.code >= 500 || rsp.code == 429,
+        _ => true,
+    }
 }
 
 impl Index {
     pub fn shared(
         name: impl ToString,
-        claims: Receiver<Arc<Claim>>,
-        updates: mpsc::Sender<Update>,
+        claims: impl Into<Shards>,
+        updates: PatchSender,
         metrics: IndexMetrics,
         cluster_networks: Vec<IpNet>,
     ) -> SharedIndex {
         let cluster_networks = cluster_networks.into_iter().map(Into::into).collect();
         Arc::new(RwLock::new(Self {
             name: name.to_string(),
-            claims,
+            claims: claims.into(),
             updates,
             http_route_refs: HashMap::new(),
             grpc_route_refs: HashMap::new(),
@@ -362,11 +554,21 @@ impl Index {
             egress_networks: HashMap::new(),
             servers: HashSet::new(),
             services: HashMap::new(),
+            service_imports: HashSet::new(),
             metrics,
             cluster_networks,
+            reference_grants: ReferenceGrants::default(),
         }))
     }
 
+    /// Records whether the ReferenceGrant API is served. Cross-namespace
+    /// backends are permitted without a grant until it is.
+    pub fn set_reference_grants_served(&mut self, served: bool) {
+        if self.reference_grants.set_served(served) {
+            self.reconcile_if_leader();
+        }
+    }
+
     /// When the write leaseholder changes or a time duration has elapsed,
     /// the index reconciles the statuses for all routes on the cluster.
     ///
@@ -381,23 +583,24 @@ impl Index {
             (idx.name.clone(), idx.claims.clone())
         };
 
-        // The timer is reset when this instance becomes the leader and it is
-        // polled as long as it is the leader. The timer ensures that
+        // The timer is reset when this instance acquires a shard and it is
+        // polled as long as it owns any shard. The timer ensures that
         // reconciliation happens at consistent intervals after leadership is
         // acquired.
         let mut timer = time::interval(reconciliation_period);
         timer.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
 
-        let mut was_leader = false;
+        let mut was_owned = Owned::default();
         loop {
-            // Refresh the state of the lease on each iteration to ensure we're
-            // checking expiration.
-            let is_leader = claims.borrow_and_update().is_current_for(&instance);
-            if is_leader && !was_leader {
-                tracing::debug!("Became leader; resetting timer");
+            // Refresh the state of the leases on each iteration to ensure
+            // we're checking expiration.
+            let owned = claims.owned_and_update(&instance);
+            if owned.gained(&was_owned) {
+                tracing::debug!(shards = %owned, "Became leader; resetting timer");
                 timer.reset_immediately();
             }
-            was_leader = is_leader;
+            let is_leader = owned.any();
+            was_owned = owned;
 
             tokio::select! {
                 // Eagerly process claim updates to track leadership changes. If
@@ -405,10 +608,7 @@ impl Index {
                 biased;
                 res = claims.changed() => {
                     res.expect("Claims watch must not be dropped");
-                    if tracing::enabled!(tracing::Level::TRACE) {
-                        let c = claims.borrow();
-                        tracing::trace!(claim=?*c, "Changed");
-                    }
+                    tracing::trace!(?claims, "Changed");
                 }
 
                 // Only wait for the timer if this instance is the leader.
@@ -609,7 +809,8 @@ impl Index {
         id: &NamespaceGroupKindName,
         parent_ref: &routes::ParentReference,
     ) -> k8s::Condition {
-        // service is a valid parent if it exists and it has a cluster_ip.
+        // service is a valid parent if it exists and, for ExternalName
+        // services, has an external name.
         match self.services.get(service) {
             Some(svc) if svc.valid_parent_service() => {
                 if self.parent_has_conflicting_routes(parent_ref, &id.gkn.kind) {
@@ -618,7 +819,7 @@ impl Index {
                     accepted()
                 }
             }
-            Some(_svc) => headless_parent(),
+            Some(_svc) => missing_external_name(),
             None => no_matching_parent(),
         }
     }
@@ -877,15 +1078,22 @@ impl Index {
 
     fn backend_condition(
         &self,
+        id: &NamespaceGroupKindName,
         parent_ref: &routes::ParentReference,
         backend_refs: &[routes::BackendReference],
     ) -> k8s::Condition {
         for backend_ref in backend_refs.iter() {
+            if let Some(message) = backend_ref.not_permitted_message(&self.reference_grants, id) {
+                return ref_not_permitted(&message);
+            }
+
             match backend_ref {
-                routes::BackendReference::Unknown => {
+                routes::BackendReference::Unknown { group, kind } => {
                     // If even one backend has a reference to an unknown / unsupported
                     // reference, return invalid backend condition
-                    return invalid_backend_kind("");
+                    return invalid_backend_kind(
+                        &routes::BackendReference::unsupported_kind_message(group, kind),
+                    );
                 }
 
                 routes::BackendReference::Service(service) => {
@@ -893,6 +1101,11 @@ impl Index {
                         return backend_not_found();
                     }
                 }
+                routes::BackendReference::ServiceImport(service_import) => {
+                    if !self.service_imports.contains(service_import) {
+                        return backend_not_found();
+                    }
+                }
                 routes::BackendReference::EgressNetwork(egress_net) => {
                     if !self.egress_networks.contains_key(egress_net) {
                         return backend_not_found();
@@ -932,8 +1145,13 @@ impl Index {
 
         // Compute a status for each parent_ref which has a kind we support.
         let parent_statuses = route.parents.iter().filter_map(|parent_ref| {
-            let backend_condition = self.backend_condition(parent_ref, &route.backends);
+            let backend_condition = self.backend_condition(id, parent_ref, &route.backends);
             self.http_parent_status(id, parent_ref, backend_condition.clone())
+                .map(|mut status| {
+                    report_invalid_rules(&mut status.conditions, &route.invalid_rules);
+                    observe_generation(status.conditions.iter_mut().flatten(), route.generation);
+                    status
+                })
         });
 
         let all_statuses = unowned_statuses.chain(parent_statuses).collect::<Vec<_>>();
@@ -943,6 +1161,7 @@ impl Index {
             .flat_map(|status| status.parents.clone())
             .collect::<Vec<_>>();
         if eq_time_insensitive_http_route_parent_statuses(&all_statuses, &route_statuses) {
+            self.metrics.patch_skips.inc();
             return None;
         }
 
@@ -968,8 +1187,13 @@ impl Index {
 
         // Compute a status for each parent_ref which has a kind we support.
         let parent_statuses = route.parents.iter().filter_map(|parent_ref| {
-            let backend_condition = self.backend_condition(parent_ref, &route.backends);
+            let backend_condition = self.backend_condition(id, parent_ref, &route.backends);
             self.grpc_parent_status(id, parent_ref, backend_condition.clone())
+                .map(|mut status| {
+                    report_invalid_rules(&mut status.conditions, &route.invalid_rules);
+                    observe_generation(status.conditions.iter_mut().flatten(), route.generation);
+                    status
+                })
         });
 
         let all_statuses = unowned_statuses.chain(parent_statuses).collect::<Vec<_>>();
@@ -980,6 +1204,7 @@ impl Index {
             .collect::<Vec<_>>();
 
         if eq_time_insensitive_grpc_route_parent_statuses(&all_statuses, &route_statuses) {
+            self.metrics.patch_skips.inc();
             return None;
         }
 
@@ -1005,8 +1230,13 @@ impl Index {
 
         // Compute a status for each parent_ref which has a kind we support.
         let parent_statuses = route.parents.iter().filter_map(|parent_ref| {
-            let backend_condition = self.backend_condition(parent_ref, &route.backends);
+            let backend_condition = self.backend_condition(id, parent_ref, &route.backends);
             self.tls_parent_status(id, parent_ref, backend_condition.clone())
+                .map(|mut status| {
+                    report_invalid_rules(&mut status.conditions, &route.invalid_rules);
+                    observe_generation(status.conditions.iter_mut().flatten(), route.generation);
+                    status
+                })
         });
 
         let all_statuses = unowned_statuses.chain(parent_statuses).collect::<Vec<_>>();
@@ -1017,6 +1247,7 @@ impl Index {
             .collect::<Vec<_>>();
 
         if eq_time_insensitive_tls_route_parent_statuses(&all_statuses, &route_statuses) {
+            self.metrics.patch_skips.inc();
             return None;
         }
 
@@ -1042,8 +1273,13 @@ impl Index {
 
         // Compute a status for each parent_ref which has a kind we support.
         let parent_statuses = route.parents.iter().filter_map(|parent_ref| {
-            let backend_condition = self.backend_condition(parent_ref, &route.backends);
+            let backend_condition = self.backend_condition(id, parent_ref, &route.backends);
             self.tcp_parent_status(id, parent_ref, backend_condition.clone())
+                .map(|mut status| {
+                    report_invalid_rules(&mut status.conditions, &route.invalid_rules);
+                    observe_generation(status.conditions.iter_mut().flatten(), route.generation);
+                    status
+                })
         });
 
         let all_statuses = unowned_statuses.chain(parent_statuses).collect::<Vec<_>>();
@@ -1054,6 +1290,7 @@ impl Index {
             .collect::<Vec<_>>();
 
         if eq_time_insensitive_tcp_route_parent_statuses(&all_statuses, &route_statuses) {
+            self.metrics.patch_skips.inc();
             return None;
         }
 
@@ -1122,8 +1359,10 @@ impl Index {
         id: &NamespaceGroupKindName,
         ratelimit: &HttpLocalRateLimitPolicyRef,
     ) -> Option<k8s::Patch<serde_json::Value>> {
-        let status = self.target_ref_status(id, &ratelimit.target_ref)?;
+        let mut status = self.target_ref_status(id, &ratelimit.target_ref)?;
+        observe_generation(&mut status.conditions, ratelimit.generation);
         if eq_time_insensitive_conditions(&status.conditions, &ratelimit.status_conditions) {
+            self.metrics.patch_skips.inc();
             return None;
         }
 
@@ -1154,10 +1393,14 @@ impl Index {
             .cloned();
 
         let all_conditions: Vec<linkerd_policy_controller_k8s_api::Condition> = unowned_conditions
-            .chain(std::iter::once(self.network_condition(egress_net)))
+            .chain(std::iter::once(k8s::Condition {
+                observed_generation: egress_net.generation,
+                ..self.network_condition(egress_net)
+            }))
             .collect::<Vec<_>>();
 
         if eq_time_insensitive_conditions(&all_conditions, &egress_net.status_conditions) {
+            self.metrics.patch_skips.inc();
             return None;
         }
 
@@ -1168,15 +1411,14 @@ impl Index {
         make_patch(id, status)
     }
 
-    /// If this instance is the leader, reconcile the statuses for all resources
-    /// for which we control the status.
-    fn reconcile_if_leader(&self) {
-        let lease = self.claims.borrow();
-        if !lease.is_current_for(&self.name) {
-            tracing::trace!(%lease.holder, ?lease.expiry, "Reconcilation skipped");
+    /// If this instance is the leader of any shard, reconcile the statuses for
+    /// all resources in its shards for which we control the status.
+    pub(crate) fn reconcile_if_leader(&self) {
+        let owned = self.claims.owned(&self.name);
+        if !owned.any() {
+            tracing::trace!(claims = ?self.claims, "Reconcilation skipped");
             return;
         }
-        drop(lease);
 
         tracing::trace!(
             egressnetworks = self.egress_networks.len(),
@@ -1187,63 +1429,58 @@ impl Index {
             httplocalratelimits = self.ratelimits.len(),
             "Reconciling"
         );
-        let egressnetworks = self.reconcile_egress_networks();
-        let routes = self.reconcile_routes();
-        let ratelimits = self.reconcile_ratelimits();
+        let egressnetworks = self.reconcile_egress_networks(&owned);
+        let routes = self.reconcile_routes(&owned);
+        let ratelimits = self.reconcile_ratelimits(&owned);
 
         if egressnetworks + routes + ratelimits > 0 {
             tracing::debug!(egressnetworks, routes, ratelimits, "Reconciled");
         }
     }
 
-    fn reconcile_egress_networks(&self) -> usize {
+    fn reconcile_egress_networks(&self, owned: &Owned) -> usize {
         let mut patches = 0;
         for (id, net) in self.egress_networks.iter() {
-            let id = NamespaceGroupKindName {
-                namespace: id.namespace.clone(),
-                gkn: GroupKindName {
-                    group: policy::EgressNetwork::group(&()),
-                    kind: policy::EgressNetwork::kind(&()),
-                    name: id.name.clone().into(),
-                },
-            };
+            if !owned.contains(&id.namespace) {
+                continue;
+            }
+            let id = resource_gkn::<policy::EgressNetwork>(id);
 
             if let Some(patch) = self.make_egress_net_patch(&id, net) {
-                match self.updates.try_send(Update {
+                if self.updates.send(Update {
                     id: id.clone(),
                     patch,
                 }) {
-                    Ok(()) => {
-                        patches += 1;
-                        self.metrics.patch_enqueues.inc();
-                    }
-                    Err(error) => {
-                        self.metrics.patch_channel_full.inc();
-                        tracing::error!(%id.namespace, route = ?id.gkn, %error, "Failed to send egress network patch");
-                    }
+                    self.metrics.patch_superseded.inc();
                 }
+                patches += 1;
+                self.metrics.patch_enqueues.inc();
             }
         }
         patches
     }
 
-    fn reconcile_routes(&self) -> usize {
+    fn reconcile_routes(&self, owned: &Owned) -> usize {
         let mut patches = 0;
         let http_patches = self
             .http_route_refs
             .iter()
+            .filter(|(id, _)| owned.contains(&id.namespace))
             .filter_map(|(id, route)| self.make_http_route_patch(id, route).map(|p| (id, p)));
         let grpc_patches = self
             .grpc_route_refs
             .iter()
+            .filter(|(id, _)| owned.contains(&id.namespace))
             .filter_map(|(id, route)| self.make_grpc_route_patch(id, route).map(|p| (id, p)));
         let tls_patches = self
             .tls_route_refs
             .iter()
+            .filter(|(id, _)| owned.contains(&id.namespace))
             .filter_map(|(id, route)| self.make_tls_route_patch(id, route).map(|p| (id, p)));
         let tcp_patches = self
             .tcp_route_refs
             .iter()
+            .filter(|(id, _)| owned.contains(&id.namespace))
             .filter_map(|(id, route)| self.make_tcp_route_patch(id, route).map(|p| (id, p)));
 
         for (id, patch) in http_patches
@@ -1251,49 +1488,35 @@ impl Index {
             .chain(tls_patches)
             .chain(tcp_patches)
         {
-            match self.updates.try_send(Update {
+            if self.updates.send(Update {
                 id: id.clone(),
                 patch,
             }) {
-                Ok(()) => {
-                    patches += 1;
-                    self.metrics.patch_enqueues.inc();
-                }
-                Err(error) => {
-                    self.metrics.patch_channel_full.inc();
-                    tracing::error!(%id.namespace, route = ?id.gkn, %error, "Failed to send route patch");
-                }
+                self.metrics.patch_superseded.inc();
             }
+            patches += 1;
+            self.metrics.patch_enqueues.inc();
         }
         patches
     }
 
-    fn reconcile_ratelimits(&self) -> usize {
+    fn reconcile_ratelimits(&self, owned: &Owned) -> usize {
         let mut patches = 0;
         for (id, rl) in self.ratelimits.iter() {
-            let id = NamespaceGroupKindName {
-                namespace: id.namespace.clone(),
-                gkn: GroupKindName {
-                    group: policy::HttpLocalRateLimitPolicy::group(&()),
-                    kind: policy::HttpLocalRateLimitPolicy::kind(&()),
-                    name: id.name.clone().into(),
-                },
-            };
+            if !owned.contains(&id.namespace) {
+                continue;
+            }
+            let id = resource_gkn::<policy::HttpLocalRateLimitPolicy>(id);
 
             if let Some(patch) = self.make_ratelimit_patch(&id, rl) {
-                match self.updates.try_send(Update {
+                if self.updates.send(Update {
                     id: id.clone(),
                     patch,
                 }) {
-                    Ok(()) => {
-                        patches += 1;
-                        self.metrics.patch_enqueues.inc();
-                    }
-                    Err(error) => {
-                        self.metrics.patch_channel_full.inc();
-                        tracing::error!(%id.namespace, ratelimit = ?id.gkn, %error, "Failed to send ratelimit patch");
-                    }
+                    self.metrics.patch_superseded.inc();
                 }
+                patches += 1;
+                self.metrics.patch_enqueues.inc();
             }
         }
         patches
@@ -1343,6 +1566,18 @@ impl kubert::index::IndexNamespacedResource<policy::HttpRoute> for Index {
         let parents =
             routes::http::make_parents(&namespace, &resource.spec.parent_refs.unwrap_or_default());
 
+        // Find the rules that can't be served
+        let invalid_rules = routes::InvalidRules::validate(
+            resource.spec.rules.iter().flatten(),
+            http_rules::validate_linkerd_rule,
+            |rule| {
+                rule.backend_refs
+                    .iter()
+                    .flatten()
+                    .try_for_each(http_rules::validate_backend_filters)
+            },
+        );
+
         // Create the route backends
         let backends = routes::http::make_backends(
             &namespace,
@@ -1360,6 +1595,7 @@ impl kubert::index::IndexNamespacedResource<policy::HttpRoute> for Index {
             .into_iter()
             .flat_map(|status| status.parents)
             .collect();
+        let generation = resource.metadata.generation;
 
         // Construct route and insert into the index; if the HTTPRoute is
         // already in the index, and it hasn't changed, skip creating a patch.
@@ -1367,6 +1603,8 @@ impl kubert::index::IndexNamespacedResource<policy::HttpRoute> for Index {
             parents,
             backends,
             statuses: vec![gateway::HTTPRouteStatus { parents: statuses }],
+            generation,
+            invalid_rules,
         };
         tracing::trace!(?route);
         // Insert into the index; if the route is already in the index, and it hasn't
@@ -1388,6 +1626,7 @@ impl kubert::index::IndexNamespacedResource<policy::HttpRoute> for Index {
             },
         };
         self.http_route_refs.remove(&id);
+        self.updates.delete(id);
     }
 
     // Since apply only reindexes a single HTTPRoute at a time, there's no need
@@ -1413,6 +1652,18 @@ impl kubert::index::IndexNamespacedResource<gateway::HTTPRoute> for Index {
         let parents =
             routes::http::make_parents(&namespace, &resource.spec.parent_refs.unwrap_or_default());
 
+        // Find the rules that can't be served
+        let invalid_rules = routes::InvalidRules::validate(
+            resource.spec.rules.iter().flatten(),
+            http_rules::validate_gateway_rule,
+            |rule| {
+                rule.backend_refs
+                    .iter()
+                    .flatten()
+                    .try_for_each(http_rules::validate_backend_filters)
+            },
+        );
+
         // Create the route backends
         let backends = routes::http::make_backends(
             &namespace,
@@ -1430,6 +1681,7 @@ impl kubert::index::IndexNamespacedResource<gateway::HTTPRoute> for Index {
             .into_iter()
             .flat_map(|status| status.parents)
             .collect();
+        let generation = resource.metadata.generation;
 
         // Construct route and insert into the index; if the HTTPRoute is
         // already in the index, and it hasn't changed, skip creating a patch.
@@ -1437,6 +1689,8 @@ impl kubert::index::IndexNamespacedResource<gateway::HTTPRoute> for Index {
             parents,
             backends,
             statuses: vec![gateway::HTTPRouteStatus { parents: statuses }],
+            generation,
+            invalid_rules,
         };
         tracing::trace!(?route);
         // Insert into the index; if the route is already in the index, and it hasn't
@@ -1458,6 +1712,7 @@ impl kubert::index::IndexNamespacedResource<gateway::HTTPRoute> for Index {
             },
         };
         self.http_route_refs.remove(&id);
+        self.updates.delete(id);
     }
 
     // Since apply only reindexes a single HTTPRoute at a time, there's no need
@@ -1483,6 +1738,18 @@ impl kubert::index::IndexNamespacedResource<gateway::GRPCRoute> for Index {
         let parents =
             routes::grpc::make_parents(&namespace, &resource.spec.parent_refs.unwrap_or_default());
 
+        // Find the rules that can't be served
+        let invalid_rules = routes::InvalidRules::validate(
+            resource.spec.rules.iter().flatten(),
+            grpc_rules::validate_rule,
+            |rule| {
+                rule.backend_refs
+                    .iter()
+                    .flatten()
+                    .try_for_each(grpc_rules::validate_backend_filters)
+            },
+        );
+
         // Create the route backends
         let backends = routes::grpc::make_backends(
             &namespace,
@@ -1500,6 +1767,7 @@ impl kubert::index::IndexNamespacedResource<gateway::GRPCRoute> for Index {
             .into_iter()
             .flat_map(|status| status.parents)
             .collect();
+        let generation = resource.metadata.generation;
 
         // Construct route and insert into the index; if the GRPCRoute is
         // already in the index and it hasn't changed, skip creating a patch.
@@ -1507,6 +1775,8 @@ impl kubert::index::IndexNamespacedResource<gateway::GRPCRoute> for Index {
             parents,
             backends,
             statuses: vec![gateway::GRPCRouteStatus { parents: statuses }],
+            generation,
+            invalid_rules,
         };
         tracing::trace!(?route);
         // Insert into the index; if the route is already in the index, and it hasn't
@@ -1528,6 +1798,7 @@ impl kubert::index::IndexNamespacedResource<gateway::GRPCRoute> for Index {
             },
         };
         self.grpc_route_refs.remove(&id);
+        self.updates.delete(id);
     }
 
     // Since apply only reindexes a single GRPCRoute at a time, there's no need
@@ -1553,6 +1824,16 @@ impl kubert::index::IndexNamespacedResource<gateway::TLSRoute> for Index {
         let parents =
             routes::tls::make_parents(&namespace, &resource.spec.parent_refs.unwrap_or_default());
 
+        // Find the rules that can't be served. The index serves only routes
+        // with a single rule, so every rule of any other route is invalid.
+        let kind = gateway::TLSRoute::kind(&());
+        let rules = resource.spec.rules.len();
+        let invalid_rules = routes::InvalidRules::validate(
+            &resource.spec.rules,
+            |_| validate_single_rule(&kind, rules),
+            |_| Ok(()),
+        );
+
         // Create the route backends
         let backends = routes::tls::make_backends(
             &namespace,
@@ -1569,6 +1850,7 @@ impl kubert::index::IndexNamespacedResource<gateway::TLSRoute> for Index {
             .into_iter()
             .flat_map(|status| status.parents)
             .collect();
+        let generation = resource.metadata.generation;
 
         // Construct route and insert into the index; if the TLSRoute is
         // already in the index, and it hasn't changed, skip creating a patch.
@@ -1576,6 +1858,8 @@ impl kubert::index::IndexNamespacedResource<gateway::TLSRoute> for Index {
             parents,
             backends,
             statuses: vec![gateway::TLSRouteStatus { parents: statuses }],
+            generation,
+            invalid_rules,
         };
         tracing::trace!(?route);
         // Insert into the index; if the route is already in the index, and it hasn't
@@ -1597,6 +1881,7 @@ impl kubert::index::IndexNamespacedResource<gateway::TLSRoute> for Index {
             },
         };
         self.tls_route_refs.remove(&id);
+        self.updates.delete(id);
     }
 
     // Since apply only reindexes a single HTTPRoute at a time, there's no need
@@ -1622,6 +1907,16 @@ impl kubert::index::IndexNamespacedResource<gateway::TCPRoute> for Index {
         let parents =
             routes::tcp::make_parents(&namespace, &resource.spec.parent_refs.unwrap_or_default());
 
+        // Find the rules that can't be served. The index serves only routes
+        // with a single rule, so every rule of any other route is invalid.
+        let kind = gateway::TCPRoute::kind(&());
+        let rules = resource.spec.rules.len();
+        let invalid_rules = routes::InvalidRules::validate(
+            &resource.spec.rules,
+            |_| validate_single_rule(&kind, rules),
+            |_| Ok(()),
+        );
+
         // Create the route backends
         let backends = routes::tcp::make_backends(
             &namespace,
@@ -1638,6 +1933,7 @@ impl kubert::index::IndexNamespacedResource<gateway::TCPRoute> for Index {
             .into_iter()
             .flat_map(|status| status.parents)
             .collect();
+        let generation = resource.metadata.generation;
 
         // Construct route and insert into the index; if the TCPRoute is
         // already in the index, and it hasn't changed, skip creating a patch.
@@ -1645,6 +1941,8 @@ impl kubert::index::IndexNamespacedResource<gateway::TCPRoute> for Index {
             parents,
             backends,
             statuses: vec![gateway::TCPRouteStatus { parents: statuses }],
+            generation,
+            invalid_rules,
         };
         tracing::trace!(?route);
         // Insert into the index; if the route is already in the index, and it hasn't
@@ -1666,6 +1964,7 @@ impl kubert::index::IndexNamespacedResource<gateway::TCPRoute> for Index {
             },
         };
         self.tcp_route_refs.remove(&id);
+        self.updates.delete(id);
     }
 
     // Since apply only reindexes a single HTTPRoute at a time, there's no need
@@ -1707,6 +2006,62 @@ impl kubert::index::IndexNamespacedResource<k8s::Service> for Index {
     // to handle resets specially.
 }
 
+impl kubert::index::IndexNamespacedResource<gateway::ReferenceGrant> for Index {
+    fn apply(&mut self, resource: gateway::ReferenceGrant) {
+        if self.reference_grants.apply(resource) {
+            self.reconcile_if_leader();
+        }
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        if self.reference_grants.delete(&namespace, &name) {
+            self.reconcile_if_leader();
+        }
+    }
+
+    fn reset(
+        &mut self,
+        grants: Vec<gateway::ReferenceGrant>,
+        deleted: kubert::index::NamespacedRemoved,
+    ) {
+        let mut changed = false;
+        for grant in grants.into_iter() {
+            changed |= self.reference_grants.apply(grant);
+        }
+        for (namespace, names) in deleted.into_iter() {
+            for name in names.into_iter() {
+                changed |= self.reference_grants.delete(&namespace, &name);
+            }
+        }
+        // Grants are only enforced once the watch has synced.
+        changed |= self.reference_grants.set_synced();
+        if changed {
+            self.reconcile_if_leader();
+        }
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<k8s::multicluster::ServiceImport> for Index {
+    fn apply(&mut self, resource: k8s::multicluster::ServiceImport) {
+        let namespace = resource
+            .namespace()
+            .expect("ServiceImport must have a namespace");
+        let name = resource.name_unchecked();
+        self.service_imports
+            .insert(ResourceId::new(namespace, name));
+        self.reconcile_if_leader();
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        self.service_imports
+            .remove(&ResourceId::new(namespace, name));
+        self.reconcile_if_leader();
+    }
+
+    // Since apply only reindexes a single ServiceImport at a time, there's no
+    // need to handle resets specially.
+}
+
 impl kubert::index::IndexNamespacedResource<policy::HttpLocalRateLimitPolicy> for Index {
     fn apply(&mut self, resource: policy::HttpLocalRateLimitPolicy) {
         let namespace = resource
@@ -1728,6 +2083,7 @@ impl kubert::index::IndexNamespacedResource<policy::HttpLocalRateLimitPolicy> fo
             creation_timestamp,
             target_ref,
             status_conditions,
+            generation: resource.metadata.generation,
         };
 
         self.index_ratelimit(id, rl);
@@ -1736,6 +2092,8 @@ impl kubert::index::IndexNamespacedResource<policy::HttpLocalRateLimitPolicy> fo
     fn delete(&mut self, namespace: String, name: String) {
         let id = ResourceId::new(namespace, name);
         self.ratelimits.remove(&id);
+        self.updates
+            .delete(resource_gkn::<policy::HttpLocalRateLimitPolicy>(&id));
         self.reconcile_if_leader();
     }
 }
@@ -1777,6 +2135,7 @@ impl kubert::index::IndexNamespacedResource<policy::EgressNetwork> for Index {
         let net = EgressNetworkRef {
             status_conditions,
             networks,
+            generation: resource.metadata.generation,
         };
 
         self.index_egress_network(id, net);
@@ -1785,6 +2144,8 @@ impl kubert::index::IndexNamespacedResource<policy::EgressNetwork> for Index {
     fn delete(&mut self, namespace: String, name: String) {
         let id = ResourceId::new(namespace, name);
         self.egress_networks.remove(&id);
+        self.updates
+            .delete(resource_gkn::<policy::EgressNetwork>(&id));
         self.reconcile_if_leader();
     }
 }
@@ -1822,6 +2183,44 @@ fn now() -> DateTime<Utc> {
     now
 }
 
+/// Records that conditions were computed from the given generation of a
+/// resource's spec.
+fn observe_generation<'c>(
+    conditions: impl IntoIterator<Item = &'c mut k8s::Condition>,
+    generation: Option<i64>,
+) {
+    for condition in conditions {
+        condition.observed_generation = generation;
+    }
+}
+
+/// Reports the rules of a route that can't be served on an accepted parent.
+///
+/// A route is only rejected when none of its rules can be served; otherwise
+/// it remains accepted and is marked partially invalid.
+fn report_invalid_rules(
+    conds: &mut Option<Vec<k8s::Condition>>,
+    invalid_rules: &routes::InvalidRules,
+) {
+    if !invalid_rules.any() {
+        return;
+    }
+    let Some(conds) = conds.as_mut() else {
+        return;
+    };
+    let Some(accepted) = conds
+        .iter()
+        .position(|c| c.type_ == conditions::ACCEPTED && c.status == cond_statuses::STATUS_TRUE)
+    else {
+        return;
+    };
+    if invalid_rules.all() {
+        conds[accepted] = unsupported_value(&invalid_rules.message());
+    } else {
+        conds.push(partially_invalid(&invalid_rules.message()));
+    }
+}
+
 pub(crate) fn no_matching_parent() -> k8s::Condition {
     k8s::Condition {
         last_transition_time: k8s::Time(now()),
@@ -1844,10 +2243,10 @@ pub(crate) fn no_matching_target() -> k8s::Condition {
     }
 }
 
-fn headless_parent() -> k8s::Condition {
+fn missing_external_name() -> k8s::Condition {
     k8s::Condition {
         last_transition_time: k8s::Time(now()),
-        message: "parent service must have a ClusterIP".to_string(),
+        message: "ExternalName parent service must have an externalName".to_string(),
         observed_generation: None,
         reason: reasons::NO_MATCHING_PARENT.to_string(),
         status: cond_statuses::STATUS_FALSE.to_string(),
@@ -1899,6 +2298,28 @@ pub(crate) fn accepted() -> k8s::Condition {
     }
 }
 
+pub(crate) fn unsupported_value(message: &str) -> k8s::Condition {
+    k8s::Condition {
+        last_transition_time: k8s::Time(now()),
+        message: message.to_string(),
+        observed_generation: None,
+        reason: reasons::UNSUPPORTED_VALUE.to_string(),
+        status: cond_statuses::STATUS_FALSE.to_string(),
+        type_: conditions::ACCEPTED.to_string(),
+    }
+}
+
+pub(crate) fn partially_invalid(message: &str) -> k8s::Condition {
+    k8s::Condition {
+        last_transition_time: k8s::Time(now()),
+        message: message.to_string(),
+        observed_generation: None,
+        reason: reasons::UNSUPPORTED_VALUE.to_string(),
+        status: cond_statuses::STATUS_TRUE.to_string(),
+        type_: conditions::PARTIALLY_INVALID.to_string(),
+    }
+}
+
 pub(crate) fn in_cluster_net_overlap() -> k8s::Condition {
     k8s::Condition {
         last_transition_time: k8s::Time(now()),
@@ -1943,6 +2364,17 @@ pub(crate) fn invalid_backend_kind(message: &str) -> k8s::Condition {
     }
 }
 
+pub(crate) fn ref_not_permitted(message: &str) -> k8s::Condition {
+    k8s::Condition {
+        last_transition_time: k8s::Time(now()),
+        message: message.to_string(),
+        observed_generation: None,
+        reason: reasons::REF_NOT_PERMITTED.to_string(),
+        status: cond_statuses::STATUS_FALSE.to_string(),
+        type_: conditions::RESOLVED_REFS.to_string(),
+    }
+}
+
 pub(crate) fn eq_time_insensitive_http_route_parent_statuses(
     left: &[gateway::HTTPRouteStatusParents],
     right: &[gateway::HTTPRouteStatusParents],
@@ -1,10 +1,18 @@
+mod events;
 mod index;
+mod queue;
 mod ratelimit;
+mod reference_grant;
 mod resource_id;
 mod routes;
 mod service;
+mod shard;
 
 #[cfg(test)]
 mod tests;
 
-pub use self::index::{Controller, ControllerMetrics, Index, IndexMetrics};
+pub use self::{
+    index::{Controller, ControllerMetrics, Index, IndexMetrics},
+    queue::{channel as patch_queue, PatchReceiver, PatchSender},
+    shard::Shards,
+};
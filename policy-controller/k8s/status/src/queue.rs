@@ -0,0 +1,281 @@
+//! A keyed queue of status patches.
+//!
+//! The index may produce many patches for a resource before the controller
+//! applies any of them. Only the latest patch for each resource is kept, so
+//! the queue is bounded by the number of resources rather than by the rate of
+//! index updates, and sending never fails.
+
+use crate::{index::Update, resource_id::NamespaceGroupKindName};
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use linkerd_policy_controller_k8s_api as k8s;
+use parking_lot::Mutex;
+use std::{collections::VecDeque, sync::Arc};
+use tokio::{
+    sync::Notify,
+    time::{self, Duration},
+};
+
+/// Creates a patch queue.
+pub fn channel() -> (PatchSender, PatchReceiver) {
+    let shared = Arc::new(Shared::default());
+    (PatchSender(shared.clone()), PatchReceiver(shared))
+}
+
+#[derive(Clone, Debug)]
+pub struct PatchSender(Arc<Shared>);
+
+#[derive(Debug)]
+pub struct PatchReceiver(Arc<Shared>);
+
+/// A patch taken from the queue.
+#[derive(Debug)]
+pub(crate) struct Pending {
+    pub(crate) update: Update,
+    /// The number of times this patch has already been attempted.
+    pub(crate) attempts: u32,
+    seq: u64,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    state: Mutex<State>,
+    notify: Notify,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Resources with a queued patch, in the order they were first queued.
+    order: VecDeque<NamespaceGroupKindName>,
+    queued: HashMap<NamespaceGroupKindName, Queued>,
+
+    /// The sequence number of the latest patch sent for each resource that
+    /// has not yet been applied. A patch that fails is only retried if it is
+    /// still the latest.
+    latest: HashMap<NamespaceGroupKindName, u64>,
+    next_seq: u64,
+
+    /// Resources that have been deleted since the receiver last checked.
+    deleted: HashSet<NamespaceGroupKindName>,
+}
+
+#[derive(Debug)]
+struct Queued {
+    patch: k8s::Patch<serde_json::Value>,
+    attempts: u32,
+    seq: u64,
+}
+
+// === impl PatchSender ===
+
+impl PatchSender {
+    /// Queues a patch, replacing any patch already queued for the same
+    /// resource. Returns true if a queued patch was replaced.
+    pub fn send(&self, Update { id, patch }: Update) -> bool {
+        let replaced = {
+            let mut state = self.0.state.lock();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.latest.insert(id.clone(), seq);
+            state.deleted.remove(&id);
+            let queued = Queued {
+                patch,
+                attempts: 0,
+                seq,
+            };
+            match state.queued.insert(id.clone(), queued) {
+                Some(_) => true,
+                None => {
+                    state.order.push_back(id);
+                    false
+                }
+            }
+        };
+        self.0.notify.notify_one();
+        replaced
+    }
+
+    /// Records that a resource was deleted, discarding any patch queued or
+    /// pending retry for it.
+    pub fn delete(&self, id: NamespaceGroupKindName) {
+        let mut state = self.0.state.lock();
+        if state.queued.remove(&id).is_some() {
+            state.order.retain(|queued| *queued != id);
+        }
+        state.latest.remove(&id);
+        state.deleted.insert(id);
+    }
+}
+
+// === impl PatchReceiver ===
+
+impl PatchReceiver {
+    /// Waits for the next queued patch.
+    pub(crate) async fn recv(&mut self) -> Pending {
+        loop {
+            if let Some(pending) = self.0.pop() {
+                return pending;
+            }
+            self.0.notify.notified().await;
+        }
+    }
+
+    /// Takes the next queued patch, if there is one, and marks it as applied.
+    #[cfg(test)]
+    pub(crate) fn try_recv(&mut self) -> Option<Update> {
+        let pending = self.0.pop()?;
+        self.complete(&pending);
+        Some(pending.update)
+    }
+
+    /// Takes the resources that have been deleted since the last call.
+    pub(crate) fn take_deleted(&self) -> Vec<NamespaceGroupKindName> {
+        self.0.state.lock().deleted.drain().collect()
+    }
+
+    /// Records that a patch has been applied or abandoned.
+    pub(crate) fn complete(&self, pending: &Pending) {
+        let mut state = self.0.state.lock();
+        if state.latest.get(&pending.update.id) == Some(&pending.seq) {
+            state.latest.remove(&pending.update.id);
+        }
+    }
+
+    /// Queues a failed patch again after `delay`, unless a newer patch for the
+    /// same resource has been sent in the meantime.
+    pub(crate) fn retry(&self, pending: Pending, delay: Duration) {
+        let shared = self.0.clone();
+        tokio::spawn(async move {
+            time::sleep(delay).await;
+            shared.requeue(pending);
+        });
+    }
+}
+
+// === impl Shared ===
+
+impl Shared {
+    fn pop(&self) -> Option<Pending> {
+        let mut state = self.state.lock();
+        let id = state.order.pop_front()?;
+        let Queued {
+            patch,
+            attempts,
+            seq,
+        } = state
+            .queued
+            .remove(&id)
+            .expect("queued resources must have a patch");
+        Some(Pending {
+            update: Update { id, patch },
+            attempts,
+            seq,
+        })
+    }
+
+    fn requeue(&self, pending: Pending) {
+        let Pending {
+            update: Update { id, patch },
+            attempts,
+            seq,
+        } = pending;
+        {
+            let mut state = self.state.lock();
+            if state.latest.get(&id) != Some(&seq) {
+                tracing::debug!(?id, "Discarding retry for superseded patch");
+                return;
+            }
+            let queued = Queued {
+                patch,
+                attempts: attempts + 1,
+                seq,
+            };
+            state.queued.insert(id.clone(), queued);
+            state.order.push_back(id);
+        }
+        self.notify.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkerd_policy_controller_core::routes::GroupKindName;
+
+    fn update(name: &str, status: &str) -> Update {
+        Update {
+            id: NamespaceGroupKindName {
+                namespace: "ns".to_string(),
+                gkn: GroupKindName {
+                    group: "policy.linkerd.io".into(),
+                    kind: "HTTPRoute".into(),
+                    name: name.to_string().into(),
+                },
+            },
+            patch: k8s::Patch::Merge(serde_json::json!({ "status": status })),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn keeps_latest_patch() {
+        let (tx, mut rx) = channel();
+        assert!(!tx.send(update("a", "1")));
+        assert!(!tx.send(update("b", "1")));
+        assert!(tx.send(update("a", "2")));
+
+        assert_eq!(rx.try_recv().unwrap(), update("a", "2"));
+        assert_eq!(rx.try_recv().unwrap(), update("b", "1"));
+        assert!(rx.try_recv().is_none());
+        assert!(rx.0.state.lock().latest.is_empty());
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn retries_latest_patch() {
+        let (tx, mut rx) = channel();
+        tx.send(update("a", "1"));
+        let pending = rx.recv().await;
+        assert_eq!(pending.attempts, 0);
+
+        // A failed patch is retried after the delay.
+        rx.retry(pending, Duration::from_secs(1));
+        assert!(rx.try_recv().is_none());
+        let pending = rx.recv().await;
+        assert_eq!(pending.update, update("a", "1"));
+        assert_eq!(pending.attempts, 1);
+
+        // A failed patch is not retried once a newer patch has been sent.
+        rx.retry(pending, Duration::from_secs(1));
+        tx.send(update("a", "2"));
+        let pending = rx.recv().await;
+        assert_eq!(pending.update, update("a", "2"));
+        rx.complete(&pending);
+        time::sleep(Duration::from_secs(2)).await;
+        assert!(rx.try_recv().is_none());
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn discards_patches_for_deleted_resources() {
+        let (tx, mut rx) = channel();
+        tx.send(update("a", "1"));
+        tx.send(update("b", "1"));
+        let pending = rx.recv().await;
+        assert_eq!(pending.update, update("a", "1"));
+
+        // Deleting a resource discards its queued patch and any retry.
+        rx.retry(pending, Duration::from_secs(1));
+        tx.delete(update("a", "1").id);
+        tx.delete(update("b", "1").id);
+        time::sleep(Duration::from_secs(2)).await;
+        assert!(rx.try_recv().is_none());
+
+        let mut deleted = rx.take_deleted();
+        deleted.sort_by(|a, b| a.gkn.name.cmp(&b.gkn.name));
+        assert_eq!(deleted, vec![update("a", "1").id, update("b", "1").id]);
+        assert!(rx.take_deleted().is_empty());
+
+        // A resource that is recreated is no longer reported as deleted.
+        tx.delete(update("a", "1").id);
+        tx.send(update("a", "2"));
+        assert!(rx.take_deleted().is_empty());
+        assert_eq!(rx.try_recv().unwrap(), update("a", "2"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Cross-namespace backend references permitted by Gateway API
+//! `ReferenceGrant`s.
+//!
+//! Routes are reported as `RefNotPermitted` with the same rules the index uses
+//! to drop their backends: grants are only enforced once the `ReferenceGrant`
+//! API is served and its watch has synced.
+
+use crate::resource_id::NamespaceGroupKindName;
+use ahash::AHashMap as HashMap;
+use linkerd_policy_controller_k8s_api::{
+    gateway::{ReferenceGrant, ReferenceGrantSpec},
+    ResourceExt,
+};
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ReferenceGrants {
+    /// Grant specs by namespace and name.
+    grants: HashMap<(String, String), ReferenceGrantSpec>,
+    served: bool,
+    synced: bool,
+}
+
+// === impl ReferenceGrants ===
+
+impl ReferenceGrants {
+    /// Indexes a grant, returning true if it changed.
+    pub(crate) fn apply(&mut self, grant: ReferenceGrant) -> bool {
+        let namespace = grant
+            .namespace()
+            .expect("ReferenceGrant must have a namespace");
+        let key = (namespace, grant.name_unchecked());
+        if self.grants.get(&key) == Some(&grant.spec) {
+            return false;
+        }
+        self.grants.insert(key, grant.spec);
+        true
+    }
+
+    /// Removes a grant, returning true if it was indexed.
+    pub(crate) fn delete(&mut self, namespace: &str, name: &str) -> bool {
+        self.grants
+            .remove(&(namespace.to_string(), name.to_string()))
+            .is_some()
+    }
+
+    /// Records whether the `ReferenceGrant` API is served, returning true if
+    /// grants are enforced differently as a result.
+    pub(crate) fn set_served(&mut self, served: bool) -> bool {
+        let enforced = self.is_enforced();
+        self.served = served;
+        self.synced = false;
+        enforced != self.is_enforced()
+    }
+
+    /// Records that the watch has synced, returning true if grants are
+    /// enforced differently as a result.
+    pub(crate) fn set_synced(&mut self) -> bool {
+        let enforced = self.is_enforced();
+        self.synced = true;
+        enforced != self.is_enforced()
+    }
+
+    fn is_enforced(&self) -> bool {
+        self.served && self.synced
+    }
+
+    /// Returns true if `route` may refer to the named backend.
+    pub(crate) fn permits(
+        &self,
+        route: &NamespaceGroupKindName,
+        group: &str,
+        kind: &str,
+        namespace: &str,
+        name: &str,
+    ) -> bool {
+        if namespace == route.namespace || !self.is_enforced() {
+            return true;
+        }
+
+        self.grants
+            .iter()
+            .filter(|((ns, _), _)| ns == namespace)
+            .any(|(_, spec)| {
+                let from = spec.from.iter().any(|from| {
+                    eq_group(&from.group, &route.gkn.group)
+                        && from.kind == route.gkn.kind
+                        && from.namespace == route.namespace
+                });
+                let to = spec.to.iter().any(|to| {
+                    eq_group(&to.group, group)
+                        && to.kind == kind
+                        && to.name.as_deref().is_none_or(|n| n == name)
+                });
+                from && to
+            })
+    }
+}
+
+/// The core API group may be written as either an empty string or `core`.
+fn eq_group(a: &str, b: &str) -> bool {
+    fn core(group: &str) -> &str {
+        if group == "core" {
+            ""
+        } else {
+            group
+        }
+    }
+    core(a) == core(b)
+}
@@ -1,4 +1,10 @@
-use crate::resource_id::ResourceId;
+use crate::{
+    reference_grant::ReferenceGrants,
+    resource_id::{NamespaceGroupKindName, ResourceId},
+};
+use linkerd_policy_controller_k8s_api::{
+    self as k8s, multicluster::ServiceImport, policy, Resource,
+};
 
 pub(crate) mod grpc;
 pub(crate) mod http;
@@ -23,6 +29,118 @@ pub enum ParentReference {
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum BackendReference {
     Service(ResourceId),
+    ServiceImport(ResourceId),
     EgressNetwork(ResourceId),
-    Unknown,
+    Unknown { group: String, kind: String },
+}
+
+impl BackendReference {
+    fn unknown(group: Option<&str>, kind: Option<&str>) -> Self {
+        Self::Unknown {
+            group: group.unwrap_or("core").to_string(),
+            kind: kind.unwrap_or("Service").to_string(),
+        }
+    }
+
+    /// Describes a backend kind that isn't supported, along with the kinds
+    /// that are, like a Gateway listener's `supportedKinds`.
+    pub(crate) fn unsupported_kind_message(group: &str, kind: &str) -> String {
+        let supported = [
+            (k8s::Service::group(&()), k8s::Service::kind(&())),
+            (ServiceImport::group(&()), ServiceImport::kind(&())),
+            (
+                policy::EgressNetwork::group(&()),
+                policy::EgressNetwork::kind(&()),
+            ),
+        ]
+        .into_iter()
+        .map(|(group, kind)| match &*group {
+            "" => format!("core/{kind}"),
+            group => format!("{group}/{kind}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+        format!("unsupported backend kind {group}/{kind}; supported kinds: {supported}")
+    }
+
+    /// Returns a message naming the backend if it is in another namespace and
+    /// no ReferenceGrant permits `route` to refer to it.
+    pub(crate) fn not_permitted_message(
+        &self,
+        grants: &ReferenceGrants,
+        route: &NamespaceGroupKindName,
+    ) -> Option<String> {
+        let (group, kind, id) = match self {
+            Self::Service(id) => (k8s::Service::group(&()), k8s::Service::kind(&()), id),
+            Self::ServiceImport(id) => (ServiceImport::group(&()), ServiceImport::kind(&()), id),
+            Self::EgressNetwork(id) => (
+                policy::EgressNetwork::group(&()),
+                policy::EgressNetwork::kind(&()),
+                id,
+            ),
+            Self::Unknown { .. } => return None,
+        };
+        if grants.permits(route, &group, &kind, &id.namespace, &id.name) {
+            return None;
+        }
+        Some(format!(
+            "no ReferenceGrant permits a reference to {kind} {}/{}",
+            id.namespace, id.name
+        ))
+    }
+}
+
+/// The rules of an xRoute that the proxy cannot serve.
+///
+/// The policy controller's index drops these rules and serves the rest of the
+/// route, so they are reported in the route's status rather than causing the
+/// whole route to be rejected. Rules are validated with the same checks the
+/// index uses, so that the status agrees with the policy that is served.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct InvalidRules {
+    /// The number of rules in the route's spec.
+    pub(crate) total: usize,
+    /// A description of each rule that cannot be served.
+    pub(crate) errors: Vec<String>,
+    /// A description of each rule that is served with a backend that cannot
+    /// be, so that requests routed to that backend fail.
+    pub(crate) backend_errors: Vec<String>,
+}
+
+impl InvalidRules {
+    pub(crate) fn validate<'r, R: 'r>(
+        rules: impl IntoIterator<Item = &'r R>,
+        validate: impl Fn(&R) -> k8s::routes::Result,
+        validate_backends: impl Fn(&R) -> k8s::routes::Result,
+    ) -> Self {
+        let mut invalid = Self::default();
+        for (i, rule) in rules.into_iter().enumerate() {
+            invalid.total += 1;
+            if let Err(error) = validate(rule) {
+                invalid.errors.push(format!("rule {i}: {error}"));
+            } else if let Err(error) = validate_backends(rule) {
+                invalid.backend_errors.push(format!("rule {i}: {error}"));
+            }
+        }
+        invalid
+    }
+
+    /// Returns true if any rule can't be served as specified.
+    pub(crate) fn any(&self) -> bool {
+        !self.errors.is_empty() || !self.backend_errors.is_empty()
+    }
+
+    /// Returns true if the route has rules and none of them can be served.
+    pub(crate) fn all(&self) -> bool {
+        self.total > 0 && self.errors.len() == self.total
+    }
+
+    pub(crate) fn message(&self) -> String {
+        self.errors
+            .iter()
+            .chain(&self.backend_errors)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
 }
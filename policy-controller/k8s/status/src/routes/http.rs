@@ -73,6 +73,13 @@ fn to_backend_ref(
             .unwrap_or(default_namespace);
         let name = backend_ref.name.clone();
         BackendReference::Service(ResourceId::new(namespace.to_string(), name))
+    } else if backend_ref_targets_kind::<k8s::multicluster::ServiceImport>(backend_ref) {
+        let namespace = backend_ref
+            .namespace
+            .as_deref()
+            .unwrap_or(default_namespace);
+        let name = backend_ref.name.clone();
+        BackendReference::ServiceImport(ResourceId::new(namespace.to_string(), name))
     } else if backend_ref_targets_kind::<policy::EgressNetwork>(backend_ref) {
         let namespace = backend_ref
             .namespace
@@ -81,7 +88,7 @@ fn to_backend_ref(
         let name = backend_ref.name.clone();
         BackendReference::EgressNetwork(ResourceId::new(namespace.to_string(), name))
     } else {
-        BackendReference::Unknown
+        BackendReference::unknown(backend_ref.group.as_deref(), backend_ref.kind.as_deref())
     }
 }
 
@@ -251,6 +258,6 @@ mod test {
         let egress_net = iter.next().unwrap();
         assert!(matches!(egress_net, BackendReference::EgressNetwork(_)));
         let unknown = iter.next().unwrap();
-        assert!(matches!(unknown, BackendReference::Unknown))
+        assert!(matches!(unknown, BackendReference::Unknown { .. }))
     }
 }
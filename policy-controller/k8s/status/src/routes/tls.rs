@@ -75,6 +75,15 @@ fn to_backend_ref(
             namespace.to_string(),
             backend_ref.name.clone(),
         ))
+    } else if backend_ref_targets_kind::<k8s::multicluster::ServiceImport>(backend_ref) {
+        let namespace = backend_ref
+            .namespace
+            .as_deref()
+            .unwrap_or(default_namespace);
+        BackendReference::ServiceImport(ResourceId::new(
+            namespace.to_string(),
+            backend_ref.name.clone(),
+        ))
     } else if backend_ref_targets_kind::<policy::EgressNetwork>(backend_ref) {
         let namespace = backend_ref
             .namespace
@@ -85,7 +94,7 @@ fn to_backend_ref(
             backend_ref.name.clone(),
         ))
     } else {
-        BackendReference::Unknown
+        BackendReference::unknown(backend_ref.group.as_deref(), backend_ref.kind.as_deref())
     }
 }
 
@@ -226,6 +235,6 @@ mod test {
         let egress_net = iter.next().unwrap();
         assert!(matches!(egress_net, BackendReference::EgressNetwork(_)));
         let unknown = iter.next().unwrap();
-        assert!(matches!(unknown, BackendReference::Unknown))
+        assert!(matches!(unknown, BackendReference::Unknown { .. }))
     }
 }
@@ -2,19 +2,21 @@ use linkerd_policy_controller_k8s_api as k8s_core_api;
 
 #[derive(Default)]
 pub(crate) struct Service {
-    cluster_ip: Option<String>,
     type_: Option<String>,
+    external_name: Option<String>,
 }
 
 impl Service {
     pub(crate) fn valid_parent_service(&self) -> bool {
-        let cluster_ip = self
-            .cluster_ip
-            .as_ref()
-            .filter(|cip| !cip.eq_ignore_ascii_case("none"))
-            .is_some();
-        let external_name = self.type_.as_deref() == Some("ExternalName");
-        cluster_ip && !external_name
+        // Headless services are valid parents: their endpoints are indexed
+        // by address, so routes apply per destination pod. ExternalName
+        // services are discovered by their target DNS name, so they must
+        // have one.
+        self.type_.as_deref() != Some("ExternalName")
+            || self
+                .external_name
+                .as_deref()
+                .is_some_and(|name| !name.is_empty())
     }
 }
 
@@ -22,8 +24,8 @@ impl From<k8s_core_api::Service> for Service {
     fn from(svc: k8s_core_api::Service) -> Self {
         svc.spec
             .map(|spec| Self {
-                cluster_ip: spec.cluster_ip,
                 type_: spec.type_,
+                external_name: spec.external_name,
             })
             .unwrap_or_default()
     }
@@ -0,0 +1,122 @@
+//! Divides ownership of resource statuses among status controllers.
+//!
+//! Each namespace is assigned to one of a fixed number of shards, and each
+//! shard is guarded by its own write lease. A controller only writes the
+//! statuses of resources in namespaces whose shard lease it holds, so that
+//! status writes are spread across replicas and a lease changing hands only
+//! delays updates in its own shard.
+
+use kubert::lease::Claim;
+use std::{fmt, sync::Arc};
+use tokio::sync::watch::{error::RecvError, Receiver};
+
+/// The claims on each shard's write lease, indexed by shard.
+#[derive(Clone, Debug)]
+pub struct Shards(Vec<Receiver<Arc<Claim>>>);
+
+/// The shards whose leases are held by a controller at a point in time.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Owned(Vec<bool>);
+
+// === impl Shards ===
+
+impl Shards {
+    /// Creates shards guarded by the given lease claims. The claims must be
+    /// provided in the same order by all controllers.
+    pub fn new(claims: Vec<Receiver<Arc<Claim>>>) -> Self {
+        assert!(!claims.is_empty(), "at least one shard is required");
+        Self(claims)
+    }
+
+    /// Returns the shards currently held by `claimant`, marking all claims as
+    /// seen.
+    pub(crate) fn owned_and_update(&mut self, claimant: &str) -> Owned {
+        Owned(
+            self.0
+                .iter_mut()
+                .map(|claims| claims.borrow_and_update().is_current_for(claimant))
+                .collect(),
+        )
+    }
+
+    /// Returns the shards currently held by `claimant`.
+    pub(crate) fn owned(&self, claimant: &str) -> Owned {
+        Owned(
+            self.0
+                .iter()
+                .map(|claims| claims.borrow().is_current_for(claimant))
+                .collect(),
+        )
+    }
+
+    /// Waits for any shard's claim to change.
+    pub(crate) async fn changed(&mut self) -> Result<(), RecvError> {
+        if let [claims] = self.0.as_mut_slice() {
+            return claims.changed().await;
+        }
+        let changes = self.0.iter_mut().map(|claims| Box::pin(claims.changed()));
+        futures::future::select_all(changes).await.0
+    }
+}
+
+impl From<Receiver<Arc<Claim>>> for Shards {
+    fn from(claims: Receiver<Arc<Claim>>) -> Self {
+        Self::new(vec![claims])
+    }
+}
+
+// === impl Owned ===
+
+impl Owned {
+    /// Returns true if any shard is held.
+    pub(crate) fn any(&self) -> bool {
+        self.0.iter().any(|owned| *owned)
+    }
+
+    /// Returns true if the shard for `namespace` is held.
+    pub(crate) fn contains(&self, namespace: &str) -> bool {
+        if self.0.is_empty() {
+            return false;
+        }
+        self.0[shard(namespace, self.0.len())]
+    }
+
+    /// Returns true if any shard is held that was not held in `prior`.
+    pub(crate) fn gained(&self, prior: &Self) -> bool {
+        self.0
+            .iter()
+            .enumerate()
+            .any(|(i, owned)| *owned && !prior.0.get(i).copied().unwrap_or(false))
+    }
+}
+
+impl fmt::Display for Owned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut shards = self
+            .0
+            .iter()
+            .enumerate()
+            .filter_map(|(i, owned)| owned.then_some(i));
+        write!(f, "[")?;
+        if let Some(first) = shards.next() {
+            write!(f, "{first}")?;
+            for shard in shards {
+                write!(f, ",{shard}")?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/// Assigns a namespace to one of `shards` shards.
+///
+/// Every controller must agree on the assignment, so this uses FNV-1a rather
+/// than a randomly seeded hasher.
+pub(crate) fn shard(namespace: &str, shards: usize) -> usize {
+    const OFFSET: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    let hash = namespace
+        .bytes()
+        .fold(OFFSET, |hash, b| (hash ^ u64::from(b)).wrapping_mul(PRIME));
+    (hash % shards as u64) as usize
+}
@@ -2,8 +2,10 @@ use linkerd_policy_controller_core::IpNet;
 use linkerd_policy_controller_k8s_api::{self as k8s_core_api, policy as linkerd_k8s_api};
 mod conflict;
 mod egress_network;
+mod events;
 mod ratelimit;
 mod routes;
+mod shard;
 
 pub fn default_cluster_networks() -> Vec<IpNet> {
     vec![
@@ -1,6 +1,7 @@
 #[cfg(test)]
 use crate::{
     index::{GRPCRouteRef, HTTPRouteRef, SharedIndex, TCPRouteRef, TLSRouteRef},
+    queue,
     resource_id::{NamespaceGroupKindName, ResourceId},
     routes,
     tests::default_cluster_networks,
@@ -10,7 +11,7 @@ use chrono::{DateTime, Utc};
 use linkerd_policy_controller_core::routes::GroupKindName;
 use linkerd_policy_controller_k8s_api::{gateway, Resource};
 use std::{sync::Arc, vec};
-use tokio::sync::{mpsc, watch};
+use tokio::sync::watch;
 
 enum ParentRefType {
     Service,
@@ -24,7 +25,7 @@ fn make_index() -> SharedIndex {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, _) = mpsc::channel(10000);
+    let (updates_tx, _) = queue::channel();
     Index::shared(
         hostname,
         claims_rx,
@@ -62,6 +63,8 @@ fn grpc_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
     index.write().update_http_route(
@@ -77,6 +80,8 @@ fn grpc_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
     index.write().update_tls_route(
@@ -92,6 +97,8 @@ fn grpc_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
     index.write().update_tcp_route(
@@ -107,6 +114,8 @@ fn grpc_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -143,6 +152,8 @@ fn http_route_conflict_grpc(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -179,6 +190,8 @@ fn http_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
     index.write().update_tls_route(
@@ -194,6 +207,8 @@ fn http_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
     index.write().update_tcp_route(
@@ -209,6 +224,8 @@ fn http_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -245,6 +262,8 @@ fn tls_route_conflict_http(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -281,6 +300,8 @@ fn tls_route_conflict_grpc(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -317,6 +338,8 @@ fn tls_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
     index.write().update_tcp_route(
@@ -332,6 +355,8 @@ fn tls_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -368,6 +393,8 @@ fn tcp_route_conflict_grpc(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -404,6 +431,8 @@ fn tcp_route_conflict_http(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -440,6 +469,8 @@ fn tcp_route_conflict_tls(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -476,6 +507,8 @@ fn tcp_route_no_conflict(p: ParentRefType) {
             parents: vec![parent.clone()],
             statuses: vec![],
             backends: vec![],
+            generation: None,
+            invalid_rules: Default::default(),
         },
     );
 
@@ -1,7 +1,8 @@
 use crate::{
     index::{accepted, in_cluster_net_overlap},
+    queue,
     resource_id::NamespaceGroupKindName,
-    tests::default_cluster_networks,
+    tests::{default_cluster_networks, make_server},
     Index, IndexMetrics,
 };
 use chrono::{DateTime, Utc};
@@ -13,7 +14,7 @@ use linkerd_policy_controller_k8s_api::{
     Resource,
 };
 use std::{sync::Arc, vec};
-use tokio::sync::{mpsc, watch};
+use tokio::sync::watch;
 
 #[test]
 fn egress_network_with_no_networks_specified() {
@@ -23,7 +24,7 @@ fn egress_network_with_no_networks_specified() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -65,7 +66,7 @@ fn egress_network_with_no_networks_specified() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -76,7 +77,7 @@ fn egress_network_with_nonoverlapping_networks_specified() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -127,7 +128,7 @@ fn egress_network_with_nonoverlapping_networks_specified() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -138,7 +139,7 @@ fn egress_network_with_overlapping_networks_specified() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -186,5 +187,113 @@ fn egress_network_with_overlapping_networks_specified() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
+}
+
+#[test]
+fn egress_network_with_current_status_is_not_patched() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = queue::channel();
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        IndexMetrics::register(&mut Default::default()),
+        default_cluster_networks(),
+    );
+
+    // The observed status already matches the desired status.
+    let egress_network = linkerd_k8s_api::EgressNetwork {
+        metadata: k8s_core_api::ObjectMeta {
+            name: Some("egress".to_string()),
+            namespace: Some("ns".to_string()),
+            ..Default::default()
+        },
+        spec: linkerd_k8s_api::EgressNetworkSpec {
+            networks: None,
+            traffic_policy: linkerd_k8s_api::TrafficPolicy::Allow,
+        },
+        status: Some(EgressNetworkStatus {
+            conditions: vec![accepted()],
+        }),
+    };
+
+    index.write().apply(egress_network);
+    assert!(updates_rx.try_recv().is_none());
+
+    // Reconciling again still doesn't produce a patch.
+    index
+        .write()
+        .apply(make_server("ns", "server", 8080, vec![], vec![], None));
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn egress_network_status_observes_generation() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = queue::channel();
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        IndexMetrics::register(&mut Default::default()),
+        default_cluster_networks(),
+    );
+
+    let id = NamespaceGroupKindName {
+        namespace: "ns".to_string(),
+        gkn: GroupKindName {
+            group: linkerd_k8s_api::EgressNetwork::group(&()),
+            kind: linkerd_k8s_api::EgressNetwork::kind(&()),
+            name: "egress".into(),
+        },
+    };
+
+    let mut egress_network = linkerd_k8s_api::EgressNetwork {
+        metadata: k8s_core_api::ObjectMeta {
+            name: Some(id.gkn.name.to_string()),
+            namespace: Some(id.namespace.clone()),
+            generation: Some(2),
+            ..Default::default()
+        },
+        spec: linkerd_k8s_api::EgressNetworkSpec {
+            networks: None,
+            traffic_policy: linkerd_k8s_api::TrafficPolicy::Allow,
+        },
+        status: Some(EgressNetworkStatus {
+            conditions: vec![k8s_core_api::Condition {
+                observed_generation: Some(1),
+                ..accepted()
+            }],
+        }),
+    };
+
+    // A status that reflects an older generation is updated.
+    index.write().apply(egress_network.clone());
+    let status = EgressNetworkStatus {
+        conditions: vec![k8s_core_api::Condition {
+            observed_generation: Some(2),
+            ..accepted()
+        }],
+    };
+    let patch = crate::index::make_patch(&id, status.clone()).unwrap();
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_none());
+
+    // Once the status reflects the current generation, it isn't patched.
+    egress_network.status = Some(status);
+    index.write().apply(egress_network);
+    assert!(updates_rx.try_recv().is_none());
 }
@@ -0,0 +1,141 @@
+use crate::{
+    events::Transitions,
+    index::{
+        accepted, backend_not_found, in_cluster_net_overlap, ratelimit_already_exists,
+        resolved_refs,
+    },
+    resource_id::NamespaceGroupKindName,
+};
+use linkerd_policy_controller_core::{routes::GroupKindName, POLICY_CONTROLLER_NAME};
+use linkerd_policy_controller_k8s_api::{
+    self as k8s, events::EventType, gateway, policy, Resource,
+};
+use tokio::time::{Duration, Instant};
+
+const INTERVAL: Duration = Duration::from_secs(300);
+
+#[test]
+fn route_error_transitions() {
+    let mut transitions = Transitions::new(INTERVAL);
+    let id = resource_id::<gateway::HTTPRoute>("route-foo");
+    let now = Instant::now();
+
+    // A route that can't resolve its backends reports a warning.
+    let events = transitions.observe(&id, &route_status([accepted(), backend_not_found()]), now);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].type_, EventType::Warning);
+    assert_eq!(events[0].reason, "BackendNotFound");
+
+    // Writing the same error conditions again is not a transition.
+    let later = now + Duration::from_secs(1);
+    let events = transitions.observe(&id, &route_status([accepted(), backend_not_found()]), later);
+    assert!(events.is_empty());
+
+    // Once the backends resolve, the route no longer reports errors.
+    let events = transitions.observe(&id, &route_status([accepted(), resolved_refs()]), later);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].type_, EventType::Normal);
+    assert_eq!(events[0].reason, "Resolved");
+
+    // A resource that never reported errors doesn't produce events.
+    let events = transitions.observe(&id, &route_status([accepted(), resolved_refs()]), later);
+    assert!(events.is_empty());
+}
+
+#[test]
+fn ignores_other_controllers_parent_statuses() {
+    let mut transitions = Transitions::new(INTERVAL);
+    let id = resource_id::<gateway::HTTPRoute>("route-foo");
+    let now = Instant::now();
+
+    let status = serde_json::json!({
+        "parents": [
+            {
+                "controllerName": "example.com/gateway-controller",
+                "conditions": [accepted(), backend_not_found()],
+            },
+            {
+                "controllerName": POLICY_CONTROLLER_NAME,
+                "conditions": [accepted(), resolved_refs()],
+            },
+        ],
+    });
+    assert!(transitions.observe(&id, &status, now).is_empty());
+}
+
+#[test]
+fn deleted_resources_are_forgotten() {
+    let mut transitions = Transitions::new(INTERVAL);
+    let id = resource_id::<gateway::HTTPRoute>("route-foo");
+    let now = Instant::now();
+
+    let events = transitions.observe(&id, &route_status([accepted(), backend_not_found()]), now);
+    assert_eq!(events.len(), 1);
+
+    // Once the resource is deleted, a recreated resource that reports no
+    // errors was never reported as failing, so nothing is resolved.
+    transitions.forget(&id);
+    let later = now + INTERVAL;
+    let events = transitions.observe(&id, &route_status([accepted(), resolved_refs()]), later);
+    assert!(events.is_empty());
+}
+
+#[test]
+fn conflicting_resources_report_warnings() {
+    let mut transitions = Transitions::new(INTERVAL);
+    let now = Instant::now();
+
+    let id = resource_id::<policy::HttpLocalRateLimitPolicy>("rl-1");
+    let status = serde_json::json!({ "conditions": [ratelimit_already_exists()] });
+    let events = transitions.observe(&id, &status, now);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].reason, "RateLimitReasonAlreadyExists");
+
+    let id = resource_id::<policy::EgressNetwork>("egress");
+    let status = serde_json::json!({ "conditions": [in_cluster_net_overlap()] });
+    let events = transitions.observe(&id, &status, now);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].reason, "EgressReasonNetworkOverlap");
+}
+
+#[test]
+fn events_are_rate_limited() {
+    let mut transitions = Transitions::new(INTERVAL);
+    let id = resource_id::<gateway::HTTPRoute>("route-foo");
+    let failing = route_status([accepted(), backend_not_found()]);
+    let ok = route_status([accepted(), resolved_refs()]);
+    let now = Instant::now();
+
+    assert_eq!(transitions.observe(&id, &failing, now).len(), 1);
+    assert_eq!(transitions.observe(&id, &ok, now).len(), 1);
+
+    // Flapping within the interval does not publish further events.
+    let soon = now + Duration::from_secs(10);
+    assert!(transitions.observe(&id, &failing, soon).is_empty());
+    assert!(transitions.observe(&id, &ok, soon).is_empty());
+
+    // After the interval elapses, transitions are reported again.
+    let later = now + INTERVAL;
+    assert_eq!(transitions.observe(&id, &failing, later).len(), 1);
+    assert_eq!(transitions.observe(&id, &ok, later).len(), 1);
+}
+
+fn resource_id<R: Resource<DynamicType = ()>>(name: &str) -> NamespaceGroupKindName {
+    NamespaceGroupKindName {
+        namespace: "ns".to_string(),
+        gkn: GroupKindName {
+            group: R::group(&()),
+            kind: R::kind(&()),
+            name: name.to_string().into(),
+        },
+    }
+}
+
+fn route_status(conditions: impl IntoIterator<Item = k8s::Condition>) -> serde_json::Value {
+    serde_json::json!({
+        "parents": [{
+            "controllerName": POLICY_CONTROLLER_NAME,
+            "conditions": conditions.into_iter().collect::<Vec<_>>(),
+        }],
+    })
+}
@@ -1,5 +1,6 @@
 use crate::{
-    index::{accepted, no_matching_target, ratelimit_already_exists, SharedIndex, Update},
+    index::{accepted, no_matching_target, ratelimit_already_exists, SharedIndex},
+    queue::{self, PatchReceiver},
     resource_id::NamespaceGroupKindName,
     tests::{default_cluster_networks, make_server},
     Index, IndexMetrics,
@@ -13,10 +14,7 @@ use linkerd_policy_controller_k8s_api::{
     Resource,
 };
 use std::sync::Arc;
-use tokio::sync::{
-    mpsc::{self, Receiver},
-    watch,
-};
+use tokio::sync::watch;
 
 #[test]
 fn ratelimit_accepted() {
@@ -51,7 +49,7 @@ fn ratelimit_accepted() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(ratelimit_id, update.id);
     assert_eq!(expected_patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -87,7 +85,7 @@ fn ratelimit_not_accepted_no_matching_target() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(ratelimit_id, update.id);
     assert_eq!(expected_patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -123,7 +121,7 @@ fn ratelimit_not_accepted_already_exists() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(rl_1_id, update.id);
     assert_eq!(rl_1_expected_patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // create another rate limit for the same server
     let (rl_2_id, rl_2) = make_ratelimit("rl-2".to_string(), "server-1".to_string());
@@ -142,7 +140,7 @@ fn ratelimit_not_accepted_already_exists() {
 
     let update_1 = updates_rx.try_recv().unwrap();
     let update_2 = updates_rx.try_recv().unwrap();
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // we should receive updates for both rate limits in any order
     if update_1.id == rl_1_id {
@@ -158,14 +156,66 @@ fn ratelimit_not_accepted_already_exists() {
     }
 }
 
-fn make_index_updates_rx() -> (SharedIndex, Receiver<Update>) {
+#[test]
+fn ratelimit_status_observes_generation() {
+    let (index, mut updates_rx) = make_index_updates_rx();
+
+    let server = make_server(
+        "ns",
+        "server-1",
+        8080,
+        vec![("app", "server")],
+        vec![],
+        None,
+    );
+    index.write().apply(server);
+
+    let (ratelimit_id, mut ratelimit) = make_ratelimit("rl-1".to_string(), "server-1".to_string());
+    ratelimit.metadata.generation = Some(1);
+    index.write().apply(ratelimit.clone());
+
+    let status = |generation| linkerd_k8s_api::HttpLocalRateLimitPolicyStatus {
+        conditions: vec![k8s_core_api::Condition {
+            observed_generation: Some(generation),
+            ..accepted()
+        }],
+        target_ref: linkerd_k8s_api::LocalTargetRef {
+            group: Some("policy.linkerd.io".to_string()),
+            kind: "Server".to_string(),
+            name: "server-1".to_string(),
+        },
+    };
+
+    // The status reports the generation it was computed from.
+    let expected_patch = crate::index::make_patch(&ratelimit_id, status(1)).unwrap();
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(ratelimit_id, update.id);
+    assert_eq!(expected_patch, update.patch);
+    assert!(updates_rx.try_recv().is_none());
+
+    // Once the status reflects the current generation, it isn't patched.
+    ratelimit.status = Some(status(1));
+    index.write().apply(ratelimit.clone());
+    assert!(updates_rx.try_recv().is_none());
+
+    // A generation bump alone causes the status to be patched.
+    ratelimit.metadata.generation = Some(2);
+    index.write().apply(ratelimit);
+    let expected_patch = crate::index::make_patch(&ratelimit_id, status(2)).unwrap();
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(ratelimit_id, update.id);
+    assert_eq!(expected_patch, update.patch);
+    assert!(updates_rx.try_recv().is_none());
+}
+
+fn make_index_updates_rx() -> (SharedIndex, PatchReceiver) {
     let hostname = "test";
     let claim = kubert::lease::Claim {
         holder: "test".to_string(),
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, updates_rx) = mpsc::channel(10000);
+    let (updates_tx, updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -0,0 +1,715 @@
+//! Gateway API mesh conformance for the conditions written to route statuses.
+
+use crate::{
+    index::{
+        accepted, invalid_backend_kind, partially_invalid, ref_not_permitted, resolved_refs,
+        unsupported_value, SharedIndex,
+    },
+    queue::{self, PatchReceiver},
+    resource_id::NamespaceGroupKindName,
+    tests::default_cluster_networks,
+    Index, IndexMetrics,
+};
+use chrono::{DateTime, Utc};
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::routes::GroupKindName;
+use linkerd_policy_controller_k8s_api::{self as k8s, gateway, policy, Resource};
+use std::sync::Arc;
+use tokio::sync::watch;
+
+#[test]
+fn http_route_with_unsupported_filter() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::HTTPRoute>("route-foo");
+    let mirror = gateway::HTTPRouteRulesFilters {
+        r#type: gateway::HTTPRouteRulesFiltersType::RequestMirror,
+        request_mirror: Some(gateway::HTTPRouteRulesFiltersRequestMirror {
+            backend_ref: gateway::HTTPRouteRulesFiltersRequestMirrorBackendRef {
+                name: "mirror".to_string(),
+                port: Some(8080),
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let rule = gateway::HTTPRouteRules {
+        filters: Some(vec![mirror]),
+        ..Default::default()
+    };
+    index.write().apply(make_http_route(&id, vec![rule]));
+
+    // The route's only rule can't be served, so the route is not accepted.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            unsupported_value("rule 0: RequestMirror filter is not supported"),
+            resolved_refs(),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn http_route_with_some_invalid_rules() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::HTTPRoute>("route-foo");
+    let valid = gateway::HTTPRouteRules {
+        matches: Some(vec![path_match("/foo")]),
+        ..Default::default()
+    };
+    let rewrite = gateway::HTTPRouteRules {
+        filters: Some(vec![gateway::HTTPRouteRulesFilters {
+            r#type: gateway::HTTPRouteRulesFiltersType::UrlRewrite,
+            url_rewrite: Some(Default::default()),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    index
+        .write()
+        .apply(make_http_route(&id, vec![valid, rewrite]));
+
+    // The valid rule is still served, so the route remains accepted.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            accepted(),
+            resolved_refs(),
+            partially_invalid("rule 1: URLRewrite filter is not supported"),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn linkerd_http_route_with_relative_path() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<policy::HttpRoute>("route-foo");
+    let route = policy::HttpRoute {
+        metadata: route_meta(&id),
+        spec: policy::HttpRouteSpec {
+            parent_refs: Some(vec![service_parent()]),
+            hostnames: None,
+            rules: Some(vec![policy::httproute::HttpRouteRule {
+                matches: Some(vec![path_match("foo")]),
+                filters: None,
+                backend_refs: None,
+                timeouts: None,
+            }]),
+        },
+        status: None,
+    };
+    index.write().apply(route);
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            unsupported_value(
+                "rule 0: HttpPathMatch paths must be absolute (begin with `/`); \"foo\" is not an absolute path"
+            ),
+            resolved_refs(),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn http_route_with_invalid_rule_and_backend() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::HTTPRoute>("route-foo");
+    let valid = gateway::HTTPRouteRules {
+        backend_refs: Some(vec![gateway::HTTPRouteRulesBackendRefs {
+            group: Some(policy::Server::group(&()).to_string()),
+            kind: Some(policy::Server::kind(&()).to_string()),
+            name: "srv".to_string(),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    let extension = gateway::HTTPRouteRules {
+        filters: Some(vec![gateway::HTTPRouteRulesFilters {
+            r#type: gateway::HTTPRouteRulesFiltersType::ExtensionRef,
+            extension_ref: Some(Default::default()),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    index
+        .write()
+        .apply(make_http_route(&id, vec![valid, extension]));
+
+    // Rule and backend reference errors are reported independently.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            accepted(),
+            invalid_backend_kind(
+                "unsupported backend kind policy.linkerd.io/Server; supported kinds: \
+                 core/Service, multicluster.x-k8s.io/ServiceImport, policy.linkerd.io/EgressNetwork"
+            ),
+            partially_invalid("rule 1: ExtensionRef filter is not supported"),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn http_route_with_invalid_matches() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::HTTPRoute>("route-foo");
+    let valid = gateway::HTTPRouteRules {
+        matches: Some(vec![path_match("/foo")]),
+        ..Default::default()
+    };
+    let regex = gateway::HTTPRouteRules {
+        matches: Some(vec![gateway::HTTPRouteRulesMatches {
+            path: Some(gateway::HTTPRouteRulesMatchesPath {
+                value: Some("/(".to_string()),
+                r#type: Some(gateway::HTTPRouteRulesMatchesPathType::RegularExpression),
+            }),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    let header = gateway::HTTPRouteRules {
+        matches: Some(vec![gateway::HTTPRouteRulesMatches {
+            headers: Some(vec![gateway::HTTPRouteRulesMatchesHeaders {
+                name: "bad header".to_string(),
+                value: "x".to_string(),
+                r#type: None,
+            }]),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    index
+        .write()
+        .apply(make_http_route(&id, vec![valid, regex, header]));
+
+    // The rules that the index can't convert are reported as invalid.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    let conditions = parent_conditions(&update.patch);
+    assert_eq!(conditions[0]["type"], "Accepted");
+    assert_eq!(conditions[0]["status"], "True");
+    assert_eq!(conditions[2]["type"], "PartiallyInvalid");
+    let message = conditions[2]["message"].as_str().unwrap();
+    assert!(message.starts_with("rule 1: "), "{message}");
+    assert!(
+        message.ends_with("; rule 2: invalid HTTP header name"),
+        "{message}"
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn linkerd_http_route_with_invalid_filter() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<policy::HttpRoute>("route-foo");
+    let filter = policy::httproute::HttpRouteFilter::RequestHeaderModifier {
+        request_header_modifier: gateway::HTTPRouteRulesFiltersRequestHeaderModifier {
+            set: Some(vec![
+                gateway::HTTPRouteRulesFiltersRequestHeaderModifierSet {
+                    name: "bad header".to_string(),
+                    value: "x".to_string(),
+                },
+            ]),
+            ..Default::default()
+        },
+    };
+    let route = policy::HttpRoute {
+        metadata: route_meta(&id),
+        spec: policy::HttpRouteSpec {
+            parent_refs: Some(vec![service_parent()]),
+            hostnames: None,
+            rules: Some(vec![policy::httproute::HttpRouteRule {
+                matches: None,
+                filters: Some(vec![filter]),
+                backend_refs: None,
+                timeouts: None,
+            }]),
+        },
+        status: None,
+    };
+    index.write().apply(route);
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            unsupported_value("rule 0: invalid HTTP header name"),
+            resolved_refs(),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn http_route_with_unsupported_backend_filter() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::HTTPRoute>("route-foo");
+    let rule = gateway::HTTPRouteRules {
+        backend_refs: Some(vec![gateway::HTTPRouteRulesBackendRefs {
+            name: "svc".to_string(),
+            port: Some(8080),
+            filters: Some(vec![gateway::HTTPRouteRulesBackendRefsFilters {
+                r#type: gateway::HTTPRouteRulesBackendRefsFiltersType::UrlRewrite,
+                url_rewrite: Some(Default::default()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    index.write().apply(make_http_route(&id, vec![rule]));
+
+    // The rule is still served, but requests to the backend fail, so the
+    // route remains accepted and is marked partially invalid.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            accepted(),
+            resolved_refs(),
+            partially_invalid(
+                "rule 0: unsupported backend filter: URLRewrite filter is not supported"
+            ),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn http_route_with_cross_namespace_backend() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+    index.write().apply(super::make_service("ns-1", "backend"));
+
+    // The ReferenceGrant API is served and its watch has synced.
+    index.write().set_reference_grants_served(true);
+    <Index as kubert::index::IndexNamespacedResource<gateway::ReferenceGrant>>::reset(
+        &mut index.write(),
+        vec![],
+        Default::default(),
+    );
+
+    let id = route_id::<gateway::HTTPRoute>("route-foo");
+    let rule = gateway::HTTPRouteRules {
+        backend_refs: Some(vec![gateway::HTTPRouteRulesBackendRefs {
+            namespace: Some("ns-1".to_string()),
+            name: "backend".to_string(),
+            port: Some(8080),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    index.write().apply(make_http_route(&id, vec![rule]));
+
+    // Without a ReferenceGrant in ns-1, the backend may not be referenced.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            accepted(),
+            ref_not_permitted("no ReferenceGrant permits a reference to Service ns-1/backend"),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+
+    let grant = gateway::ReferenceGrant {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns-1".to_string()),
+            name: Some("grant".to_string()),
+            ..Default::default()
+        },
+        spec: gateway::ReferenceGrantSpec {
+            from: vec![gateway::ReferenceGrantFrom {
+                group: gateway::HTTPRoute::group(&()).to_string(),
+                kind: gateway::HTTPRoute::kind(&()).to_string(),
+                namespace: "ns-0".to_string(),
+            }],
+            to: vec![gateway::ReferenceGrantTo {
+                group: "".to_string(),
+                kind: "Service".to_string(),
+                name: Some("backend".to_string()),
+            }],
+        },
+    };
+    index.write().apply(grant);
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([accepted(), resolved_refs()]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn http_route_with_cross_namespace_backend_without_reference_grant_api() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+    index.write().apply(super::make_service("ns-1", "backend"));
+
+    let id = route_id::<gateway::HTTPRoute>("route-foo");
+    let rule = gateway::HTTPRouteRules {
+        backend_refs: Some(vec![gateway::HTTPRouteRulesBackendRefs {
+            namespace: Some("ns-1".to_string()),
+            name: "backend".to_string(),
+            port: Some(8080),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    index.write().apply(make_http_route(&id, vec![rule]));
+
+    // Cross-namespace references are permitted when ReferenceGrants are not
+    // served.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([accepted(), resolved_refs()]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn grpc_route_with_unsupported_method_match() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::GRPCRoute>("route-foo");
+    let regex = gateway::GRPCRouteRules {
+        matches: Some(vec![gateway::GRPCRouteRulesMatches {
+            method: Some(gateway::GRPCRouteRulesMatchesMethod {
+                service: Some("foo.*".to_string()),
+                method: None,
+                r#type: Some(gateway::GRPCRouteRulesMatchesMethodType::RegularExpression),
+            }),
+            headers: None,
+        }]),
+        ..Default::default()
+    };
+    let mut route = make_grpc_route(&id, vec![regex]);
+    index.write().apply(route.clone());
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            unsupported_value("rule 0: unsupported GRPCRoute method match type: RegularExpression"),
+            resolved_refs(),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+
+    // Adding a rule that can be served makes the route accepted.
+    route.spec.rules.as_mut().unwrap().push(Default::default());
+    index.write().apply(route);
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            accepted(),
+            resolved_refs(),
+            partially_invalid("rule 0: unsupported GRPCRoute method match type: RegularExpression"),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn grpc_route_with_unsupported_filter() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::GRPCRoute>("route-foo");
+    let rule = gateway::GRPCRouteRules {
+        filters: Some(vec![gateway::GRPCRouteRulesFilters {
+            r#type: gateway::GRPCRouteRulesFiltersType::RequestMirror,
+            request_mirror: Some(Default::default()),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    index.write().apply(make_grpc_route(&id, vec![rule]));
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            unsupported_value("rule 0: RequestMirror filter is not supported"),
+            resolved_refs(),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn tcp_route_is_accepted() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::TCPRoute>("route-foo");
+    index.write().apply(make_tcp_route(&id, 1));
+
+    // TCPRoute rules have no matches or filters, so a single rule is always
+    // valid.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([accepted(), resolved_refs()]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn tcp_route_with_multiple_rules() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::TCPRoute>("route-foo");
+    index.write().apply(make_tcp_route(&id, 2));
+
+    // Routes with more than one rule are not served at all, so none of the
+    // rules are valid and the route is not accepted.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            unsupported_value(
+                "rule 0: TCPRoute needs to have one rule; rule 1: TCPRoute needs to have one rule"
+            ),
+            resolved_refs(),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn tls_route_is_accepted() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::TLSRoute>("route-foo");
+    index.write().apply(make_tls_route(&id, 1));
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([accepted(), resolved_refs()]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn tls_route_with_multiple_rules() {
+    let (index, mut updates_rx) = make_index();
+    index.write().apply(super::make_service("ns-0", "svc"));
+
+    let id = route_id::<gateway::TLSRoute>("route-foo");
+    index.write().apply(make_tls_route(&id, 3));
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(
+        parent_conditions(&update.patch),
+        &conditions([
+            unsupported_value(
+                "rule 0: TLSRoute needs to have one rule; \
+                 rule 1: TLSRoute needs to have one rule; \
+                 rule 2: TLSRoute needs to have one rule"
+            ),
+            resolved_refs(),
+        ]),
+    );
+    assert!(updates_rx.try_recv().is_none());
+}
+
+fn make_index() -> (SharedIndex, PatchReceiver) {
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, updates_rx) = queue::channel();
+    let index = Index::shared(
+        "test",
+        claims_rx,
+        updates_tx,
+        IndexMetrics::register(&mut Default::default()),
+        default_cluster_networks(),
+    );
+    (index, updates_rx)
+}
+
+fn route_id<R: Resource<DynamicType = ()>>(name: &str) -> NamespaceGroupKindName {
+    NamespaceGroupKindName {
+        namespace: "ns-0".to_string(),
+        gkn: GroupKindName {
+            group: R::group(&()),
+            kind: R::kind(&()),
+            name: name.to_string().into(),
+        },
+    }
+}
+
+fn route_meta(id: &NamespaceGroupKindName) -> k8s::ObjectMeta {
+    k8s::ObjectMeta {
+        name: Some(id.gkn.name.to_string()),
+        namespace: Some(id.namespace.clone()),
+        creation_timestamp: Some(k8s::Time(Utc::now())),
+        ..Default::default()
+    }
+}
+
+fn service_parent() -> gateway::HTTPRouteParentRefs {
+    gateway::HTTPRouteParentRefs {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: Some("ns-0".to_string()),
+        name: "svc".to_string(),
+        section_name: None,
+        port: Some(8080),
+    }
+}
+
+fn path_match(path: &str) -> gateway::HTTPRouteRulesMatches {
+    gateway::HTTPRouteRulesMatches {
+        path: Some(gateway::HTTPRouteRulesMatchesPath {
+            value: Some(path.to_string()),
+            r#type: Some(gateway::HTTPRouteRulesMatchesPathType::PathPrefix),
+        }),
+        ..Default::default()
+    }
+}
+
+fn make_http_route(
+    id: &NamespaceGroupKindName,
+    rules: Vec<gateway::HTTPRouteRules>,
+) -> gateway::HTTPRoute {
+    gateway::HTTPRoute {
+        metadata: route_meta(id),
+        spec: gateway::HTTPRouteSpec {
+            parent_refs: Some(vec![service_parent()]),
+            hostnames: None,
+            rules: Some(rules),
+        },
+        status: None,
+    }
+}
+
+fn make_grpc_route(
+    id: &NamespaceGroupKindName,
+    rules: Vec<gateway::GRPCRouteRules>,
+) -> gateway::GRPCRoute {
+    gateway::GRPCRoute {
+        metadata: route_meta(id),
+        spec: gateway::GRPCRouteSpec {
+            parent_refs: Some(vec![gateway::GRPCRouteParentRefs {
+                group: Some("core".to_string()),
+                kind: Some("Service".to_string()),
+                namespace: Some("ns-0".to_string()),
+                name: "svc".to_string(),
+                section_name: None,
+                port: Some(8080),
+            }]),
+            hostnames: None,
+            rules: Some(rules),
+        },
+        status: None,
+    }
+}
+
+fn make_tcp_route(id: &NamespaceGroupKindName, rules: usize) -> gateway::TCPRoute {
+    gateway::TCPRoute {
+        metadata: route_meta(id),
+        spec: gateway::TCPRouteSpec {
+            parent_refs: Some(vec![gateway::TCPRouteParentRefs {
+                group: Some("core".to_string()),
+                kind: Some("Service".to_string()),
+                namespace: Some("ns-0".to_string()),
+                name: "svc".to_string(),
+                section_name: None,
+                port: Some(8080),
+            }]),
+            rules: (0..rules)
+                .map(|_| gateway::TCPRouteRules {
+                    name: None,
+                    backend_refs: Some(vec![]),
+                })
+                .collect(),
+        },
+        status: None,
+    }
+}
+
+fn make_tls_route(id: &NamespaceGroupKindName, rules: usize) -> gateway::TLSRoute {
+    gateway::TLSRoute {
+        metadata: route_meta(id),
+        spec: gateway::TLSRouteSpec {
+            parent_refs: Some(vec![gateway::TLSRouteParentRefs {
+                group: Some("core".to_string()),
+                kind: Some("Service".to_string()),
+                namespace: Some("ns-0".to_string()),
+                name: "svc".to_string(),
+                section_name: None,
+                port: Some(8080),
+            }]),
+            hostnames: None,
+            rules: (0..rules)
+                .map(|_| gateway::TLSRouteRules {
+                    name: None,
+                    backend_refs: Some(vec![]),
+                })
+                .collect(),
+        },
+        status: None,
+    }
+}
+
+fn conditions(conditions: impl IntoIterator<Item = k8s::Condition>) -> serde_json::Value {
+    serde_json::to_value(conditions.into_iter().collect::<Vec<_>>()).unwrap()
+}
+
+/// Returns the conditions patched onto the route's first parent status.
+fn parent_conditions(patch: &k8s::Patch<serde_json::Value>) -> &serde_json::Value {
+    match patch {
+        k8s::Patch::Merge(patch) => &patch["status"]["parents"][0]["conditions"],
+        patch => panic!("unexpected patch: {patch:?}"),
+    }
+}
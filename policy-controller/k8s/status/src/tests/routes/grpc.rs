@@ -3,6 +3,7 @@ use crate::{
         accepted, backend_not_found, invalid_backend_kind, no_matching_parent, resolved_refs,
         route_conflicted, POLICY_API_GROUP,
     },
+    queue,
     resource_id::NamespaceGroupKindName,
     tests::{default_cluster_networks, make_server},
     Index, IndexMetrics,
@@ -12,7 +13,7 @@ use kubert::index::IndexNamespacedResource;
 use linkerd_policy_controller_core::{routes::GroupKindName, POLICY_CONTROLLER_NAME};
 use linkerd_policy_controller_k8s_api::{self as k8s, gateway, policy, Resource, ResourceExt};
 use std::sync::Arc;
-use tokio::sync::{mpsc, watch};
+use tokio::sync::watch;
 
 pub(crate) fn make_parent_status(
     namespace: impl ToString,
@@ -51,7 +52,7 @@ fn route_with_valid_service_backends() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -139,7 +140,7 @@ fn route_with_valid_service_backends() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -150,7 +151,7 @@ fn route_with_valid_egress_network_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -219,7 +220,7 @@ fn route_with_valid_egress_network_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -230,7 +231,7 @@ fn route_with_invalid_service_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -314,7 +315,7 @@ fn route_with_invalid_service_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -325,7 +326,7 @@ fn route_with_egress_network_backend_different_from_parent() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -399,7 +400,7 @@ fn route_with_egress_network_backend_different_from_parent() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -410,7 +411,7 @@ fn route_with_egress_network_backend_and_service_parent() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -484,7 +485,7 @@ fn route_with_egress_network_backend_and_service_parent() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -495,7 +496,7 @@ fn route_with_egress_network_parent_and_service_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -567,7 +568,7 @@ fn route_with_egress_network_parent_and_service_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -578,7 +579,7 @@ fn route_accepted_after_server_create() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -652,7 +653,7 @@ fn route_accepted_after_server_create() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -663,7 +664,7 @@ fn route_accepted_after_egress_network_create() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -750,7 +751,7 @@ fn route_accepted_after_egress_network_create() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -761,7 +762,7 @@ fn route_rejected_after_server_delete() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -781,7 +782,7 @@ fn route_rejected_after_server_delete() {
     index.write().apply(server);
 
     // There should be no update since there are no GRPCRoutes yet.
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // Create the route id and route
     let id = NamespaceGroupKindName {
@@ -841,7 +842,7 @@ fn route_rejected_after_server_delete() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 }
 
 #[test]
@@ -852,7 +853,7 @@ fn route_rejected_after_egress_network_delete() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -865,7 +866,7 @@ fn route_rejected_after_egress_network_delete() {
     index.write().apply(egress);
 
     // There should be no update since there are no TLSRoutes yet.
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // Create the route id and route
     let id = NamespaceGroupKindName {
@@ -950,7 +951,7 @@ fn route_rejected_after_egress_network_delete() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 }
 
 #[test]
@@ -961,7 +962,7 @@ fn service_route_type_conflict() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1095,7 +1096,7 @@ fn service_route_type_conflict() {
     }
 
     // No more updates.
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -1106,7 +1107,7 @@ fn egress_network_route_type_conflict() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1240,7 +1241,7 @@ fn egress_network_route_type_conflict() {
     }
 
     // No more updates.
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 fn make_route(
@@ -3,6 +3,7 @@ use crate::{
         accepted, backend_not_found, invalid_backend_kind, no_matching_parent, resolved_refs,
         POLICY_API_GROUP,
     },
+    queue,
     resource_id::NamespaceGroupKindName,
     tests::{default_cluster_networks, make_server},
     Index, IndexMetrics,
@@ -12,7 +13,7 @@ use kubert::index::IndexNamespacedResource;
 use linkerd_policy_controller_core::{routes::GroupKindName, POLICY_CONTROLLER_NAME};
 use linkerd_policy_controller_k8s_api::{self as k8s, gateway, policy, Resource, ResourceExt};
 use std::sync::Arc;
-use tokio::sync::{mpsc, watch};
+use tokio::sync::watch;
 
 pub(crate) fn make_parent_status(
     namespace: impl ToString,
@@ -51,7 +52,7 @@ fn linkerd_route_with_no_backends() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -109,7 +110,162 @@ fn linkerd_route_with_no_backends() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
+}
+
+#[test]
+fn linkerd_route_status_observes_generation() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = queue::channel();
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        IndexMetrics::register(&mut Default::default()),
+        default_cluster_networks(),
+    );
+
+    // Apply the parent service
+    let parent = super::make_service("ns-0", "svc");
+    index.write().apply(parent.clone());
+
+    // Apply the route.
+    let id = NamespaceGroupKindName {
+        namespace: parent.namespace().as_deref().unwrap().to_string(),
+        gkn: GroupKindName {
+            group: policy::HttpRoute::group(&()),
+            kind: policy::HttpRoute::kind(&()),
+            name: "route-foo".into(),
+        },
+    };
+    let parent = gateway::HTTPRouteParentRefs {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: parent.namespace(),
+        name: parent.name_unchecked(),
+        section_name: None,
+        port: Some(8080),
+    };
+
+    let mut route = make_linkerd_route(&id, parent.clone(), None);
+    route.metadata.generation = Some(1);
+    index.write().apply(route.clone());
+
+    let status = |generation| {
+        let observed = |condition| k8s::Condition {
+            observed_generation: Some(generation),
+            ..condition
+        };
+        gateway::HTTPRouteStatus {
+            parents: vec![gateway::HTTPRouteStatusParents {
+                parent_ref: gateway::HTTPRouteStatusParentsParentRef {
+                    group: parent.group.clone(),
+                    kind: parent.kind.clone(),
+                    namespace: parent.namespace.clone(),
+                    name: parent.name.clone(),
+                    section_name: parent.section_name.clone(),
+                    port: parent.port,
+                },
+                controller_name: POLICY_CONTROLLER_NAME.to_string(),
+                conditions: Some(vec![observed(accepted()), observed(resolved_refs())]),
+            }],
+        }
+    };
+
+    // The status reports the generation it was computed from.
+    let patch = crate::index::make_patch(&id, status(1)).unwrap();
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_none());
+
+    // Once the status reflects the current generation, it isn't patched.
+    route.status = Some(status(1));
+    index.write().apply(route.clone());
+    assert!(updates_rx.try_recv().is_none());
+
+    // A generation bump alone causes the status to be patched.
+    route.metadata.generation = Some(2);
+    index.write().apply(route);
+    let patch = crate::index::make_patch(&id, status(2)).unwrap();
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn linkerd_route_with_headless_parent() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = queue::channel();
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        IndexMetrics::register(&mut Default::default()),
+        default_cluster_networks(),
+    );
+
+    // Apply a headless parent service
+    let mut parent = super::make_service("ns-0", "svc");
+    let spec = parent.spec.as_mut().unwrap();
+    spec.cluster_ip = Some("None".to_string());
+    spec.cluster_ips = Some(vec!["None".to_string()]);
+    index.write().apply(parent.clone());
+
+    // Apply the route.
+    let id = NamespaceGroupKindName {
+        namespace: parent.namespace().as_deref().unwrap().to_string(),
+        gkn: GroupKindName {
+            group: policy::HttpRoute::group(&()),
+            kind: policy::HttpRoute::kind(&()),
+            name: "route-foo".into(),
+        },
+    };
+    let parent = gateway::HTTPRouteParentRefs {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: parent.namespace(),
+        name: parent.name_unchecked(),
+        section_name: None,
+        port: Some(8080),
+    };
+
+    let route = make_linkerd_route(&id, parent.clone(), None);
+    index.write().apply(route);
+
+    // Headless services are accepted as parents.
+    let parent_status = gateway::HTTPRouteStatusParents {
+        parent_ref: gateway::HTTPRouteStatusParentsParentRef {
+            group: parent.group,
+            kind: parent.kind,
+            namespace: parent.namespace,
+            name: parent.name,
+            section_name: parent.section_name,
+            port: parent.port,
+        },
+        controller_name: POLICY_CONTROLLER_NAME.to_string(),
+        conditions: Some(vec![accepted(), resolved_refs()]),
+    };
+    let status = gateway::HTTPRouteStatus {
+        parents: vec![parent_status],
+    };
+    let patch = crate::index::make_patch(&id, status).unwrap();
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -120,7 +276,7 @@ fn gateway_route_with_no_backends() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -177,7 +333,7 @@ fn gateway_route_with_no_backends() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -188,7 +344,7 @@ fn linkerd_route_with_valid_service_backends() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -276,7 +432,112 @@ fn linkerd_route_with_valid_service_backends() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
+}
+
+#[test]
+fn linkerd_route_with_service_import_backend() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = queue::channel();
+    let index = Index::shared(
+        hostname,
+        claims_rx,
+        updates_tx,
+        IndexMetrics::register(&mut Default::default()),
+        default_cluster_networks(),
+    );
+
+    // Apply the parent service
+    let parent = super::make_service("ns-0", "svc");
+    index.write().apply(parent.clone());
+
+    // Apply the route.
+    let id = NamespaceGroupKindName {
+        namespace: parent.namespace().as_deref().unwrap().to_string(),
+        gkn: GroupKindName {
+            group: policy::HttpRoute::group(&()),
+            kind: policy::HttpRoute::kind(&()),
+            name: "route-foo".into(),
+        },
+    };
+    let parent = gateway::HTTPRouteParentRefs {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: parent.namespace(),
+        name: parent.name_unchecked(),
+        section_name: None,
+        port: Some(8080),
+    };
+    let route = make_linkerd_route(
+        &id,
+        parent.clone(),
+        Some(vec![gateway::HTTPRouteRulesBackendRefs {
+            weight: None,
+            group: Some("multicluster.x-k8s.io".to_string()),
+            kind: Some("ServiceImport".to_string()),
+            name: "backend".to_string(),
+            namespace: Some("ns-0".to_string()),
+            port: Some(8080),
+            filters: None,
+        }]),
+    );
+    index.write().apply(route);
+
+    let make_update = |backend_condition| {
+        let parent_status = gateway::HTTPRouteStatusParents {
+            parent_ref: gateway::HTTPRouteStatusParentsParentRef {
+                group: parent.group.clone(),
+                kind: parent.kind.clone(),
+                namespace: parent.namespace.clone(),
+                name: parent.name.clone(),
+                section_name: parent.section_name.clone(),
+                port: parent.port,
+            },
+            controller_name: POLICY_CONTROLLER_NAME.to_string(),
+            conditions: Some(vec![accepted(), backend_condition]),
+        };
+        let status = gateway::HTTPRouteStatus {
+            parents: vec![parent_status],
+        };
+        crate::index::make_patch(&id, status).unwrap()
+    };
+
+    // The ServiceImport does not exist yet.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(make_update(backend_not_found()), update.patch);
+    assert!(updates_rx.try_recv().is_none());
+
+    // Apply the backend ServiceImport.
+    let service_import = k8s::multicluster::ServiceImport {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some("backend".to_string()),
+            ..Default::default()
+        },
+        spec: k8s::multicluster::ServiceImportSpec {
+            ports: vec![k8s::multicluster::ServicePort {
+                name: None,
+                protocol: None,
+                app_protocol: None,
+                port: 8080,
+            }],
+            ips: None,
+            type_: k8s::multicluster::ServiceImportType::ClusterSetIP,
+        },
+        status: None,
+    };
+    index.write().apply(service_import);
+
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(make_update(resolved_refs()), update.patch);
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -287,7 +548,7 @@ fn linkerd_route_with_valid_egress_networks_backends() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index: Arc<parking_lot::lock_api::RwLock<parking_lot::RawRwLock, Index>> = Index::shared(
         hostname,
         claims_rx,
@@ -356,7 +617,7 @@ fn linkerd_route_with_valid_egress_networks_backends() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -367,7 +628,7 @@ fn gateway_route_with_valid_service_backends() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -456,7 +717,7 @@ fn gateway_route_with_valid_service_backends() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -467,7 +728,7 @@ fn gateway_route_with_valid_egress_networks_backends() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -536,7 +797,7 @@ fn gateway_route_with_valid_egress_networks_backends() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -547,7 +808,7 @@ fn linkerd_route_with_invalid_service_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -631,7 +892,7 @@ fn linkerd_route_with_invalid_service_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -642,7 +903,7 @@ fn linkerd_route_with_egress_network_backend_different_from_parent() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -716,7 +977,7 @@ fn linkerd_route_with_egress_network_backend_different_from_parent() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -727,7 +988,7 @@ fn linkerd_route_with_egress_network_backend_and_service_parent() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -802,7 +1063,7 @@ fn linkerd_route_with_egress_network_backend_and_service_parent() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -813,7 +1074,7 @@ fn linkerd_route_with_egress_network_parent_and_service_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -886,7 +1147,7 @@ fn linkerd_route_with_egress_network_parent_and_service_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -897,7 +1158,7 @@ fn gateway_route_with_invalid_service_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -981,7 +1242,7 @@ fn gateway_route_with_invalid_service_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -992,7 +1253,7 @@ fn gateway_route_with_egress_network_backend_different_from_parent() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1066,7 +1327,7 @@ fn gateway_route_with_egress_network_backend_different_from_parent() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -1077,7 +1338,7 @@ fn gateway_route_with_egress_network_backend_and_service_parent() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1152,7 +1413,7 @@ fn gateway_route_with_egress_network_backend_and_service_parent() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -1163,7 +1424,7 @@ fn gateway_route_with_egress_network_parent_and_service_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1236,7 +1497,7 @@ fn gateway_route_with_egress_network_parent_and_service_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -1247,7 +1508,7 @@ fn linkerd_route_accepted_after_server_create() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1321,7 +1582,7 @@ fn linkerd_route_accepted_after_server_create() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -1332,7 +1593,7 @@ fn linkerd_route_accepted_after_egress_network_create() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1419,7 +1680,7 @@ fn linkerd_route_accepted_after_egress_network_create() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -1430,7 +1691,7 @@ fn gateway_route_accepted_after_server_create() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1504,7 +1765,7 @@ fn gateway_route_accepted_after_server_create() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -1515,7 +1776,7 @@ fn gateway_route_accepted_after_egress_network_create() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1602,7 +1863,7 @@ fn gateway_route_accepted_after_egress_network_create() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -1613,7 +1874,7 @@ fn linkerd_route_rejected_after_server_delete() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1633,7 +1894,7 @@ fn linkerd_route_rejected_after_server_delete() {
     index.write().apply(server);
 
     // There should be no update since there are no HTTPRoutes yet.
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // Create the route id and route
     let id = NamespaceGroupKindName {
@@ -1693,7 +1954,7 @@ fn linkerd_route_rejected_after_server_delete() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 }
 
 #[test]
@@ -1704,7 +1965,7 @@ fn linkerd_route_rejected_after_egress_network_delete() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1717,7 +1978,7 @@ fn linkerd_route_rejected_after_egress_network_delete() {
     index.write().apply(egress);
 
     // There should be no update since there are no HTTPRoutes yet.
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // Create the route id and route
     let id = NamespaceGroupKindName {
@@ -1802,7 +2063,7 @@ fn linkerd_route_rejected_after_egress_network_delete() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 }
 
 #[test]
@@ -1813,7 +2074,7 @@ fn gateway_route_rejected_after_server_delete() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1833,7 +2094,7 @@ fn gateway_route_rejected_after_server_delete() {
     index.write().apply(server);
 
     // There should be no update since there are no HTTPRoutes yet.
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // Create the route id and route
     let id = NamespaceGroupKindName {
@@ -1893,7 +2154,7 @@ fn gateway_route_rejected_after_server_delete() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 }
 
 #[test]
@@ -1904,7 +2165,7 @@ fn gateway_route_rejected_after_egress_network_delete() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -1917,7 +2178,7 @@ fn gateway_route_rejected_after_egress_network_delete() {
     index.write().apply(egress);
 
     // There should be no update since there are no HTTPRoutes yet.
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // Create the route id and route
     let id = NamespaceGroupKindName {
@@ -2002,7 +2263,7 @@ fn gateway_route_rejected_after_egress_network_delete() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 }
 
 fn make_linkerd_route(
@@ -3,6 +3,7 @@ use crate::{
         accepted, backend_not_found, invalid_backend_kind, no_matching_parent, resolved_refs,
         POLICY_API_GROUP,
     },
+    queue,
     resource_id::NamespaceGroupKindName,
     tests::{default_cluster_networks, make_server},
     Index, IndexMetrics,
@@ -12,7 +13,7 @@ use kubert::index::IndexNamespacedResource;
 use linkerd_policy_controller_core::{routes::GroupKindName, POLICY_CONTROLLER_NAME};
 use linkerd_policy_controller_k8s_api::{self as k8s, gateway, policy, Resource, ResourceExt};
 use std::{sync::Arc, vec};
-use tokio::sync::{mpsc, watch};
+use tokio::sync::watch;
 
 pub(crate) fn make_parent_status(
     namespace: impl ToString,
@@ -51,7 +52,7 @@ fn route_with_valid_service_backends() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -137,7 +138,7 @@ fn route_with_valid_service_backends() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -148,7 +149,7 @@ fn route_with_valid_egress_network_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -216,7 +217,7 @@ fn route_with_valid_egress_network_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -227,7 +228,7 @@ fn route_with_invalid_service_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -309,7 +310,7 @@ fn route_with_invalid_service_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -320,7 +321,7 @@ fn route_with_egress_network_backend_different_from_parent() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -393,7 +394,7 @@ fn route_with_egress_network_backend_different_from_parent() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -404,7 +405,7 @@ fn route_with_egress_network_backend_and_service_parent() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -477,7 +478,7 @@ fn route_with_egress_network_backend_and_service_parent() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -488,7 +489,7 @@ fn route_with_egress_network_parent_and_service_backend() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -559,7 +560,7 @@ fn route_with_egress_network_parent_and_service_backend() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -570,7 +571,7 @@ fn route_accepted_after_server_create() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -644,7 +645,7 @@ fn route_accepted_after_server_create() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -655,7 +656,7 @@ fn route_accepted_after_egress_network_create() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -742,7 +743,7 @@ fn route_accepted_after_egress_network_create() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err())
+    assert!(updates_rx.try_recv().is_none())
 }
 
 #[test]
@@ -753,7 +754,7 @@ fn route_rejected_after_server_delete() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -773,7 +774,7 @@ fn route_rejected_after_server_delete() {
     index.write().apply(server);
 
     // There should be no update since there are no TLSRoutes yet.
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // Create the route id and route
     let id = NamespaceGroupKindName {
@@ -833,7 +834,7 @@ fn route_rejected_after_server_delete() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 }
 
 #[test]
@@ -844,7 +845,7 @@ fn route_rejected_after_egress_network_delete() {
         expiry: DateTime::<Utc>::MAX_UTC,
     };
     let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
-    let (updates_tx, mut updates_rx) = mpsc::channel(10000);
+    let (updates_tx, mut updates_rx) = queue::channel();
     let index = Index::shared(
         hostname,
         claims_rx,
@@ -857,7 +858,7 @@ fn route_rejected_after_egress_network_delete() {
     index.write().apply(egress);
 
     // There should be no update since there are no TCPRoutes yet.
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 
     // Create the route id and route
     let id = NamespaceGroupKindName {
@@ -942,7 +943,7 @@ fn route_rejected_after_egress_network_delete() {
     let update = updates_rx.try_recv().unwrap();
     assert_eq!(id, update.id);
     assert_eq!(patch, update.patch);
-    assert!(updates_rx.try_recv().is_err());
+    assert!(updates_rx.try_recv().is_none());
 }
 
 fn make_route(
@@ -0,0 +1,107 @@
+use crate::{
+    queue,
+    shard::{shard, Shards},
+    tests::default_cluster_networks,
+    Index, IndexMetrics,
+};
+use chrono::{DateTime, Utc};
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_k8s_api::{self as k8s_core_api, policy as linkerd_k8s_api};
+use std::sync::Arc;
+use tokio::sync::watch;
+
+#[test]
+fn namespaces_are_assigned_to_stable_shards() {
+    // Every controller must agree on the assignment, regardless of process.
+    assert_eq!(shard("default", 1), 0);
+    assert_eq!(shard("default", 4), shard("default", 4));
+    let shards = (0..32)
+        .map(|i| shard(&format!("ns-{i}"), 4))
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(shards.len(), 4, "namespaces should be spread across shards");
+}
+
+#[test]
+fn reconciles_only_owned_shards() {
+    let hostname = "test";
+    let claim = |holder: &str| {
+        let (tx, rx) = watch::channel(Arc::new(kubert::lease::Claim {
+            holder: holder.to_string(),
+            expiry: DateTime::<Utc>::MAX_UTC,
+        }));
+        (tx, rx)
+    };
+    let (_owned_tx, owned_rx) = claim(hostname);
+    let (_other_tx, other_rx) = claim("other");
+    let (updates_tx, mut updates_rx) = queue::channel();
+    let index = Index::shared(
+        hostname,
+        Shards::new(vec![owned_rx, other_rx]),
+        updates_tx,
+        IndexMetrics::register(&mut Default::default()),
+        default_cluster_networks(),
+    );
+
+    let namespace_in = |s: usize| {
+        (0..)
+            .map(|i| format!("ns-{i}"))
+            .find(|ns| shard(ns, 2) == s)
+            .unwrap()
+    };
+    let owned_ns = namespace_in(0);
+    let other_ns = namespace_in(1);
+    for ns in [&owned_ns, &other_ns] {
+        index.write().apply(linkerd_k8s_api::EgressNetwork {
+            metadata: k8s_core_api::ObjectMeta {
+                name: Some("egress".to_string()),
+                namespace: Some(ns.clone()),
+                ..Default::default()
+            },
+            spec: linkerd_k8s_api::EgressNetworkSpec {
+                networks: None,
+                traffic_policy: linkerd_k8s_api::TrafficPolicy::Allow,
+            },
+            status: None,
+        });
+    }
+
+    // Only resources in owned shards are reconciled.
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(update.id.namespace, owned_ns);
+    assert!(updates_rx.try_recv().is_none());
+
+    index.read().reconcile_if_leader();
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(update.id.namespace, owned_ns);
+    assert!(updates_rx.try_recv().is_none());
+}
+
+#[test]
+fn reconciles_nothing_without_owned_shards() {
+    let claim = kubert::lease::Claim {
+        holder: "other".to_string(),
+        expiry: DateTime::<Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = queue::channel();
+    let index = Index::shared(
+        "test",
+        claims_rx,
+        updates_tx,
+        IndexMetrics::register(&mut Default::default()),
+        default_cluster_networks(),
+    );
+    index.write().apply(linkerd_k8s_api::EgressNetwork {
+        metadata: k8s_core_api::ObjectMeta {
+            name: Some("egress".to_string()),
+            namespace: Some("ns".to_string()),
+            ..Default::default()
+        },
+        spec: linkerd_k8s_api::EgressNetworkSpec {
+            networks: None,
+            traffic_policy: linkerd_k8s_api::TrafficPolicy::Allow,
+        },
+        status: None,
+    });
+    assert!(updates_rx.try_recv().is_none());
+}
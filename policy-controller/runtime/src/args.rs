@@ -1,31 +1,32 @@
 use crate::{
     admission::Admission,
     core::IpNet,
+    debug,
     grpc::{self, metrics::GrpcServerMetricsFamily},
-    index::{self, ports::parse_portset, ClusterInfo, DefaultPolicy},
+    grpc_tls::{TlsPaths, TlsServer},
+    index::{self, ports::parse_portset, ClusterInfo, DefaultPolicy, NamespaceScope},
     index_list::IndexList,
+    index_sync::{IndexSync, SyncTracker, SyncedIndex},
     k8s::{self, gateway, Client, Resource},
     lease, status, InboundDiscover, OutboundDiscover,
 };
 use anyhow::{bail, Result};
 use clap::Parser;
-use futures::prelude::*;
+use futures::{prelude::*, stream};
 use kube::{core::DeserializeGuard, runtime::watcher, ResourceExt};
+use kubert::index::IndexNamespacedResource;
+use parking_lot::RwLock;
 use prometheus_client::registry::Registry;
 use serde::de::DeserializeOwned;
 use std::fmt::Debug;
-use std::{net::SocketAddr, sync::Arc};
-use tokio::{sync::mpsc, time::Duration};
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+use tokio::time::{self, Duration};
 use tonic::transport::Server;
 use tracing::{info, info_span, instrument, Instrument};
 
 const DETECT_TIMEOUT: Duration = Duration::from_secs(10);
 const RECONCILIATION_PERIOD: Duration = Duration::from_secs(10);
-
-// The maximum number of status patches to buffer. As a conservative estimate,
-// we assume that sending a patch will take at least 1ms, so we set the buffer
-// size to be the same as the reconciliation period in milliseconds.
-const STATUS_UPDATE_QUEUE_SIZE: usize = RECONCILIATION_PERIOD.as_millis() as usize;
+const WATCH_ERROR_DELAY: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Parser)]
 #[clap(name = "policy", about = "A policy resource controller")]
@@ -71,6 +72,11 @@ pub struct Args {
     #[clap(long, default_value = "cluster.local")]
     cluster_domain: String,
 
+    /// Additional cluster domains under which services may be addressed
+    /// in outbound policy lookups.
+    #[clap(long, value_delimiter = ',')]
+    cluster_domain_aliases: Vec<String>,
+
     #[clap(long, default_value = "all-unauthenticated")]
     default_policy: DefaultPolicy,
 
@@ -90,11 +96,112 @@ pub struct Args {
     #[clap(long, default_value = "5000")]
     patch_timeout_ms: u64,
 
+    /// The minimum number of seconds between Kubernetes Events published for
+    /// the same resource and reason when its status changes.
+    #[clap(long, default_value = "300")]
+    status_event_interval_secs: u64,
+
+    /// The number of seconds between checks for Gateway API resource kinds
+    /// that are installed or removed while the controller is running.
+    #[clap(long, default_value = "30")]
+    api_discovery_interval_secs: u64,
+
+    /// Divides status writes among replicas by namespace, with each of this
+    /// many shards guarded by its own write Lease.
+    #[clap(long, default_value = "1", value_parser = clap::value_parser!(u16).range(1..))]
+    status_shards: u16,
+
     #[clap(long)]
     allow_l5d_request_headers: bool,
 
+    /// Delays watch updates sent to proxies by up to this many milliseconds
+    /// so that bursts of index updates are coalesced into a single message.
+    /// Disabled when zero.
+    #[clap(long, default_value = "0")]
+    grpc_update_debounce_ms: u64,
+
+    /// Requires that gRPC clients' mTLS identities match the workloads named
+    /// in their requests. When gRPC TLS is enabled, identities are taken from
+    /// client certificates and `--grpc-tls-client-ca` is required; otherwise
+    /// `--grpc-trust-proxy-client-id` is required.
+    #[clap(long)]
+    grpc_verify_client_identity: bool,
+
+    /// Trusts the `l5d-client-id` header on plaintext gRPC connections. Only
+    /// set this when the controller is meshed, since the proxy is what
+    /// prevents clients from forging the header.
+    #[clap(long, requires = "grpc_verify_client_identity")]
+    grpc_trust_proxy_client_id: bool,
+
+    /// Limits the number of distinct namespaces that label gRPC watch
+    /// metrics. Watches from namespaces beyond the limit are labeled
+    /// `_other`. Unlimited when unset.
+    #[clap(long)]
+    grpc_metrics_max_namespaces: Option<usize>,
+
+    /// Serves the policy gRPC API over TLS with the PEM-encoded private key
+    /// at this path. The key and certificates are reloaded when they change,
+    /// so rotated credentials are used without a restart.
+    #[clap(long, requires = "grpc_tls_certs")]
+    grpc_tls_key: Option<PathBuf>,
+
+    /// The PEM-encoded certificate chain served with `--grpc-tls-key`.
+    #[clap(long, requires = "grpc_tls_key")]
+    grpc_tls_certs: Option<PathBuf>,
+
+    /// Requires gRPC clients to present a certificate issued by one of the
+    /// PEM-encoded roots at this path. When set, client identities are taken
+    /// from these certificates rather than from the `l5d-client-id` header.
+    #[clap(long, requires = "grpc_tls_key")]
+    grpc_tls_client_ca: Option<PathBuf>,
+
     #[clap(long, default_value = "linkerd-egress")]
     global_egress_network_namespace: String,
+
+    /// Limits the namespaces whose policy resources are watched. Workloads in
+    /// other namespaces are served default policy. All namespaces are watched
+    /// when unset.
+    #[clap(long, value_delimiter = ',')]
+    watch_namespaces: Vec<String>,
+
+    /// Namespaces whose policy resources are not watched. Workloads in these
+    /// namespaces are served default policy.
+    #[clap(long, value_delimiter = ',')]
+    ignore_namespaces: Vec<String>,
+
+    /// Only policy resources matching this label selector are watched.
+    #[clap(long)]
+    watch_label_selector: Option<String>,
+}
+
+/// Limits the resources returned by a watch.
+#[derive(Clone, Debug, Default)]
+struct WatchScope {
+    namespaces: NamespaceScope,
+    label_selector: Option<String>,
+}
+
+impl WatchScope {
+    /// Restricts a watch to resources in scope.
+    fn config(&self, mut config: watcher::Config) -> watcher::Config {
+        let excluded = self.namespaces.excluded();
+        if !excluded.is_empty() {
+            let fields = excluded
+                .iter()
+                .map(|ns| format!("metadata.namespace!={ns}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            config = config.fields(&fields);
+        }
+        if let Some(selector) = &self.label_selector {
+            let labels = match config.label_selector.take() {
+                Some(labels) => format!("{labels},{selector}"),
+                None => selector.clone(),
+            };
+            config = config.labels(&labels);
+        }
+        config
+    }
 }
 
 impl Args {
@@ -114,6 +221,7 @@ impl Args {
             admission_controller_disabled,
             identity_domain,
             cluster_domain,
+            cluster_domain_aliases,
             cluster_networks: IpNets(cluster_networks),
             default_policy,
             policy_deployment_name,
@@ -121,10 +229,44 @@ impl Args {
             probe_networks,
             default_opaque_ports,
             patch_timeout_ms,
+            status_event_interval_secs,
+            api_discovery_interval_secs,
+            status_shards,
             allow_l5d_request_headers,
+            grpc_update_debounce_ms,
+            grpc_verify_client_identity,
+            grpc_trust_proxy_client_id,
+            grpc_metrics_max_namespaces,
+            grpc_tls_key,
+            grpc_tls_certs,
+            grpc_tls_client_ca,
             global_egress_network_namespace,
+            watch_namespaces,
+            ignore_namespaces,
+            watch_label_selector,
         } = self;
 
+        check_grpc_auth(
+            grpc_tls_key.is_some() && grpc_tls_certs.is_some(),
+            grpc_tls_client_ca.is_some(),
+            grpc_verify_client_identity,
+            grpc_trust_proxy_client_id,
+        )?;
+
+        // Fail fast if the gRPC server's TLS credentials can't be loaded.
+        let grpc_tls = match grpc_tls_key.zip(grpc_tls_certs) {
+            Some((key, certs)) => {
+                let paths = TlsPaths {
+                    key,
+                    certs,
+                    client_ca: grpc_tls_client_ca,
+                };
+                info!(?paths, "Loading gRPC TLS credentials");
+                Some(paths.load().await?)
+            }
+            None => None,
+        };
+
         let server = if admission_controller_disabled {
             None
         } else {
@@ -144,6 +286,7 @@ impl Args {
             default_opaque_ports,
             probe_networks,
             global_egress_network_namespace,
+            namespace_scope: NamespaceScope::new(watch_namespaces, ignore_namespaces),
         });
 
         // Build the API index data structures which will maintain information
@@ -165,14 +308,23 @@ impl Args {
             inbound_index.clone(),
         );
         let rt_metrics = kubert::RuntimeMetrics::register(prom.sub_registry_with_prefix("kube"));
+        let index_sync = IndexSync::register(prom.sub_registry_with_prefix("index"));
         let grpc_metrics = grpc::metrics::GrpcServerMetricsFamily::register(
             prom.sub_registry_with_prefix("grpc_server"),
+            grpc_metrics_max_namespaces,
         );
 
         let mut runtime = kubert::Runtime::builder()
             .with_log(log_level, log_format)
             .with_metrics(rt_metrics)
-            .with_admin(admin.into_builder().with_prometheus(prom))
+            .with_admin(
+                admin
+                    .into_builder()
+                    .with_prometheus(prom)
+                    .with_handler("/debug/inbound", debug::inbound(inbound_index.clone()))
+                    .with_handler("/debug/outbound", debug::outbound(outbound_index.clone()))
+                    .with_handler("/debug/explain", debug::explain(inbound_index.clone())),
+            )
             .with_client(client)
             .with_optional_server(server)
             .build()
@@ -186,12 +338,13 @@ impl Args {
             &control_plane_namespace,
             &policy_deployment_name,
             &hostname,
+            status_shards.into(),
         )
         .await?;
 
         // Build the status index which will maintain information necessary for
         // updating the status field of policy resources.
-        let (updates_tx, updates_rx) = mpsc::channel(STATUS_UPDATE_QUEUE_SIZE);
+        let (updates_tx, updates_rx) = status::patch_queue();
         let status_index = status::Index::shared(
             hostname.clone(),
             claims.clone(),
@@ -200,82 +353,97 @@ impl Args {
             cluster_networks.clone(),
         );
 
-        // Spawn resource watches.
+        // Spawn resource watches. Policy resources are only watched within the
+        // configured scope, while workloads, Services, and resources that may
+        // be referenced across namespaces are watched cluster-wide so that
+        // workloads outside of the scope are served default policy.
+        let policy_scope = WatchScope {
+            namespaces: cluster_info.namespace_scope.clone(),
+            label_selector: watch_label_selector,
+        };
+        let cluster_scope = WatchScope::default();
+
+        // Gateway API CRDs may be installed or removed after the controller
+        // starts, so their watches follow API discovery.
+        let api_discovery_interval = Duration::from_secs(api_discovery_interval_secs);
 
-        let pods = guarded_watch::<k8s::Pod, _>(
+        let pods = guarded_watch::<k8s::Pod, _, _>(
             &mut runtime,
+            &index_sync,
+            &cluster_scope,
             watcher::Config::default().labels("linkerd.io/control-plane-ns"),
+            inbound_index.clone(),
         );
+        tokio::spawn(pods.instrument(info_span!("pods")));
 
-        tokio::spawn(
-            kubert::index::namespaced(inbound_index.clone(), pods).instrument(info_span!("pods")),
-        );
-
-        let external_workloads = guarded_watch::<k8s::external_workload::ExternalWorkload, _>(
+        let external_workloads = guarded_watch::<k8s::external_workload::ExternalWorkload, _, _>(
             &mut runtime,
+            &index_sync,
+            &cluster_scope,
             watcher::Config::default(),
+            inbound_index.clone(),
         );
-        tokio::spawn(
-            kubert::index::namespaced(inbound_index.clone(), external_workloads)
-                .instrument(info_span!("external_workloads")),
-        );
+        tokio::spawn(external_workloads.instrument(info_span!("external_workloads")));
 
-        let servers =
-            guarded_watch::<k8s::policy::Server, _>(&mut runtime, watcher::Config::default());
         let servers_indexes = IndexList::new(inbound_index.clone())
             .push(status_index.clone())
             .shared();
-        tokio::spawn(
-            kubert::index::namespaced(servers_indexes, servers).instrument(info_span!("servers")),
-        );
-
-        let server_authzs = guarded_watch::<k8s::policy::ServerAuthorization, _>(
+        let servers = guarded_watch::<k8s::policy::Server, _, _>(
             &mut runtime,
+            &index_sync,
+            &policy_scope,
             watcher::Config::default(),
+            servers_indexes,
         );
-        tokio::spawn(
-            kubert::index::namespaced(inbound_index.clone(), server_authzs)
-                .instrument(info_span!("serverauthorizations")),
-        );
+        tokio::spawn(servers.instrument(info_span!("servers")));
 
-        let authz_policies = guarded_watch::<k8s::policy::AuthorizationPolicy, _>(
+        let server_authzs = guarded_watch::<k8s::policy::ServerAuthorization, _, _>(
             &mut runtime,
+            &index_sync,
+            &policy_scope,
             watcher::Config::default(),
+            inbound_index.clone(),
         );
-        tokio::spawn(
-            kubert::index::namespaced(inbound_index.clone(), authz_policies)
-                .instrument(info_span!("authorizationpolicies")),
-        );
+        tokio::spawn(server_authzs.instrument(info_span!("serverauthorizations")));
 
-        let mtls_authns = guarded_watch::<k8s::policy::MeshTLSAuthentication, _>(
+        let authz_policies = guarded_watch::<k8s::policy::AuthorizationPolicy, _, _>(
             &mut runtime,
+            &index_sync,
+            &policy_scope,
             watcher::Config::default(),
+            inbound_index.clone(),
         );
-        tokio::spawn(
-            kubert::index::namespaced(inbound_index.clone(), mtls_authns)
-                .instrument(info_span!("meshtlsauthentications")),
-        );
+        tokio::spawn(authz_policies.instrument(info_span!("authorizationpolicies")));
 
-        let network_authns = guarded_watch::<k8s::policy::NetworkAuthentication, _>(
+        let mtls_authns = guarded_watch::<k8s::policy::MeshTLSAuthentication, _, _>(
             &mut runtime,
+            &index_sync,
+            &cluster_scope,
             watcher::Config::default(),
+            inbound_index.clone(),
         );
-        tokio::spawn(
-            kubert::index::namespaced(inbound_index.clone(), network_authns)
-                .instrument(info_span!("networkauthentications")),
-        );
+        tokio::spawn(mtls_authns.instrument(info_span!("meshtlsauthentications")));
 
-        let ratelimit_policies = guarded_watch::<k8s::policy::HttpLocalRateLimitPolicy, _>(
+        let network_authns = guarded_watch::<k8s::policy::NetworkAuthentication, _, _>(
             &mut runtime,
+            &index_sync,
+            &cluster_scope,
             watcher::Config::default(),
+            inbound_index.clone(),
         );
+        tokio::spawn(network_authns.instrument(info_span!("networkauthentications")));
+
         let ratelimit_policies_indexes = IndexList::new(inbound_index.clone())
             .push(status_index.clone())
             .shared();
-        tokio::spawn(
-            kubert::index::namespaced(ratelimit_policies_indexes.clone(), ratelimit_policies)
-                .instrument(info_span!("httplocalratelimitpolicies")),
+        let ratelimit_policies = guarded_watch::<k8s::policy::HttpLocalRateLimitPolicy, _, _>(
+            &mut runtime,
+            &index_sync,
+            &policy_scope,
+            watcher::Config::default(),
+            ratelimit_policies_indexes.clone(),
         );
+        tokio::spawn(ratelimit_policies.instrument(info_span!("httplocalratelimitpolicies")));
 
         let http_routes_indexes = IndexList::new(inbound_index.clone())
             .push(outbound_index.clone())
@@ -283,103 +451,152 @@ impl Args {
             .shared();
 
         if api_resource_exists::<k8s::policy::HttpRoute>(&runtime.client()).await {
-            let http_routes = guarded_watch::<k8s::policy::HttpRoute, _>(
+            let http_routes = guarded_watch::<k8s::policy::HttpRoute, _, _>(
                 &mut runtime,
+                &index_sync,
+                &policy_scope,
                 watcher::Config::default(),
+                http_routes_indexes.clone(),
             );
-
-            tokio::spawn(
-                kubert::index::namespaced(http_routes_indexes.clone(), http_routes)
-                    .instrument(info_span!("httproutes.policy.linkerd.io")),
-            );
+            tokio::spawn(http_routes.instrument(info_span!("httproutes.policy.linkerd.io")));
         } else {
             tracing::warn!(
                 "httproutes.policy.linkerd.io resource kind not found, skipping watches"
             );
         }
 
-        if api_resource_exists::<gateway::HTTPRoute>(&runtime.client()).await {
-            let gateway_http_routes =
-                guarded_watch::<gateway::HTTPRoute, _>(&mut runtime, watcher::Config::default());
-            tokio::spawn(
-                kubert::index::namespaced(http_routes_indexes, gateway_http_routes)
-                    .instrument(info_span!("httproutes.gateway.networking.k8s.io")),
-            );
-        } else {
-            tracing::warn!(
-                "httproutes.gateway.networking.k8s.io resource kind not found, skipping watches"
-            );
-        }
+        let gateway_http_routes = discovered_watch::<gateway::HTTPRoute, _, _>(
+            &mut runtime,
+            &index_sync,
+            &policy_scope,
+            watcher::Config::default(),
+            api_discovery_interval,
+            http_routes_indexes,
+        );
+        tokio::spawn(
+            gateway_http_routes.instrument(info_span!("httproutes.gateway.networking.k8s.io")),
+        );
 
-        if api_resource_exists::<gateway::GRPCRoute>(&runtime.client()).await {
-            let gateway_grpc_routes =
-                guarded_watch::<gateway::GRPCRoute, _>(&mut runtime, watcher::Config::default());
-            let gateway_grpc_routes_indexes = IndexList::new(outbound_index.clone())
-                .push(inbound_index.clone())
-                .push(status_index.clone())
-                .shared();
-            tokio::spawn(
-                kubert::index::namespaced(gateway_grpc_routes_indexes.clone(), gateway_grpc_routes)
-                    .instrument(info_span!("grpcroutes.gateway.networking.k8s.io")),
-            );
-        } else {
-            tracing::warn!(
-                "grpcroutes.gateway.networking.k8s.io resource kind not found, skipping watches"
-            );
-        }
+        let gateway_grpc_routes_indexes = IndexList::new(outbound_index.clone())
+            .push(inbound_index.clone())
+            .push(status_index.clone())
+            .shared();
+        let gateway_grpc_routes = discovered_watch::<gateway::GRPCRoute, _, _>(
+            &mut runtime,
+            &index_sync,
+            &policy_scope,
+            watcher::Config::default(),
+            api_discovery_interval,
+            gateway_grpc_routes_indexes,
+        );
+        tokio::spawn(
+            gateway_grpc_routes.instrument(info_span!("grpcroutes.gateway.networking.k8s.io")),
+        );
 
-        if api_resource_exists::<gateway::TLSRoute>(&runtime.client()).await {
-            let tls_routes =
-                guarded_watch::<gateway::TLSRoute, _>(&mut runtime, watcher::Config::default());
-            let tls_routes_indexes = IndexList::new(status_index.clone())
-                .push(outbound_index.clone())
-                .shared();
-            tokio::spawn(
-                kubert::index::namespaced(tls_routes_indexes.clone(), tls_routes)
-                    .instrument(info_span!("tlsroutes.gateway.networking.k8s.io")),
-            );
-        } else {
-            tracing::warn!(
-                "tlsroutes.gateway.networking.k8s.io resource kind not found, skipping watches"
-            );
-        }
+        let tls_routes_indexes = IndexList::new(status_index.clone())
+            .push(outbound_index.clone())
+            .shared();
+        let tls_routes = discovered_watch::<gateway::TLSRoute, _, _>(
+            &mut runtime,
+            &index_sync,
+            &policy_scope,
+            watcher::Config::default(),
+            api_discovery_interval,
+            tls_routes_indexes,
+        );
+        tokio::spawn(tls_routes.instrument(info_span!("tlsroutes.gateway.networking.k8s.io")));
 
-        if api_resource_exists::<gateway::TCPRoute>(&runtime.client()).await {
-            let tcp_routes =
-                guarded_watch::<gateway::TCPRoute, _>(&mut runtime, watcher::Config::default());
-            let tcp_routes_indexes = IndexList::new(status_index.clone())
-                .push(outbound_index.clone())
-                .shared();
-            tokio::spawn(
-                kubert::index::namespaced(tcp_routes_indexes.clone(), tcp_routes)
-                    .instrument(info_span!("tcproutes.gateway.networking.k8s.io")),
-            );
-        } else {
-            tracing::warn!(
-                "tcproutes.gateway.networking.k8s.io resource kind not found, skipping watches"
-            );
-        }
+        let tcp_routes_indexes = IndexList::new(status_index.clone())
+            .push(outbound_index.clone())
+            .shared();
+        let tcp_routes = discovered_watch::<gateway::TCPRoute, _, _>(
+            &mut runtime,
+            &index_sync,
+            &policy_scope,
+            watcher::Config::default(),
+            api_discovery_interval,
+            tcp_routes_indexes,
+        );
+        tokio::spawn(tcp_routes.instrument(info_span!("tcproutes.gateway.networking.k8s.io")));
 
-        let services = guarded_watch::<k8s::Service, _>(&mut runtime, watcher::Config::default());
-        let services_indexes = IndexList::new(outbound_index.clone())
+        let reference_grants_indexes = IndexList::new(outbound_index.clone())
             .push(status_index.clone())
             .shared();
+        // ReferenceGrants are only enforced while their API is served, so that
+        // cross-namespace backends remain permitted without the CRD.
+        let reference_grants = discovered_watch_with_served::<gateway::ReferenceGrant, _, _>(
+            &mut runtime,
+            &index_sync,
+            &cluster_scope,
+            watcher::Config::default(),
+            api_discovery_interval,
+            reference_grants_indexes,
+            {
+                let outbound_index = outbound_index.clone();
+                let status_index = status_index.clone();
+                move |served| {
+                    outbound_index.write().set_reference_grants_served(served);
+                    status_index.write().set_reference_grants_served(served);
+                }
+            },
+        );
         tokio::spawn(
-            kubert::index::namespaced(services_indexes, services)
-                .instrument(info_span!("services")),
+            reference_grants.instrument(info_span!("referencegrants.gateway.networking.k8s.io")),
         );
 
-        let egress_networks = guarded_watch::<k8s::policy::EgressNetwork, _>(
+        let services_indexes = IndexList::new(outbound_index.clone())
+            .push(status_index.clone())
+            .shared();
+        let services = guarded_watch::<k8s::Service, _, _>(
             &mut runtime,
+            &index_sync,
+            &cluster_scope,
             watcher::Config::default(),
+            services_indexes,
         );
+        tokio::spawn(services.instrument(info_span!("services")));
+
+        // Headless services are indexed by the addresses of their endpoints.
+        let headless_endpoints = guarded_watch::<k8s::EndpointSlice, _, _>(
+            &mut runtime,
+            &index_sync,
+            &cluster_scope,
+            watcher::Config::default().labels("service.kubernetes.io/headless"),
+            outbound_index.clone(),
+        );
+        tokio::spawn(headless_endpoints.instrument(info_span!("endpointslices")));
+
+        if api_resource_exists::<k8s::multicluster::ServiceImport>(&runtime.client()).await {
+            let service_imports_indexes = IndexList::new(outbound_index.clone())
+                .push(status_index.clone())
+                .shared();
+            let service_imports = guarded_watch::<k8s::multicluster::ServiceImport, _, _>(
+                &mut runtime,
+                &index_sync,
+                &cluster_scope,
+                watcher::Config::default(),
+                service_imports_indexes,
+            );
+            tokio::spawn(
+                service_imports.instrument(info_span!("serviceimports.multicluster.x-k8s.io")),
+            );
+        } else {
+            tracing::warn!(
+                "serviceimports.multicluster.x-k8s.io resource kind not found, skipping watches"
+            );
+        }
+
         let egress_networks_indexes = IndexList::new(status_index.clone())
             .push(outbound_index.clone())
             .shared();
-        tokio::spawn(
-            kubert::index::namespaced(egress_networks_indexes, egress_networks)
-                .instrument(info_span!("egressnetworks")),
+        let egress_networks = guarded_watch::<k8s::policy::EgressNetwork, _, _>(
+            &mut runtime,
+            &index_sync,
+            &policy_scope,
+            watcher::Config::default(),
+            egress_networks_indexes,
         );
+        tokio::spawn(egress_networks.instrument(info_span!("egressnetworks")));
 
         // Spawn the status Controller reconciliation.
         tokio::spawn(
@@ -390,12 +607,18 @@ impl Args {
         // Run the gRPC server, serving results by looking up against the index handle.
         tokio::spawn(grpc(
             grpc_addr,
+            grpc_tls,
             cluster_domain,
+            cluster_domain_aliases,
             cluster_networks,
             allow_l5d_request_headers,
+            Duration::from_millis(grpc_update_debounce_ms),
+            grpc_verify_client_identity,
+            grpc_trust_proxy_client_id,
             inbound_index,
             outbound_index,
             grpc_metrics.clone(),
+            index_sync,
             runtime.shutdown_handle(),
         ));
 
@@ -406,6 +629,7 @@ impl Args {
             hostname,
             updates_rx,
             Duration::from_millis(patch_timeout_ms),
+            Duration::from_secs(status_event_interval_secs),
             status_metrics,
         );
         tokio::spawn(
@@ -443,18 +667,38 @@ impl std::str::FromStr for IpNets {
 #[allow(clippy::too_many_arguments)]
 async fn grpc(
     addr: SocketAddr,
+    tls: Option<TlsServer>,
     cluster_domain: String,
+    cluster_domain_aliases: Vec<String>,
     cluster_networks: Vec<IpNet>,
     allow_l5d_request_headers: bool,
+    update_debounce: Duration,
+    verify_client_identity: bool,
+    trust_proxy_client_id: bool,
     inbound_index: index::inbound::SharedIndex,
     outbound_index: index::outbound::SharedIndex,
     metrics: GrpcServerMetricsFamily,
+    index_sync: IndexSync,
     drain: drain::Watch,
 ) -> Result<()> {
+    // Policy served before the indexes have listed every resource could fall
+    // back to the default policy, so hold off until they have synced.
+    tokio::select! {
+        _ = index_sync.synced() => info!("Indexes synced"),
+        _ = drain.clone().signaled() => return Ok(()),
+    }
+
     let inbound_discover = InboundDiscover::new(inbound_index);
+    let verifier = if verify_client_identity {
+        grpc::workload::ClientVerifier::new(inbound_discover.clone(), trust_proxy_client_id)
+    } else {
+        grpc::workload::ClientVerifier::disabled()
+    };
     let inbound_svc = grpc::inbound::InboundPolicyServer::new(
         inbound_discover,
         cluster_networks,
+        update_debounce,
+        verifier.clone(),
         drain.clone(),
         metrics.clone(),
     )
@@ -464,18 +708,36 @@ async fn grpc(
     let outbound_svc = grpc::outbound::OutboundPolicyServer::new(
         outbound_discover,
         cluster_domain,
+        cluster_domain_aliases,
         allow_l5d_request_headers,
+        update_debounce,
+        verifier,
         drain.clone(),
         metrics,
     )
     .svc();
 
     let (close_tx, close_rx) = tokio::sync::oneshot::channel();
-    tokio::pin! {
-        let srv = Server::builder().add_service(inbound_svc).add_service(outbound_svc).serve_with_shutdown(addr, close_rx.map(|_| {}));
-    }
+    let router = Server::builder()
+        .add_service(inbound_svc)
+        .add_service(outbound_svc);
+    let srv = match tls {
+        Some(tls) => {
+            let listener = tokio::net::TcpListener::bind(addr).await?;
+            info!(%addr, "policy gRPC server listening with TLS");
+            router
+                .serve_with_incoming_shutdown(tls.incoming(listener), close_rx.map(|_| {}))
+                .boxed()
+        }
+        None => {
+            info!(%addr, "policy gRPC server listening");
+            router
+                .serve_with_shutdown(addr, close_rx.map(|_| {}))
+                .boxed()
+        }
+    };
+    tokio::pin!(srv);
 
-    info!(%addr, "policy gRPC server listening");
     tokio::select! {
         res = (&mut srv) => res?,
         handle = drain.signaled() => {
@@ -487,32 +749,74 @@ async fn grpc(
 }
 
 async fn api_resource_exists<T>(client: &Client) -> bool
+where
+    T: Resource,
+    T::DynamicType: Default,
+{
+    api_resource_served::<T>(client).await.unwrap_or(false)
+}
+
+/// Checks API discovery for the resource kind. A missing API group is
+/// reported as not served, while other discovery errors are returned.
+async fn api_resource_served<T>(client: &Client) -> Result<bool, kube::Error>
 where
     T: Resource,
     T::DynamicType: Default,
 {
     let dt = Default::default();
-    client
-        .list_api_group_resources(&T::api_version(&dt))
-        .await
-        .ok()
-        .iter()
-        .flat_map(|r| r.resources.iter())
-        .any(|r| r.kind == T::kind(&dt))
+    match client.list_api_group_resources(&T::api_version(&dt)).await {
+        Ok(resources) => Ok(resources.resources.iter().any(|r| r.kind == T::kind(&dt))),
+        Err(kube::Error::Api(error)) if error.code == 404 => Ok(false),
+        Err(error) => Err(error),
+    }
 }
 
 // A watch that uses DeserializeGuard to skip resources which fail to deserialize.
 // Any deserialization errors are logged as warnings and the event is skipped.
-fn guarded_watch<R, T>(
+fn guarded_watch<R, I, T>(
     runtime: &mut kubert::Runtime<T>,
+    index_sync: &IndexSync,
+    scope: &WatchScope,
     watcher_config: watcher::Config,
+    index: Arc<RwLock<I>>,
+) -> impl Future<Output = ()> + Send + 'static
+where
+    R: Resource + DeserializeOwned + Clone + Debug + Send + Sync + 'static,
+    R::DynamicType: Default,
+    I: IndexNamespacedResource<R> + Send + Sync + 'static,
+    T: 'static,
+{
+    let synced = index_sync.track(resource_name::<R>(), runtime.initialized_handle());
+    let watch = runtime.watch_all::<DeserializeGuard<R>>(scope.config(watcher_config));
+    kubert::index::namespaced(SyncedIndex::shared(index, synced), guard(scope, watch))
+}
+
+/// Names a resource kind by its plural and group, e.g.
+/// `httproutes.gateway.networking.k8s.io`.
+fn resource_name<R>() -> String
+where
+    R: Resource,
+    R::DynamicType: Default,
+{
+    let dt = Default::default();
+    let group = R::group(&dt);
+    if group.is_empty() {
+        R::plural(&dt).to_string()
+    } else {
+        format!("{}.{group}", R::plural(&dt))
+    }
+}
+
+fn guard<R>(
+    scope: &WatchScope,
+    watch: impl Stream<Item = watcher::Event<DeserializeGuard<R>>>,
 ) -> impl Stream<Item = watcher::Event<R>>
 where
     R: Resource + DeserializeOwned + Clone + Debug + Send + 'static,
     R::DynamicType: Default,
 {
-    runtime
-        .watch_all::<DeserializeGuard<R>>(watcher_config)
+    let namespaces = scope.namespaces.clone();
+    watch
         .filter_map(async |item| {
             let log_warning = |t: &DeserializeGuard<R>| {
                 if let Err(ref err) = t.0 {
@@ -542,4 +846,255 @@ where
                 watcher::Event::InitDone => Some(watcher::Event::<R>::InitDone),
             }
         })
+        // Excluded namespaces are filtered by the API server, but included
+        // namespaces can't be expressed as a field selector.
+        .filter(move |event| {
+            future::ready(match event {
+                watcher::Event::Apply(r)
+                | watcher::Event::InitApply(r)
+                | watcher::Event::Delete(r) => {
+                    r.namespace().is_none_or(|ns| namespaces.contains(&ns))
+                }
+                watcher::Event::Init | watcher::Event::InitDone => true,
+            })
+        })
+}
+
+/// Indexes a resource kind whose CRD may be installed or removed while the
+/// controller is running.
+///
+/// API discovery is checked every `interval`. While the kind is served, it is
+/// watched like any other resource; once it is no longer served, the watch is
+/// stopped and its resources are removed from the index. Readiness only waits
+/// for the initial sync if the kind is served at startup.
+fn discovered_watch<R, I, T>(
+    runtime: &mut kubert::Runtime<T>,
+    index_sync: &IndexSync,
+    scope: &WatchScope,
+    watcher_config: watcher::Config,
+    interval: Duration,
+    index: Arc<RwLock<I>>,
+) -> impl Future<Output = ()> + Send + 'static
+where
+    R: Resource + DeserializeOwned + Clone + Debug + Send + Sync + 'static,
+    R::DynamicType: Default,
+    I: IndexNamespacedResource<R> + Send + Sync + 'static,
+{
+    discovered_watch_with_served(
+        runtime,
+        index_sync,
+        scope,
+        watcher_config,
+        interval,
+        index,
+        |_| {},
+    )
+}
+
+/// Like [`discovered_watch`], but calls `on_served` with `true` before the
+/// kind's watch is started and with `false` before its index is reset once it
+/// is no longer served.
+fn discovered_watch_with_served<R, I, T>(
+    runtime: &mut kubert::Runtime<T>,
+    index_sync: &IndexSync,
+    scope: &WatchScope,
+    watcher_config: watcher::Config,
+    interval: Duration,
+    index: Arc<RwLock<I>>,
+    on_served: impl Fn(bool) + Send + Sync + 'static,
+) -> impl Future<Output = ()> + Send + 'static
+where
+    R: Resource + DeserializeOwned + Clone + Debug + Send + Sync + 'static,
+    R::DynamicType: Default,
+    I: IndexNamespacedResource<R> + Send + Sync + 'static,
+{
+    let client = runtime.client();
+    let synced = index_sync.track(resource_name::<R>(), runtime.initialized_handle());
+    let index = SyncedIndex::shared(index, synced.clone());
+    let scope = scope.clone();
+    let watch = async move {
+        loop {
+            api_resource_installed(|| api_resource_served::<R>(&client), &synced, interval).await;
+            tracing::info!("Resource kind found, starting watch");
+            on_served(true);
+
+            let watch = kubert::errors::LogAndSleep::fixed_delay(
+                WATCH_ERROR_DELAY,
+                watcher::watcher(
+                    kube::Api::<DeserializeGuard<R>>::all(client.clone()),
+                    scope.config(watcher_config.clone()),
+                ),
+            );
+            let events = guard(&scope, watch)
+                .take_until(api_resource_removed(
+                    {
+                        let client = client.clone();
+                        move || {
+                            let client = client.clone();
+                            async move { api_resource_served::<R>(&client).await }
+                        }
+                    },
+                    interval,
+                ))
+                // Resetting the index with no resources deletes everything that
+                // was indexed by this watch.
+                .chain(stream::once(future::lazy(|_| {
+                    on_served(false);
+                    watcher::Event::Init
+                })))
+                .chain(stream::iter([watcher::Event::InitDone]));
+            kubert::index::namespaced(index.clone(), events).await;
+            synced.reset();
+            tracing::warn!("Resource kind removed, stopped watch");
+        }
+    };
+    runtime.cancel_on_shutdown(watch).map(|_| ())
+}
+
+/// Completes once `served` reports that the resource kind is served, checking
+/// every `interval`.
+///
+/// Readiness is only released once discovery reports that the kind is not
+/// served. Discovery errors are retried without releasing it, since the kind
+/// may be installed and its resources not yet indexed.
+async fn api_resource_installed<F, E>(
+    mut served: impl FnMut() -> F,
+    synced: &SyncTracker,
+    interval: Duration,
+) where
+    F: Future<Output = Result<bool, E>>,
+    E: std::fmt::Display,
+{
+    loop {
+        match served().await {
+            Ok(true) => return,
+            Ok(false) => {
+                if synced.release() {
+                    tracing::warn!("Resource kind not found, waiting for it to be installed");
+                }
+            }
+            Err(error) => tracing::warn!(%error, "Failed to check API discovery"),
+        }
+        time::sleep(interval).await;
+    }
+}
+
+/// Completes once `served` reports that the resource kind is no longer
+/// served, checking every `interval`. Discovery errors are not treated as
+/// removal.
+async fn api_resource_removed<F, E>(mut served: impl FnMut() -> F, interval: Duration)
+where
+    F: Future<Output = Result<bool, E>>,
+    E: std::fmt::Display,
+{
+    loop {
+        time::sleep(interval).await;
+        match served().await {
+            Ok(true) => {}
+            Ok(false) => return,
+            Err(error) => tracing::debug!(%error, "Failed to check API discovery"),
+        }
+    }
+}
+
+/// Rejects gRPC authentication settings under which client identities can't
+/// be established.
+fn check_grpc_auth(
+    tls: bool,
+    client_ca: bool,
+    verify_client_identity: bool,
+    trust_proxy_client_id: bool,
+) -> Result<()> {
+    if client_ca && !tls {
+        bail!("--grpc-tls-client-ca requires --grpc-tls-key and --grpc-tls-certs");
+    }
+    if !verify_client_identity {
+        return Ok(());
+    }
+    if tls {
+        // Connections terminated by the controller don't pass through a
+        // proxy, so the `l5d-client-id` header can't be trusted on them.
+        if !client_ca {
+            bail!("--grpc-verify-client-identity requires --grpc-tls-client-ca when gRPC TLS is enabled");
+        }
+    } else if !trust_proxy_client_id {
+        bail!("--grpc-verify-client-identity requires --grpc-tls-client-ca or --grpc-trust-proxy-client-id");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Returns a discovery check that reports each of `results` in turn.
+    fn discovery(
+        results: impl IntoIterator<Item = Result<bool, &'static str>>,
+    ) -> impl FnMut() -> future::Ready<Result<bool, &'static str>> {
+        let mut results = results.into_iter().collect::<VecDeque<_>>();
+        move || future::ready(results.pop_front().expect("unexpected discovery check"))
+    }
+
+    fn tracker(sync: &IndexSync, initialized: &mut kubert::Initialized) -> SyncTracker {
+        sync.track("kinds.example.com".to_string(), initialized.add_handle())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn discovery_errors_do_not_release_readiness() {
+        let sync = IndexSync::register(&mut Registry::default());
+        let mut initialized = kubert::Initialized::default();
+        let synced = tracker(&sync, &mut initialized);
+
+        let served = discovery([Err("unavailable"), Err("unavailable"), Ok(true)]);
+        api_resource_installed(served, &synced, Duration::from_secs(10)).await;
+
+        // The kind's watch must still sync before the controller is ready.
+        assert!(synced.release());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn uninstalled_kinds_release_readiness() {
+        let sync = IndexSync::register(&mut Registry::default());
+        let mut initialized = kubert::Initialized::default();
+        let synced = tracker(&sync, &mut initialized);
+
+        let served = discovery([Ok(false), Err("unavailable"), Ok(false), Ok(true)]);
+        api_resource_installed(served, &synced, Duration::from_secs(10)).await;
+
+        assert!(!synced.release());
+        time::timeout(Duration::from_secs(1), sync.synced())
+            .await
+            .expect("indexes should be synced");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn discovery_errors_are_not_removal() {
+        let interval = Duration::from_secs(10);
+        let served = discovery([Ok(true), Err("unavailable"), Ok(false)]);
+        let start = time::Instant::now();
+        api_resource_removed(served, interval).await;
+        assert_eq!(start.elapsed(), interval * 3);
+    }
+
+    #[test]
+    fn grpc_auth_requires_an_identity_source() {
+        // Plaintext connections may only be verified with the proxy's header.
+        assert!(check_grpc_auth(false, false, false, false).is_ok());
+        assert!(check_grpc_auth(false, false, true, true).is_ok());
+        assert!(check_grpc_auth(false, false, true, false).is_err());
+
+        // TLS connections may only be verified with client certificates.
+        assert!(check_grpc_auth(true, false, false, false).is_ok());
+        assert!(check_grpc_auth(true, true, true, false).is_ok());
+        assert!(check_grpc_auth(true, true, true, true).is_ok());
+        assert!(check_grpc_auth(true, false, true, false).is_err());
+        assert!(check_grpc_auth(true, false, true, true).is_err());
+    }
+
+    #[test]
+    fn grpc_client_ca_requires_tls() {
+        assert!(check_grpc_auth(false, true, false, false).is_err());
+        assert!(check_grpc_auth(false, true, true, true).is_err());
+    }
 }
@@ -0,0 +1,655 @@
+//! Admin endpoints that describe the policy computed by the indexes.
+//!
+//! These are intended to help diagnose a proxy that receives unexpected policy:
+//!
+//! - `/debug/inbound?namespace=<ns>&pod=<name>&port=<port>` describes the
+//!   server for a pod port, the resources that contributed to it, and the
+//!   policy resources in the pod's namespace that were rejected.
+//! - `/debug/outbound?namespace=<ns>&service=<name>&port=<port>&source_namespace=<ns>`
+//!   describes the outbound policy for a Service port, including the routes
+//!   that contributed to it, and the routes and backends that were rejected.
+//! - `/debug/explain?namespace=<ns>&pod=<name>&port=<port>&client_ip=<ip>`
+//!   evaluates a connection (or, when `path` is set, an HTTP request) against
+//!   a pod port's server and reports whether the proxy would permit it, along
+//!   with the route and authorization that applied. The client may be
+//!   described with `client_identity`, and HTTP requests with `method`,
+//!   `path`, `host` and repeated `header=<name>:<value>` parameters.
+//!
+//! Responses are JSON. The endpoints only read the indexes: describing a pod
+//! or Service port does not cause it to be indexed.
+
+use crate::{core, index};
+use http_body_util::Full;
+use hyper::{body::Incoming, Request, Response, StatusCode};
+use serde_json::{json, Value};
+use std::{net::IpAddr, num::NonZeroU16};
+
+mod json;
+
+type Body = Full<bytes::Bytes>;
+
+pub(crate) fn inbound(
+    index: index::inbound::SharedIndex,
+) -> impl Fn(Request<Incoming>) -> Response<Body> + Send + Sync + 'static {
+    move |req| {
+        let params = Params::from_request(&req);
+        inbound_response(&index.read(), &params)
+    }
+}
+
+fn inbound_response(index: &index::inbound::Index, params: &Params) -> Response<Body> {
+    let (namespace, pod, port) = match (
+        params.get("namespace"),
+        params.get("pod"),
+        parse_port(params),
+    ) {
+        (Some(ns), Some(pod), Ok(port)) => (ns, pod, port),
+        (_, _, Err(error)) => return bad_request(error),
+        _ => return bad_request("`namespace` and `pod` parameters are required"),
+    };
+
+    match index.pod_server_report(namespace, pod, port) {
+        Ok(report) => ok(inbound_json(&report)),
+        Err(error) => not_found(error),
+    }
+}
+
+pub(crate) fn outbound(
+    index: index::outbound::SharedIndex,
+) -> impl Fn(Request<Incoming>) -> Response<Body> + Send + Sync + 'static {
+    move |req| {
+        let params = Params::from_request(&req);
+        outbound_response(&index.read(), &params)
+    }
+}
+
+fn outbound_response(index: &index::outbound::Index, params: &Params) -> Response<Body> {
+    let (namespace, service, port) = match (
+        params.get("namespace"),
+        params.get("service"),
+        parse_port(params),
+    ) {
+        (Some(ns), Some(svc), Ok(port)) => (ns, svc, port),
+        (_, _, Err(error)) => return bad_request(error),
+        _ => return bad_request("`namespace` and `service` parameters are required"),
+    };
+    let source_namespace = params.get("source_namespace").unwrap_or(namespace);
+
+    match index.service_policy_report(namespace, service, port, source_namespace) {
+        Ok(report) => ok(outbound_json(&report)),
+        Err(error) => not_found(error),
+    }
+}
+
+pub(crate) fn explain(
+    index: index::inbound::SharedIndex,
+) -> impl Fn(Request<Incoming>) -> Response<Body> + Send + Sync + 'static {
+    move |req| {
+        let params = Params::from_request(&req);
+        explain_response(&index.read(), &params)
+    }
+}
+
+fn explain_response(index: &index::inbound::Index, params: &Params) -> Response<Body> {
+    let (namespace, pod, port) = match (
+        params.get("namespace"),
+        params.get("pod"),
+        parse_port(params),
+    ) {
+        (Some(ns), Some(pod), Ok(port)) => (ns, pod, port),
+        (_, _, Err(error)) => return bad_request(error),
+        _ => return bad_request("`namespace` and `pod` parameters are required"),
+    };
+    let request = match explain_request(params) {
+        Ok(request) => request,
+        Err(error) => return bad_request(error),
+    };
+
+    let server = match index.pod_server(namespace, pod, port) {
+        Ok(server) => server,
+        Err(error) => return not_found(error),
+    };
+    ok(explain_json(&server, &request))
+}
+
+fn explain_json(
+    server: &core::inbound::InboundServer,
+    request: &core::inbound::ExplainRequest,
+) -> Value {
+    let explanation = server.explain(request);
+    json!({
+        "server": json::server_ref(&server.reference),
+        "decision": json::decision(&explanation.decision),
+        "route": explanation.route.as_ref().map(json::route_ref),
+        "authorization": explanation.authorization.as_ref().map(json::authorization_ref),
+    })
+}
+
+fn explain_request(params: &Params) -> Result<core::inbound::ExplainRequest, String> {
+    let client_ip = params
+        .get("client_ip")
+        .ok_or("`client_ip` parameter is required")?
+        .parse::<IpAddr>()
+        .map_err(|error| format!("invalid client_ip: {error}"))?;
+    let client_identity = params.get("client_identity").map(ToString::to_string);
+
+    let http = match params.get("path") {
+        None => None,
+        Some(path) => {
+            let mut http = hyper::Request::builder()
+                .method(params.get("method").unwrap_or("GET"))
+                .uri(path);
+            if let Some(host) = params.get("host") {
+                http = http.header(hyper::header::HOST, host);
+            }
+            for header in params.get_all("header") {
+                let (name, value) = header
+                    .split_once(':')
+                    .ok_or_else(|| format!("invalid header: {header}"))?;
+                http = http.header(name.trim(), value.trim());
+            }
+            let http = http
+                .body(())
+                .map_err(|error| format!("invalid request: {error}"))?;
+            Some(http)
+        }
+    };
+
+    Ok(core::inbound::ExplainRequest {
+        client_ip,
+        client_identity,
+        http,
+    })
+}
+
+fn inbound_json(report: &index::inbound::InboundServerReport) -> Value {
+    use core::inbound::{AuthorizationRef, RouteRef};
+
+    let server = &report.server;
+
+    // Collect the names of the resources that contributed to the server,
+    // either directly or through one of its routes.
+    let route_authzs = server
+        .http_routes
+        .values()
+        .flat_map(|r| r.authorizations.keys())
+        .chain(
+            server
+                .grpc_routes
+                .values()
+                .flat_map(|r| r.authorizations.keys()),
+        );
+    let mut authorization_policies = Vec::new();
+    let mut server_authorizations = Vec::new();
+    for reference in server.authorizations.keys().chain(route_authzs) {
+        match reference {
+            AuthorizationRef::AuthorizationPolicy(name) => authorization_policies.push(name),
+            AuthorizationRef::ServerAuthorization(name) => server_authorizations.push(name),
+            AuthorizationRef::Default(_) => {}
+        }
+    }
+    let mut routes = server
+        .http_routes
+        .keys()
+        .chain(server.grpc_routes.keys())
+        .filter_map(|reference| match reference {
+            RouteRef::Resource(gkn) => Some(format!("{}/{}/{}", gkn.group, gkn.kind, gkn.name)),
+            RouteRef::Default(_) => None,
+        })
+        .collect::<Vec<_>>();
+    for names in [&mut authorization_policies, &mut server_authorizations] {
+        names.sort();
+        names.dedup();
+    }
+    routes.sort();
+
+    let mut http_routes = server
+        .http_routes
+        .iter()
+        .map(|(r, route)| json::inbound_route(r, route, json::http_route_match))
+        .collect::<Vec<_>>();
+    http_routes.sort_by_key(|v| v["ref"].to_string());
+    let mut grpc_routes = server
+        .grpc_routes
+        .iter()
+        .map(|(r, route)| json::inbound_route(r, route, json::grpc_route_match))
+        .collect::<Vec<_>>();
+    grpc_routes.sort_by_key(|v| v["ref"].to_string());
+
+    json!({
+        "server": {
+            "reference": json::server_ref(&server.reference),
+            "protocol": json::protocol(&server.protocol),
+            "authorizations": json::authorizations(&server.authorizations),
+            "ratelimit": server.ratelimit.as_ref().map(json::ratelimit),
+            "http_routes": http_routes,
+            "grpc_routes": grpc_routes,
+        },
+        "contributing": {
+            "authorization_policies": authorization_policies,
+            "server_authorizations": server_authorizations,
+            "routes": routes,
+            "ratelimit_policy": server.ratelimit.as_ref().map(|rl| &rl.name),
+        },
+        "rejected": report
+            .rejected
+            .iter()
+            .map(|r| {
+                json!({
+                    "group": r.resource.group,
+                    "kind": r.resource.kind,
+                    "namespace": r.resource.namespace,
+                    "name": r.resource.name,
+                    "reason": r.reason,
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn outbound_json(report: &index::outbound::OutboundPolicyReport) -> Value {
+    use core::outbound::Backend;
+
+    fn sorted(mut routes: Vec<Value>) -> Vec<Value> {
+        routes.sort_by_key(|v| {
+            (
+                v["ref"]["namespace"].to_string(),
+                v["ref"]["name"].to_string(),
+            )
+        });
+        routes
+    }
+
+    let policy = &report.policy;
+
+    // Backends that proxies can't route to are still sent, so describe why
+    // each one is unusable.
+    let http_backends = policy.http_routes.iter().flat_map(|(gknn, route)| {
+        route
+            .rules
+            .iter()
+            .flat_map(move |rule| rule.backends.iter().map(move |b| (gknn, b)))
+    });
+    let grpc_backends = policy.grpc_routes.iter().flat_map(|(gknn, route)| {
+        route
+            .rules
+            .iter()
+            .flat_map(move |rule| rule.backends.iter().map(move |b| (gknn, b)))
+    });
+    let tls_backends = policy
+        .tls_routes
+        .iter()
+        .flat_map(|(gknn, route)| route.rule.backends.iter().map(move |b| (gknn, b)));
+    let tcp_backends = policy
+        .tcp_routes
+        .iter()
+        .flat_map(|(gknn, route)| route.rule.backends.iter().map(move |b| (gknn, b)));
+    let mut rejected_backends = http_backends
+        .chain(grpc_backends)
+        .chain(tls_backends)
+        .chain(tcp_backends)
+        .filter_map(|(gknn, backend)| {
+            let reason = match backend {
+                Backend::Invalid { message, .. } => message.clone(),
+                Backend::Service(svc) if !svc.exists => {
+                    format!("Service {}.{} not found", svc.name, svc.namespace)
+                }
+                Backend::ServiceImport(svc) if !svc.exists => {
+                    format!("ServiceImport {}.{} not found", svc.name, svc.namespace)
+                }
+                Backend::EgressNetwork(net) if !net.exists => {
+                    format!("EgressNetwork {}.{} not found", net.name, net.namespace)
+                }
+                _ => return None,
+            };
+            Some(json!({
+                "route": json::route_id(gknn),
+                "backend": json::backend(backend),
+                "reason": reason,
+            }))
+        })
+        .collect::<Vec<_>>();
+    rejected_backends.sort_by_key(|v| v["route"].to_string());
+
+    json!({
+        "parent": json::parent(&policy.parent_info),
+        "port": policy.port.get(),
+        "app_protocol": policy.app_protocol.as_ref().map(json::app_protocol),
+        "accrual": policy.accrual.as_ref().map(json::accrual),
+        "http_retry": policy
+            .http_retry
+            .as_ref()
+            .map(|r| json::retry(r, json::http_retry_condition)),
+        "grpc_retry": policy
+            .grpc_retry
+            .as_ref()
+            .map(|r| json::retry(r, json::grpc_retry_condition)),
+        "timeouts": json::timeouts(&policy.timeouts),
+        "http_routes": sorted(
+            policy
+                .http_routes
+                .iter()
+                .map(|(gknn, route)| {
+                    json::outbound_route(
+                        gknn,
+                        route,
+                        json::http_route_match,
+                        json::http_retry_condition,
+                    )
+                })
+                .collect()
+        ),
+        "grpc_routes": sorted(
+            policy
+                .grpc_routes
+                .iter()
+                .map(|(gknn, route)| {
+                    json::outbound_route(
+                        gknn,
+                        route,
+                        json::grpc_route_match,
+                        json::grpc_retry_condition,
+                    )
+                })
+                .collect()
+        ),
+        "tls_routes": sorted(
+            policy
+                .tls_routes
+                .iter()
+                .map(|(gknn, route)| json::tls_route(gknn, route))
+                .collect()
+        ),
+        "tcp_routes": sorted(
+            policy
+                .tcp_routes
+                .iter()
+                .map(|(gknn, route)| json::tcp_route(gknn, route))
+                .collect()
+        ),
+        "rejected": {
+            "routes": report
+                .rejected_routes
+                .iter()
+                .map(|r| json!({ "route": json::route_id(&r.route), "reason": r.reason }))
+                .collect::<Vec<_>>(),
+            "backends": rejected_backends,
+        },
+    })
+}
+
+/// Decoded query parameters. Parameters may be repeated.
+struct Params(Vec<(String, String)>);
+
+impl Params {
+    fn from_request<B>(req: &Request<B>) -> Self {
+        Self::from_query(req.uri().query().unwrap_or_default())
+    }
+
+    fn from_query(query: &str) -> Self {
+        Self(
+            form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect(),
+        )
+    }
+
+    /// Returns the last value of a parameter.
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn get_all<'p>(&'p self, name: &'p str) -> impl Iterator<Item = &'p str> + 'p {
+        self.0
+            .iter()
+            .filter(move |(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+fn parse_port(params: &Params) -> Result<NonZeroU16, String> {
+    let port = params
+        .get("port")
+        .ok_or_else(|| "`port` parameter is required".to_string())?;
+    port.parse()
+        .map_err(|error| format!("invalid port: {error}"))
+}
+
+fn ok(body: Value) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(hyper::header::CONTENT_TYPE, "application/json")
+        .body(Full::new(body.to_string().into()))
+        .expect("response must be valid")
+}
+
+fn bad_request(error: impl std::fmt::Display) -> Response<Body> {
+    error_response(StatusCode::BAD_REQUEST, error)
+}
+
+fn not_found(error: impl std::fmt::Display) -> Response<Body> {
+    error_response(StatusCode::NOT_FOUND, error)
+}
+
+fn error_response(status: StatusCode, error: impl std::fmt::Display) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(hyper::header::CONTENT_TYPE, "application/json")
+        .body(Full::new(
+            json!({ "error": error.to_string() }).to_string().into(),
+        ))
+        .expect("response must be valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        inbound::{
+            AuthorizationRef, ClientAuthentication, ClientAuthorization, InboundServer,
+            ProxyProtocol, ServerRef,
+        },
+        outbound::{
+            Backend, HttpRoute, OutboundPolicy, OutboundRouteRule, ParentInfo, WeightedService,
+        },
+        routes::{GroupKindNamespaceName, HttpRouteMatch},
+        IdentityMatch,
+    };
+    use std::time::Duration;
+
+    fn gknn(name: &'static str) -> GroupKindNamespaceName {
+        GroupKindNamespaceName {
+            group: "gateway.networking.k8s.io".into(),
+            kind: "HTTPRoute".into(),
+            namespace: "ns".into(),
+            name: name.into(),
+        }
+    }
+
+    fn server() -> InboundServer {
+        InboundServer {
+            reference: ServerRef::Server("web".to_string()),
+            protocol: ProxyProtocol::Detect {
+                timeout: Duration::from_secs(10),
+            },
+            authorizations: [(
+                AuthorizationRef::AuthorizationPolicy("authn".to_string()),
+                ClientAuthorization {
+                    networks: vec!["10.0.0.0/8".parse::<ipnet::IpNet>().unwrap().into()],
+                    authentication: ClientAuthentication::TlsAuthenticated(vec![
+                        IdentityMatch::Suffix(vec!["ns".to_string()]),
+                    ]),
+                },
+            )]
+            .into_iter()
+            .collect(),
+            ratelimit: None,
+            http_routes: Default::default(),
+            grpc_routes: Default::default(),
+        }
+    }
+
+    #[test]
+    fn inbound_fields_are_structured() {
+        let report = index::inbound::InboundServerReport {
+            server: server(),
+            rejected: vec![],
+        };
+        let json = inbound_json(&report);
+        let server = &json["server"];
+        assert_eq!(server["reference"], json!({ "server": "web" }));
+        assert_eq!(
+            server["protocol"],
+            json!({ "detect": { "timeout_ms": 10000 } })
+        );
+        assert_eq!(
+            server["authorizations"],
+            json!([{
+                "ref": { "kind": "AuthorizationPolicy", "name": "authn" },
+                "authentication": { "tls_authenticated": { "identities": ["*.ns"] } },
+                "networks": [{ "net": "10.0.0.0/8", "except": [] }],
+            }])
+        );
+        assert_eq!(
+            json["contributing"]["authorization_policies"],
+            json!(["authn"])
+        );
+    }
+
+    #[test]
+    fn explain_is_structured() {
+        let request = core::inbound::ExplainRequest {
+            client_ip: "10.1.2.3".parse().unwrap(),
+            client_identity: Some("web.ns".to_string()),
+            http: None,
+        };
+        assert_eq!(
+            explain_json(&server(), &request),
+            json!({
+                "server": { "server": "web" },
+                "decision": "allow",
+                "route": null,
+                "authorization": { "kind": "AuthorizationPolicy", "name": "authn" },
+            })
+        );
+
+        let request = core::inbound::ExplainRequest {
+            client_identity: None,
+            ..request
+        };
+        assert_eq!(
+            explain_json(&server(), &request)["decision"],
+            json!("unauthorized")
+        );
+    }
+
+    #[test]
+    fn outbound_reports_rejected_routes_and_backends() {
+        let missing = WeightedService {
+            weight: 1,
+            authority: "missing.ns.svc.cluster.local:80".to_string(),
+            name: "missing".to_string(),
+            namespace: "ns".to_string(),
+            port: 80.try_into().unwrap(),
+            filters: vec![],
+            exists: false,
+        };
+        let route = HttpRoute {
+            hostnames: vec![],
+            rules: vec![OutboundRouteRule {
+                matches: vec![HttpRouteMatch::default()],
+                backends: vec![
+                    Backend::Service(missing),
+                    Backend::Invalid {
+                        weight: 1,
+                        message: "RefNotPermitted: denied".to_string(),
+                    },
+                ],
+                retry: None,
+                timeouts: Default::default(),
+                filters: vec![],
+            }],
+            creation_timestamp: None,
+        };
+        let report = index::outbound::OutboundPolicyReport {
+            policy: OutboundPolicy {
+                parent_info: ParentInfo::Service {
+                    name: "web".to_string(),
+                    namespace: "ns".to_string(),
+                    authority: "web.ns.svc.cluster.local:80".to_string(),
+                    external_name: None,
+                },
+                http_routes: [(gknn("applied"), route)].into_iter().collect(),
+                grpc_routes: Default::default(),
+                tls_routes: Default::default(),
+                tcp_routes: Default::default(),
+                port: 80.try_into().unwrap(),
+                app_protocol: None,
+                accrual: None,
+                http_retry: None,
+                grpc_retry: None,
+                timeouts: Default::default(),
+            },
+            rejected_routes: vec![index::outbound::RouteRejection {
+                route: gknn("rejected"),
+                reason: "invalid path regex".to_string(),
+            }],
+        };
+
+        let json = outbound_json(&report);
+        assert_eq!(json["parent"]["kind"], json!("Service"));
+        assert_eq!(
+            json["http_routes"][0]["rules"][0]["matches"][0]["path"],
+            json!({ "prefix": "/" })
+        );
+        assert_eq!(
+            json["rejected"]["routes"],
+            json!([{
+                "route": {
+                    "group": "gateway.networking.k8s.io",
+                    "kind": "HTTPRoute",
+                    "namespace": "ns",
+                    "name": "rejected",
+                },
+                "reason": "invalid path regex",
+            }])
+        );
+        let reasons = json["rejected"]["backends"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b["reason"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            ["Service missing.ns not found", "RefNotPermitted: denied"]
+        );
+    }
+
+    #[test]
+    fn requires_parameters() {
+        let index = index::inbound::Index::shared(index::ClusterInfo {
+            networks: vec![],
+            control_plane_ns: "linkerd".to_string(),
+            dns_domain: "cluster.local".to_string(),
+            identity_domain: "cluster.local".to_string(),
+            default_policy: index::DefaultPolicy::Deny,
+            default_detect_timeout: Duration::from_secs(10),
+            default_opaque_ports: Default::default(),
+            probe_networks: vec![],
+            global_egress_network_namespace: std::sync::Arc::new("linkerd-egress".to_string()),
+            namespace_scope: Default::default(),
+        });
+        let rsp = inbound_response(&index.read(), &Params::from_query("namespace=ns&pod=p"));
+        assert_eq!(rsp.status(), StatusCode::BAD_REQUEST);
+        let rsp = inbound_response(
+            &index.read(),
+            &Params::from_query("namespace=ns&pod=p&port=80"),
+        );
+        assert_eq!(rsp.status(), StatusCode::NOT_FOUND);
+    }
+}
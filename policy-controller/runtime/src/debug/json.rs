@@ -0,0 +1,467 @@
+//! Renders the core policy types as JSON for the debug endpoints.
+//!
+//! The core types are not serializable, so each is described explicitly.
+//! Durations are rendered in milliseconds.
+
+use crate::core::{
+    inbound::{
+        AuthorizationRef, ClientAuthentication, ClientAuthorization, Decision, InboundRoute,
+        InboundRouteRule, ProxyProtocol, RateLimit, RouteRef, ServerRef,
+    },
+    outbound::{
+        self, AppProtocol, Backend, FailureAccrual, GrpcRetryCondition, HttpRetryCondition,
+        OutboundRoute, OutboundRouteRule, ParentInfo, RouteRetry, RouteTimeouts, TcpRoute,
+        TlsRoute, TrafficPolicy,
+    },
+    routes::{
+        FailureInjectorFilter, GroupKindNamespaceName, GrpcRouteMatch, HeaderMatch,
+        HeaderModifierFilter, HostMatch, HttpRouteMatch, PathMatch, PathModifier, QueryParamMatch,
+        RequestRedirectFilter,
+    },
+    NetworkMatch,
+};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+// === inbound ===
+
+pub(super) fn server_ref(reference: &ServerRef) -> Value {
+    match reference {
+        ServerRef::Default(policy) => json!({ "default": policy }),
+        ServerRef::Server(name) => json!({ "server": name }),
+    }
+}
+
+pub(super) fn route_ref(reference: &RouteRef) -> Value {
+    match reference {
+        RouteRef::Default(name) => json!({ "default": name }),
+        RouteRef::Resource(gkn) => json!({
+            "group": gkn.group,
+            "kind": gkn.kind,
+            "name": gkn.name,
+        }),
+    }
+}
+
+pub(super) fn authorization_ref(reference: &AuthorizationRef) -> Value {
+    match reference {
+        AuthorizationRef::Default(name) => json!({ "default": name }),
+        AuthorizationRef::ServerAuthorization(name) => json!({
+            "kind": "ServerAuthorization",
+            "name": name,
+        }),
+        AuthorizationRef::AuthorizationPolicy(name) => json!({
+            "kind": "AuthorizationPolicy",
+            "name": name,
+        }),
+    }
+}
+
+pub(super) fn protocol(protocol: &ProxyProtocol) -> Value {
+    match protocol {
+        ProxyProtocol::Detect { timeout } => {
+            json!({ "detect": { "timeout_ms": millis(*timeout) } })
+        }
+        ProxyProtocol::Http1 => json!("http1"),
+        ProxyProtocol::Http2 => json!("http2"),
+        ProxyProtocol::Grpc => json!("grpc"),
+        ProxyProtocol::Opaque => json!("opaque"),
+        ProxyProtocol::Tls => json!("tls"),
+    }
+}
+
+pub(super) fn decision(decision: &Decision) -> Value {
+    match decision {
+        Decision::Allow => json!("allow"),
+        Decision::RouteNotFound => json!("route_not_found"),
+        Decision::Unauthorized => json!("unauthorized"),
+    }
+}
+
+pub(super) fn authorizations<'a>(
+    authzs: impl IntoIterator<Item = (&'a AuthorizationRef, &'a ClientAuthorization)>,
+) -> Vec<Value> {
+    let mut authzs = authzs
+        .into_iter()
+        .map(|(reference, authz)| {
+            json!({
+                "ref": authorization_ref(reference),
+                "authentication": authentication(&authz.authentication),
+                "networks": authz.networks.iter().map(network).collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+    authzs.sort_by_key(|v| v["ref"].to_string());
+    authzs
+}
+
+fn authentication(authn: &ClientAuthentication) -> Value {
+    match authn {
+        ClientAuthentication::Unauthenticated => json!({ "unauthenticated": {} }),
+        ClientAuthentication::TlsUnauthenticated => json!({ "tls_unauthenticated": {} }),
+        ClientAuthentication::TlsAuthenticated(identities) => json!({
+            "tls_authenticated": {
+                "identities": identities.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            },
+        }),
+    }
+}
+
+fn network(net: &NetworkMatch) -> Value {
+    json!({
+        "net": net.net.to_string(),
+        "except": net.except.iter().map(ToString::to_string).collect::<Vec<_>>(),
+    })
+}
+
+pub(super) fn ratelimit(rl: &RateLimit) -> Value {
+    json!({
+        "name": rl.name,
+        "total": rl.total.as_ref().map(|l| l.requests_per_second),
+        "identity": rl.identity.as_ref().map(|l| l.requests_per_second),
+        "overrides": rl
+            .overrides
+            .iter()
+            .map(|o| {
+                json!({
+                    "requests_per_second": o.requests_per_second,
+                    "client_identities": o.client_identities,
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+pub(super) fn inbound_route<M>(
+    reference: &RouteRef,
+    route: &InboundRoute<M>,
+    route_match: impl Fn(&M) -> Value,
+) -> Value {
+    json!({
+        "ref": route_ref(reference),
+        "hostnames": route.hostnames.iter().map(host_match).collect::<Vec<_>>(),
+        "rules": route
+            .rules
+            .iter()
+            .map(|InboundRouteRule { matches, filters }| {
+                json!({
+                    "matches": matches.iter().map(&route_match).collect::<Vec<_>>(),
+                    "filters": filters.iter().map(inbound_filter).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>(),
+        "authorizations": authorizations(&route.authorizations),
+    })
+}
+
+fn inbound_filter(filter: &crate::core::inbound::Filter) -> Value {
+    use crate::core::inbound::Filter;
+    match filter {
+        Filter::RequestHeaderModifier(f) => {
+            json!({ "request_header_modifier": header_modifier(f) })
+        }
+        Filter::ResponseHeaderModifier(f) => {
+            json!({ "response_header_modifier": header_modifier(f) })
+        }
+        Filter::RequestRedirect(f) => json!({ "request_redirect": redirect(f) }),
+        Filter::FailureInjector(f) => json!({ "failure_injector": failure_injector(f) }),
+    }
+}
+
+// === outbound ===
+
+pub(super) fn parent(parent: &ParentInfo) -> Value {
+    match parent {
+        ParentInfo::Service {
+            name,
+            namespace,
+            authority,
+            external_name,
+        } => json!({
+            "kind": "Service",
+            "namespace": namespace,
+            "name": name,
+            "authority": authority,
+            "external_name": external_name,
+        }),
+        ParentInfo::EgressNetwork {
+            name,
+            namespace,
+            traffic_policy,
+        } => json!({
+            "kind": "EgressNetwork",
+            "namespace": namespace,
+            "name": name,
+            "traffic_policy": match traffic_policy {
+                TrafficPolicy::Allow => "allow",
+                TrafficPolicy::Deny => "deny",
+            },
+        }),
+    }
+}
+
+pub(super) fn app_protocol(protocol: &AppProtocol) -> Value {
+    match protocol {
+        AppProtocol::Http1 => json!("http1"),
+        AppProtocol::Http2 => json!("http2"),
+        AppProtocol::Opaque => json!("opaque"),
+        AppProtocol::Unknown(protocol) => json!({ "unknown": &**protocol }),
+    }
+}
+
+pub(super) fn accrual(accrual: &FailureAccrual) -> Value {
+    match accrual {
+        FailureAccrual::Consecutive {
+            max_failures,
+            backoff,
+        } => json!({
+            "consecutive": {
+                "max_failures": max_failures,
+                "min_penalty_ms": millis(backoff.min_penalty),
+                "max_penalty_ms": millis(backoff.max_penalty),
+                "jitter": backoff.jitter,
+            },
+        }),
+    }
+}
+
+pub(super) fn timeouts(timeouts: &RouteTimeouts) -> Value {
+    json!({
+        "response_ms": timeouts.response.map(millis),
+        "request_ms": timeouts.request.map(millis),
+        "idle_ms": timeouts.idle.map(millis),
+    })
+}
+
+pub(super) fn retry<R>(retry: &RouteRetry<R>, condition: impl Fn(&R) -> Value) -> Value {
+    json!({
+        "limit": retry.limit,
+        "timeout_ms": retry.timeout.map(millis),
+        "conditions": retry
+            .conditions
+            .as_ref()
+            .map(|conditions| conditions.iter().map(condition).collect::<Vec<_>>()),
+    })
+}
+
+pub(super) fn http_retry_condition(condition: &HttpRetryCondition) -> Value {
+    json!({
+        "status_min": condition.status_min,
+        "status_max": condition.status_max,
+    })
+}
+
+pub(super) fn grpc_retry_condition(condition: &GrpcRetryCondition) -> Value {
+    json!(match condition {
+        GrpcRetryCondition::Cancelled => "cancelled",
+        GrpcRetryCondition::DeadlineExceeded => "deadline_exceeded",
+        GrpcRetryCondition::ResourceExhausted => "resource_exhausted",
+        GrpcRetryCondition::Internal => "internal",
+        GrpcRetryCondition::Unavailable => "unavailable",
+    })
+}
+
+pub(super) fn route_id(gknn: &GroupKindNamespaceName) -> Value {
+    json!({
+        "group": gknn.group,
+        "kind": gknn.kind,
+        "namespace": gknn.namespace,
+        "name": gknn.name,
+    })
+}
+
+pub(super) fn outbound_route<M, R>(
+    gknn: &GroupKindNamespaceName,
+    route: &OutboundRoute<M, R>,
+    route_match: impl Fn(&M) -> Value,
+    retry_condition: impl Fn(&R) -> Value,
+) -> Value {
+    json!({
+        "ref": route_id(gknn),
+        "hostnames": route.hostnames.iter().map(host_match).collect::<Vec<_>>(),
+        "rules": route
+            .rules
+            .iter()
+            .map(|OutboundRouteRule { matches, backends, retry: r, timeouts: t, filters }| {
+                json!({
+                    "matches": matches.iter().map(&route_match).collect::<Vec<_>>(),
+                    "backends": backends.iter().map(backend).collect::<Vec<_>>(),
+                    "filters": filters.iter().map(outbound_filter).collect::<Vec<_>>(),
+                    "retry": r.as_ref().map(|r| retry(r, &retry_condition)),
+                    "timeouts": timeouts(t),
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+pub(super) fn tls_route(gknn: &GroupKindNamespaceName, route: &TlsRoute) -> Value {
+    json!({
+        "ref": route_id(gknn),
+        "hostnames": route.hostnames.iter().map(host_match).collect::<Vec<_>>(),
+        "backends": route.rule.backends.iter().map(backend).collect::<Vec<_>>(),
+    })
+}
+
+pub(super) fn tcp_route(gknn: &GroupKindNamespaceName, route: &TcpRoute) -> Value {
+    json!({
+        "ref": route_id(gknn),
+        "backends": route.rule.backends.iter().map(backend).collect::<Vec<_>>(),
+    })
+}
+
+pub(super) fn backend(backend: &Backend) -> Value {
+    match backend {
+        Backend::Addr(addr) => json!({
+            "kind": "Addr",
+            "weight": addr.weight,
+            "addr": std::net::SocketAddr::new(addr.addr, addr.port.get()).to_string(),
+        }),
+        Backend::Service(svc) | Backend::ServiceImport(svc) => json!({
+            "kind": if matches!(backend, Backend::Service(_)) { "Service" } else { "ServiceImport" },
+            "weight": svc.weight,
+            "namespace": svc.namespace,
+            "name": svc.name,
+            "port": svc.port.get(),
+            "authority": svc.authority,
+            "exists": svc.exists,
+            "filters": svc.filters.iter().map(outbound_filter).collect::<Vec<_>>(),
+        }),
+        Backend::EgressNetwork(net) => json!({
+            "kind": "EgressNetwork",
+            "weight": net.weight,
+            "namespace": net.namespace,
+            "name": net.name,
+            "port": net.port.map(|p| p.get()),
+            "exists": net.exists,
+            "filters": net.filters.iter().map(outbound_filter).collect::<Vec<_>>(),
+        }),
+        Backend::Invalid { weight, message } => json!({
+            "kind": "Invalid",
+            "weight": weight,
+            "message": message,
+        }),
+    }
+}
+
+fn outbound_filter(filter: &outbound::Filter) -> Value {
+    use outbound::Filter;
+    match filter {
+        Filter::RequestHeaderModifier(f) => {
+            json!({ "request_header_modifier": header_modifier(f) })
+        }
+        Filter::ResponseHeaderModifier(f) => {
+            json!({ "response_header_modifier": header_modifier(f) })
+        }
+        Filter::RequestRedirect(f) => json!({ "request_redirect": redirect(f) }),
+        Filter::FailureInjector(f) => json!({ "failure_injector": failure_injector(f) }),
+    }
+}
+
+// === routes ===
+
+fn host_match(host: &HostMatch) -> Value {
+    match host {
+        HostMatch::Exact(host) => json!(host),
+        HostMatch::Suffix { reverse_labels } => {
+            let labels = reverse_labels.iter().rev().map(String::as_str);
+            json!(std::iter::once("*")
+                .chain(labels)
+                .collect::<Vec<_>>()
+                .join("."))
+        }
+    }
+}
+
+pub(super) fn http_route_match(m: &HttpRouteMatch) -> Value {
+    json!({
+        "path": m.path.as_ref().map(|path| match path {
+            PathMatch::Exact(path) => json!({ "exact": path }),
+            PathMatch::Prefix(path) => json!({ "prefix": path }),
+            PathMatch::Regex(re) => json!({ "regex": re.as_str() }),
+        }),
+        "headers": m.headers.iter().map(header_match).collect::<Vec<_>>(),
+        "query_params": m
+            .query_params
+            .iter()
+            .map(|qp| match qp {
+                QueryParamMatch::Exact(name, value) => json!({ "name": name, "exact": value }),
+                QueryParamMatch::Regex(name, re) => json!({ "name": name, "regex": re.as_str() }),
+            })
+            .collect::<Vec<_>>(),
+        "method": m.method.as_ref().map(|m| m.as_str()),
+    })
+}
+
+pub(super) fn grpc_route_match(m: &GrpcRouteMatch) -> Value {
+    json!({
+        "headers": m.headers.iter().map(header_match).collect::<Vec<_>>(),
+        "method": m.method.as_ref().map(|m| json!({
+            "service": m.service,
+            "method": m.method,
+        })),
+    })
+}
+
+fn header_match(m: &HeaderMatch) -> Value {
+    match m {
+        HeaderMatch::Exact(name, value) => json!({
+            "name": name.as_str(),
+            "exact": String::from_utf8_lossy(value.as_bytes()),
+        }),
+        HeaderMatch::Regex(name, re) => json!({
+            "name": name.as_str(),
+            "regex": re.as_str(),
+        }),
+    }
+}
+
+fn header_modifier(f: &HeaderModifierFilter) -> Value {
+    let headers = |headers: &[(
+        crate::core::routes::HeaderName,
+        crate::core::routes::HeaderValue,
+    )]| {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                json!({
+                    "name": name.as_str(),
+                    "value": String::from_utf8_lossy(value.as_bytes()),
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    json!({
+        "add": headers(&f.add),
+        "set": headers(&f.set),
+        "remove": f.remove.iter().map(|name| name.as_str()).collect::<Vec<_>>(),
+    })
+}
+
+fn redirect(f: &RequestRedirectFilter) -> Value {
+    json!({
+        "scheme": f.scheme.as_ref().map(|s| s.as_str()),
+        "host": f.host,
+        "path": f.path.as_ref().map(|path| match path {
+            PathModifier::Full(path) => json!({ "full": path }),
+            PathModifier::Prefix(path) => json!({ "prefix": path }),
+        }),
+        "port": f.port.map(|p| p.get()),
+        "status": f.status.map(|s| s.as_u16()),
+    })
+}
+
+fn failure_injector(f: &FailureInjectorFilter) -> Value {
+    json!({
+        "status": f.status.as_u16(),
+        "message": f.message,
+        "ratio": {
+            "numerator": f.ratio.numerator,
+            "denominator": f.ratio.denominator,
+        },
+    })
+}
+
+fn millis(duration: Duration) -> u128 {
+    duration.as_millis()
+}
@@ -0,0 +1,360 @@
+//! TLS termination for the policy gRPC server.
+//!
+//! Credentials are loaded once at startup and then reloaded whenever their
+//! files change, so that rotated keys and certificates take effect without
+//! restarting the controller.
+
+use crate::grpc::workload::TlsClientInfo;
+use anyhow::{anyhow, Context, Result};
+use futures::prelude::*;
+use std::{
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    task::{Context as TaskContext, Poll},
+};
+use tokio::{
+    io::{self, AsyncRead, AsyncWrite, ReadBuf},
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, watch},
+    time,
+};
+use tokio_rustls::{rustls, server::TlsStream, TlsAcceptor};
+use tonic::transport::server::Connected;
+use tracing::{debug, info, warn};
+
+const HANDSHAKE_TIMEOUT: time::Duration = time::Duration::from_secs(10);
+
+/// How long to wait before accepting again after an accept error.
+const ACCEPT_ERROR_BACKOFF: time::Duration = time::Duration::from_millis(100);
+
+/// How often the credential files are checked for changes.
+const RELOAD_INTERVAL: time::Duration = time::Duration::from_secs(10);
+
+/// The files from which the gRPC server's TLS credentials are loaded.
+#[derive(Clone, Debug)]
+pub(crate) struct TlsPaths {
+    pub(crate) key: PathBuf,
+    pub(crate) certs: PathBuf,
+
+    /// When set, clients must present a certificate issued by one of these
+    /// roots.
+    pub(crate) client_ca: Option<PathBuf>,
+}
+
+/// Terminates TLS for the gRPC server with the most recently loaded
+/// credentials.
+#[derive(Clone, Debug)]
+pub(crate) struct TlsServer {
+    config: watch::Receiver<Arc<rustls::ServerConfig>>,
+}
+
+/// The contents of the credential files.
+#[derive(Debug, PartialEq)]
+struct Pem {
+    key: Vec<u8>,
+    certs: Vec<u8>,
+    client_ca: Option<Vec<u8>>,
+}
+
+/// A TLS connection accepted by the gRPC server.
+pub(crate) struct TlsConn {
+    io: TlsStream<TcpStream>,
+    info: TlsClientInfo,
+}
+
+// === impl TlsPaths ===
+
+impl TlsPaths {
+    /// Loads the credentials, failing if they can't be read or are invalid,
+    /// and spawns a task that reloads them when their files change.
+    pub(crate) async fn load(self) -> Result<TlsServer> {
+        let mut pem = self.read().await?;
+        let (tx, rx) = watch::channel(self.server_config(&pem)?);
+        tokio::spawn(async move {
+            let mut interval = time::interval(RELOAD_INTERVAL);
+            interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+            interval.tick().await;
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = tx.closed() => return,
+                }
+                self.reload(&mut pem, &tx).await;
+            }
+        });
+        Ok(TlsServer { config: rx })
+    }
+
+    /// Publishes a new server config if the credential files have changed.
+    ///
+    /// The last good config is kept when the files can't be loaded, e.g. when
+    /// only some of them have been rotated.
+    async fn reload(&self, current: &mut Pem, tx: &watch::Sender<Arc<rustls::ServerConfig>>) {
+        let config = match self.read().await {
+            Ok(pem) if pem == *current => return,
+            Ok(pem) => self.server_config(&pem).map(|config| (pem, config)),
+            Err(error) => Err(error),
+        };
+        match config {
+            Ok((pem, config)) => {
+                info!("Reloaded gRPC TLS credentials");
+                *current = pem;
+                tx.send_replace(config);
+            }
+            Err(error) => warn!(%error, "Failed to reload gRPC TLS credentials"),
+        }
+    }
+
+    async fn read(&self) -> Result<Pem> {
+        let key = read(&self.key).await?;
+        let certs = read(&self.certs).await?;
+        let client_ca = match self.client_ca.as_ref() {
+            Some(path) => Some(read(path).await?),
+            None => None,
+        };
+        Ok(Pem {
+            key,
+            certs,
+            client_ca,
+        })
+    }
+
+    fn server_config(&self, pem: &Pem) -> Result<Arc<rustls::ServerConfig>> {
+        let key = rustls_pemfile::private_key(&mut pem.key.as_slice())?
+            .ok_or_else(|| anyhow!("no private key in {}", self.key.display()))?;
+
+        let certs = parse_certs(&self.certs, &pem.certs)?;
+        if certs.is_empty() {
+            return Err(anyhow!("no certificates in {}", self.certs.display()));
+        }
+
+        // Use the process-wide provider installed at startup so that the gRPC
+        // server and the Kubernetes client share the same crypto backend.
+        let provider = rustls::crypto::CryptoProvider::get_default()
+            .cloned()
+            .unwrap_or_else(|| Arc::new(rustls::crypto::aws_lc_rs::default_provider()));
+        let builder = rustls::ServerConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()?;
+        let builder = match self.client_ca.as_ref().zip(pem.client_ca.as_ref()) {
+            Some((path, client_ca)) => {
+                let mut roots = rustls::RootCertStore::empty();
+                for cert in parse_certs(path, client_ca)? {
+                    roots.add(cert)?;
+                }
+                let verifier = rustls::server::WebPkiClientVerifier::builder_with_provider(
+                    Arc::new(roots),
+                    provider,
+                )
+                .build()?;
+                builder.with_client_cert_verifier(verifier)
+            }
+            None => builder.with_no_client_auth(),
+        };
+        let mut config = builder.with_single_cert(certs, key)?;
+        config.alpn_protocols = vec![b"h2".to_vec()];
+        Ok(Arc::new(config))
+    }
+}
+
+// === impl TlsServer ===
+
+impl TlsServer {
+    /// Accepts TLS connections from `listener`.
+    ///
+    /// Connections that fail the handshake are logged and dropped.
+    pub(crate) fn incoming(self, listener: TcpListener) -> impl Stream<Item = io::Result<TlsConn>> {
+        let (tx, rx) = mpsc::channel(1);
+        tokio::spawn(async move {
+            loop {
+                let (tcp, client_addr) = tokio::select! {
+                    res = listener.accept() => match res {
+                        Ok(accepted) => accepted,
+                        Err(error) => {
+                            // Back off so that persistent errors, e.g. when
+                            // the process is out of file descriptors, don't
+                            // spin.
+                            warn!(%error, "Failed to accept connection");
+                            time::sleep(ACCEPT_ERROR_BACKOFF).await;
+                            continue;
+                        }
+                    },
+                    _ = tx.closed() => return,
+                };
+
+                let tls = self.clone();
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    match time::timeout(HANDSHAKE_TIMEOUT, tls.accept(tcp)).await {
+                        Ok(Ok(conn)) => {
+                            let _ = tx.send(Ok(conn)).await;
+                        }
+                        Ok(Err(error)) => info!(%client_addr, %error, "TLS handshake failed"),
+                        Err(_) => info!(%client_addr, "TLS handshake timed out"),
+                    }
+                });
+            }
+        });
+        tokio_stream::wrappers::ReceiverStream::new(rx)
+    }
+
+    async fn accept(&self, tcp: TcpStream) -> Result<TlsConn> {
+        let remote_addr = tcp.peer_addr().ok();
+        let acceptor = TlsAcceptor::from(self.config.borrow().clone());
+        let io = acceptor.accept(tcp).await?;
+        let client_id = io
+            .get_ref()
+            .1
+            .peer_certificates()
+            .and_then(|certs| certs.first())
+            .and_then(client_identity);
+        debug!(?remote_addr, ?client_id, "Accepted TLS connection");
+        Ok(TlsConn {
+            io,
+            info: TlsClientInfo {
+                remote_addr,
+                client_id,
+            },
+        })
+    }
+}
+
+async fn read(path: &Path) -> Result<Vec<u8>> {
+    tokio::fs::read(path)
+        .await
+        .with_context(|| format!("failed to read {}", path.display()))
+}
+
+fn parse_certs(path: &Path, pem: &[u8]) -> Result<Vec<rustls::pki_types::CertificateDer<'static>>> {
+    rustls_pemfile::certs(&mut &*pem)
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("invalid certificates in {}", path.display()))
+}
+
+/// Linkerd identities are encoded as the first DNS name in a certificate's
+/// subject alternative names.
+fn client_identity(cert: &rustls::pki_types::CertificateDer<'_>) -> Option<String> {
+    let cert = webpki::EndEntityCert::try_from(cert).ok()?;
+    let id = cert.valid_dns_names().next()?;
+    Some(id.to_string())
+}
+
+// === impl TlsConn ===
+
+impl Connected for TlsConn {
+    type ConnectInfo = TlsClientInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.info.clone()
+    }
+}
+
+impl AsyncRead for TlsConn {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.io).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for TlsConn {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.io).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.io).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.io).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.io).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.io.is_write_vectored()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_A: &str = include_str!("../testdata/grpc-tls/a.key");
+    const CERT_A: &str = include_str!("../testdata/grpc-tls/a.crt");
+    const KEY_B: &str = include_str!("../testdata/grpc-tls/b.key");
+    const CERT_B: &str = include_str!("../testdata/grpc-tls/b.crt");
+
+    /// Writes credentials to a directory that is unique to the test.
+    fn write(test: &str, key: &str, certs: &str) -> TlsPaths {
+        let dir = std::env::temp_dir().join(format!("grpc-tls-{}-{test}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let paths = TlsPaths {
+            key: dir.join("tls.key"),
+            certs: dir.join("tls.crt"),
+            client_ca: None,
+        };
+        std::fs::write(&paths.key, key).unwrap();
+        std::fs::write(&paths.certs, certs).unwrap();
+        paths
+    }
+
+    #[tokio::test]
+    async fn fails_fast_on_invalid_credentials() {
+        let paths = write("missing", KEY_A, CERT_A);
+        let missing = TlsPaths {
+            key: paths.key.with_file_name("missing.key"),
+            ..paths.clone()
+        };
+        assert!(missing.load().await.is_err());
+
+        let paths = write("invalid", "", CERT_A);
+        assert!(paths.load().await.is_err());
+
+        let paths = write("valid", KEY_A, CERT_A);
+        assert!(paths.load().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reloads_changed_credentials() {
+        let paths = write("reload", KEY_A, CERT_A);
+        let mut pem = paths.read().await.unwrap();
+        let (tx, mut rx) = watch::channel(paths.server_config(&pem).unwrap());
+        let initial = rx.borrow_and_update().clone();
+
+        // Unchanged files aren't reloaded.
+        paths.reload(&mut pem, &tx).await;
+        assert!(!rx.has_changed().unwrap());
+
+        // Mid-rotation, the key doesn't match the certificate, so the last
+        // good config is kept.
+        std::fs::write(&paths.key, KEY_B).unwrap();
+        paths.reload(&mut pem, &tx).await;
+        assert!(!rx.has_changed().unwrap());
+        assert!(Arc::ptr_eq(&initial, &rx.borrow()));
+
+        // Once rotation completes, the new credentials are used.
+        std::fs::write(&paths.certs, CERT_B).unwrap();
+        paths.reload(&mut pem, &tx).await;
+        assert!(rx.has_changed().unwrap());
+        assert!(!Arc::ptr_eq(&initial, &rx.borrow_and_update()));
+
+        // Files that can't be read don't replace the config.
+        std::fs::remove_file(&paths.certs).unwrap();
+        paths.reload(&mut pem, &tx).await;
+        assert!(!rx.has_changed().unwrap());
+    }
+}
@@ -0,0 +1,255 @@
+//! Tracks whether each watched resource has completed its initial list.
+//!
+//! Until every watch's initial list has been applied to its index, the
+//! indexes may be missing resources that exist in the cluster, so policy
+//! served from them could fall back to the default policy. Each tracked watch
+//! holds the runtime's readiness until it syncs, and [`IndexSync::synced`]
+//! lets the gRPC server wait for all of them before serving.
+
+use kubert::index::{IndexNamespacedResource, NamespacedRemoved};
+use parking_lot::{Mutex, RwLock};
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{family::Family, gauge::Gauge},
+    registry::Registry,
+};
+use std::sync::Arc;
+use tokio::sync::watch;
+
+#[derive(Clone, Debug)]
+pub(crate) struct IndexSync {
+    synced: Family<ResourceLabels, Gauge>,
+    pending: Arc<watch::Sender<usize>>,
+}
+
+/// Tracks the initial sync of a single watch. Clones share the same state.
+#[derive(Clone, Debug)]
+pub(crate) struct SyncTracker(Arc<Tracker>);
+
+/// An index that marks its watch as synced once the watch's initial list has
+/// been applied to the wrapped index.
+#[derive(Debug)]
+pub(crate) struct SyncedIndex<I> {
+    index: Arc<RwLock<I>>,
+    synced: SyncTracker,
+}
+
+#[derive(Debug)]
+struct Tracker {
+    synced: Gauge,
+    pending: Mutex<Option<Pending>>,
+}
+
+#[derive(Debug)]
+struct Pending {
+    count: Arc<watch::Sender<usize>>,
+    _initialized: kubert::initialized::Handle,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ResourceLabels {
+    resource: String,
+}
+
+// === impl IndexSync ===
+
+impl IndexSync {
+    pub(crate) fn register(prom: &mut Registry) -> Self {
+        let synced = Family::default();
+        prom.register(
+            "synced",
+            "Whether a watched resource has completed its initial list (1) or not (0)",
+            synced.clone(),
+        );
+
+        Self {
+            synced,
+            pending: Arc::new(watch::Sender::new(0)),
+        }
+    }
+
+    /// Starts tracking the initial sync of `resource`, holding `initialized`
+    /// until the resource syncs or the tracker is released.
+    pub(crate) fn track(
+        &self,
+        resource: String,
+        initialized: kubert::initialized::Handle,
+    ) -> SyncTracker {
+        let synced = self
+            .synced
+            .get_or_create(&ResourceLabels { resource })
+            .clone();
+        synced.set(0);
+        self.pending.send_modify(|count| *count += 1);
+        SyncTracker(Arc::new(Tracker {
+            synced,
+            pending: Mutex::new(Some(Pending {
+                count: self.pending.clone(),
+                _initialized: initialized,
+            })),
+        }))
+    }
+
+    /// Waits until every tracked resource has synced or been released.
+    pub(crate) async fn synced(&self) {
+        let mut pending = self.pending.subscribe();
+        let _ = pending.wait_for(|count| *count == 0).await;
+    }
+}
+
+// === impl SyncTracker ===
+
+impl SyncTracker {
+    /// Marks the resource as synced, releasing readiness if the initial sync
+    /// was pending.
+    pub(crate) fn synced(&self) {
+        self.0.synced.set(1);
+        self.release();
+    }
+
+    /// Marks the resource as no longer synced, e.g. because its watch stopped.
+    /// This does not affect readiness once the initial sync has completed.
+    pub(crate) fn reset(&self) {
+        self.0.synced.set(0);
+    }
+
+    /// Stops waiting for the initial sync without marking the resource as
+    /// synced. Returns true if the initial sync was still pending.
+    pub(crate) fn release(&self) -> bool {
+        self.0.pending.lock().take().is_some()
+    }
+}
+
+// === impl SyncedIndex ===
+
+impl<I> SyncedIndex<I> {
+    pub(crate) fn shared(index: Arc<RwLock<I>>, synced: SyncTracker) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self { index, synced }))
+    }
+}
+
+impl<I, R> IndexNamespacedResource<R> for SyncedIndex<I>
+where
+    I: IndexNamespacedResource<R>,
+{
+    fn apply(&mut self, resource: R) {
+        self.index.write().apply(resource);
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        self.index.write().delete(namespace, name);
+    }
+
+    fn reset(&mut self, resources: Vec<R>, removed: NamespacedRemoved) {
+        self.index.write().reset(resources, removed);
+        self.synced.synced();
+    }
+}
+
+// === impl Pending ===
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        self.count.send_modify(|count| *count -= 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::k8s::{self, ObjectMeta};
+    use kube::runtime::watcher;
+
+    /// Records the number of watches that had not synced when it was reset.
+    struct TestIndex {
+        pending: watch::Receiver<usize>,
+        pending_on_reset: Option<usize>,
+    }
+
+    impl IndexNamespacedResource<k8s::Pod> for TestIndex {
+        fn apply(&mut self, _: k8s::Pod) {}
+
+        fn delete(&mut self, _: String, _: String) {}
+
+        fn reset(&mut self, _: Vec<k8s::Pod>, _: NamespacedRemoved) {
+            self.pending_on_reset = Some(*self.pending.borrow());
+        }
+    }
+
+    fn pod(name: &str) -> k8s::Pod {
+        k8s::Pod {
+            metadata: ObjectMeta {
+                namespace: Some("ns".to_string()),
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn synced_once_all_resources_sync() {
+        let mut prom = Registry::default();
+        let sync = IndexSync::register(&mut prom);
+        let mut initialized = kubert::Initialized::default();
+
+        let pods = sync.track("pods".to_string(), initialized.add_handle());
+        let servers = sync.track(
+            "servers.policy.linkerd.io".to_string(),
+            initialized.add_handle(),
+        );
+        let gauge = |resource: &str| {
+            sync.synced
+                .get_or_create(&ResourceLabels {
+                    resource: resource.to_string(),
+                })
+                .get()
+        };
+
+        let index = Arc::new(RwLock::new(TestIndex {
+            pending: sync.pending.subscribe(),
+            pending_on_reset: None,
+        }));
+        let events = futures::stream::iter([
+            watcher::Event::Init,
+            watcher::Event::InitApply(pod("pod-0")),
+            watcher::Event::InitDone,
+            watcher::Event::Apply(pod("pod-1")),
+        ]);
+        kubert::index::namespaced(SyncedIndex::shared(index.clone(), pods.clone()), events).await;
+
+        // The watch is only marked as synced once the index has been reset
+        // with its initial list.
+        assert_eq!(index.read().pending_on_reset, Some(2));
+        assert_eq!(gauge("pods"), 1);
+        assert_eq!(*sync.pending.borrow(), 1);
+
+        // Not-installed kinds stop holding readiness without being synced.
+        assert!(servers.release());
+        assert!(!servers.release());
+        assert_eq!(gauge("servers.policy.linkerd.io"), 0);
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), sync.synced())
+            .await
+            .expect("indexes should be synced");
+        tokio::time::timeout(std::time::Duration::from_secs(1), initialized.initialized())
+            .await
+            .expect("runtime should be initialized");
+
+        pods.reset();
+        assert_eq!(gauge("pods"), 0);
+    }
+
+    #[tokio::test]
+    async fn dropped_trackers_release_readiness() {
+        let sync = IndexSync::register(&mut Registry::default());
+        let mut initialized = kubert::Initialized::default();
+
+        let pods = sync.track("pods".to_string(), initialized.add_handle());
+        let clone = pods.clone();
+        drop(pods);
+        assert_eq!(*sync.pending.borrow(), 1);
+        drop(clone);
+        assert_eq!(*sync.pending.borrow(), 0);
+    }
+}
@@ -1,30 +1,36 @@
-use crate::k8s::{self, api::apps::v1::Deployment, ObjectMeta, Resource};
+use crate::{
+    k8s::{self, api::apps::v1::Deployment, ObjectMeta, Resource},
+    status,
+};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use k8s_openapi::api::coordination::v1 as coordv1;
 use kube::api::PatchParams;
-use std::sync::Arc;
+use kubert::lease::{Claim, ClaimParams, LeaseManager};
+use parking_lot::Mutex;
+use std::{collections::HashMap, sync::Arc};
 use tokio::{sync::watch, time};
+use tracing::Instrument;
 
 const LEASE_DURATION: time::Duration = time::Duration::from_secs(30);
 const LEASE_NAME: &str = "policy-controller-write";
 const RENEW_GRACE_PERIOD: time::Duration = time::Duration::from_secs(1);
 const FIELD_MANAGER: &str = "policy-controller";
 
+/// Creates and claims the status write leases.
+///
+/// With a single shard, the `policy-controller-write` Lease is claimed by
+/// whichever replica gets it first. With more shards, each shard is guarded by
+/// its own Lease (the first keeps the unsharded name) and replicas take turns
+/// claiming them so that each live replica holds about `shards / replicas` of
+/// them.
 pub async fn init<T>(
     runtime: &kubert::Runtime<T>,
     namespace: &str,
     deployment_name: &str,
     claimant: &str,
-) -> Result<watch::Receiver<Arc<kubert::lease::Claim>>> {
-    let params = kubert::LeaseParams {
-        name: LEASE_NAME.to_string(),
-        namespace: namespace.to_string(),
-        claimant: claimant.to_string(),
-        lease_duration: LEASE_DURATION,
-        renew_grace_period: RENEW_GRACE_PERIOD,
-        field_manager: Some(FIELD_MANAGER.into()),
-    };
-
+    shards: usize,
+) -> Result<status::Shards> {
     // Fetch the policy-controller deployment so that we can use it as an owner
     // reference of the Lease.
     let api = k8s::Api::<Deployment>::namespaced(runtime.client(), namespace);
@@ -50,10 +56,108 @@ pub async fn init<T>(
         time::sleep(time::Duration::from_secs(1)).await;
     };
 
+    let names = (0..shards.max(1))
+        .map(|shard| match shard {
+            0 => LEASE_NAME.to_string(),
+            shard => format!("{LEASE_NAME}-{shard}"),
+        })
+        .collect::<Vec<_>>();
+    for name in &names {
+        create(runtime.client(), namespace, name, &deployment).await?;
+    }
+
+    if let [name] = names.as_slice() {
+        let params = kubert::LeaseParams {
+            name: name.clone(),
+            namespace: namespace.to_string(),
+            claimant: claimant.to_string(),
+            lease_duration: LEASE_DURATION,
+            renew_grace_period: RENEW_GRACE_PERIOD,
+            field_manager: Some(FIELD_MANAGER.into()),
+        };
+        let (claim, _task) = runtime.spawn_lease(params).await?;
+        return Ok(claim.into());
+    }
+
+    let ready = watch_ready_replicas(api, deployment_name.to_string(), &deployment);
+    tracing::info!(
+        shards = names.len(),
+        ready = *ready.borrow(),
+        "Sharding status writes"
+    );
+
+    let balance = Arc::new(Balance {
+        claimant: claimant.to_string(),
+        claims: Mutex::new(vec![unclaimed(); names.len()]),
+        ready,
+    });
+    let mut claims = Vec::with_capacity(names.len());
+    for (shard, name) in names.into_iter().enumerate() {
+        let api = k8s::Api::<coordv1::Lease>::namespaced(runtime.client(), namespace);
+        let manager = LeaseManager::init(api, &name)
+            .await?
+            .with_field_manager(FIELD_MANAGER);
+        let claim = spawn_shard(manager, shard, balance.clone())
+            .instrument(tracing::info_span!("lease", %name))
+            .await?;
+        claims.push(claim);
+    }
+    Ok(status::Shards::new(claims))
+}
+
+/// Periodically refreshes the number of ready replicas in the Deployment, so
+/// that shards are balanced across the replicas that are actually running.
+fn watch_ready_replicas(
+    api: k8s::Api<Deployment>,
+    name: String,
+    deployment: &Deployment,
+) -> watch::Receiver<usize> {
+    let ready = |deployment: &Deployment| {
+        deployment
+            .status
+            .as_ref()
+            .and_then(|status| status.ready_replicas)
+            .unwrap_or(0)
+            .max(0) as usize
+    };
+    let (tx, rx) = watch::channel(ready(deployment));
+    tokio::spawn(
+        async move {
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = tx.closed() => return,
+                    _ = time::sleep(LEASE_DURATION) => {}
+                }
+                match api.get(&name).await {
+                    Ok(deployment) => {
+                        tx.send_if_modified(|replicas| {
+                            let ready = ready(&deployment);
+                            let modified = *replicas != ready;
+                            *replicas = ready;
+                            modified
+                        });
+                    }
+                    Err(error) => tracing::debug!(%error, "Failed to fetch Deployment"),
+                }
+            }
+        }
+        .in_current_span(),
+    );
+    rx
+}
+
+/// Creates the named Lease if it does not already exist.
+async fn create(
+    client: k8s::Client,
+    namespace: &str,
+    name: &str,
+    deployment: &Deployment,
+) -> Result<()> {
     let patch = kube::api::Patch::Apply(coordv1::Lease {
         metadata: ObjectMeta {
-            name: Some(params.name.clone()),
-            namespace: Some(params.namespace.clone()),
+            name: Some(name.to_string()),
+            namespace: Some(namespace.to_string()),
             // Specifying a resource version of "0" means that we will
             // only create the Lease if it does not already exist.
             resource_version: Some("0".to_string()),
@@ -66,7 +170,7 @@ pub async fn init<T>(
                     ),
                     (
                         "linkerd.io/control-plane-ns".to_string(),
-                        params.namespace.clone(),
+                        namespace.to_string(),
                     ),
                 ]
                 .into_iter()
@@ -80,21 +184,21 @@ pub async fn init<T>(
         field_manager: Some("policy-controller".to_string()),
         ..Default::default()
     };
-    let api = k8s::Api::<coordv1::Lease>::namespaced(runtime.client(), namespace);
+    let api = k8s::Api::<coordv1::Lease>::namespaced(client, namespace);
 
     // An individual request may timeout or hit a transient error, so we try up to 3 times with a brief pause.
     let mut tries = 3;
     loop {
         tries -= 1;
-        let error = match api.patch(LEASE_NAME, &patch_params, &patch).await {
+        let error = match api.patch(name, &patch_params, &patch).await {
             Ok(lease) => {
                 tracing::info!(?lease, "Created Lease");
-                break;
+                return Ok(());
             }
             Err(k8s::Error::Api(error)) if error.code >= 500 => error.into(),
             Err(k8s::Error::Api(error)) => {
                 tracing::debug!(?error, "Lease already exists");
-                break;
+                return Ok(());
             }
             Err(k8s::Error::Service(error)) => error,
             Err(k8s::Error::HyperError(error)) => error.into(),
@@ -108,7 +212,290 @@ pub async fn init<T>(
         tracing::warn!(?error, "Failed to create Lease, retrying in 1s...");
         time::sleep(time::Duration::from_secs(1)).await;
     }
+}
+
+/// State shared by a replica's shard tasks, used to balance shards across the
+/// live replicas.
+struct Balance {
+    claimant: String,
+    /// The latest claim observed on each shard's Lease.
+    claims: Mutex<Vec<Arc<Claim>>>,
+    /// The number of ready replicas in the Deployment.
+    ready: watch::Receiver<usize>,
+}
+
+/// How the shards are spread across live replicas at a point in time.
+#[derive(Debug, PartialEq, Eq)]
+struct Load {
+    /// The number of shards held by this replica.
+    held: usize,
+    /// The number of shards each live replica should hold.
+    capacity: usize,
+    /// True if another live replica holds fewer than `capacity` shards.
+    underloaded: bool,
+}
+
+/// Spawns a task that maintains the claim on a shard's Lease.
+///
+/// A replica that already holds its share of the shards waits an additional
+/// lease duration before claiming an expired Lease, giving replicas that hold
+/// fewer shards the first chance to claim it. A replica that holds more than
+/// its share (e.g. because it started before the other replicas) vacates
+/// Leases rather than renewing them, but only while another live replica
+/// holds less than its share, so that shards don't change hands when fewer
+/// replicas are running than the Deployment requests.
+async fn spawn_shard(
+    manager: LeaseManager,
+    shard: usize,
+    balance: Arc<Balance>,
+) -> Result<watch::Receiver<Arc<Claim>>> {
+    let params = ClaimParams {
+        lease_duration: LEASE_DURATION,
+        renew_grace_period: RENEW_GRACE_PERIOD,
+    };
+    let claimant = balance.claimant.clone();
+
+    let load = balance.load();
+    let mut claim = if load.held < load.capacity {
+        manager.ensure_claimed(&claimant, &params).await?
+    } else {
+        manager.sync().await?.unwrap_or_else(unclaimed)
+    };
+    balance.update(shard, claim.clone());
+    let (tx, rx) = watch::channel(claim.clone());
+
+    tokio::spawn(
+        async move {
+            loop {
+                let holds = claim.holder == claimant;
+                if holds {
+                    tokio::select! {
+                        biased;
+                        _ = tx.closed() => break,
+                        _ = claim.expire_with_grace(RENEW_GRACE_PERIOD) => {}
+                    }
+
+                    let load = balance.load();
+                    if load.held > load.capacity && load.underloaded {
+                        tracing::info!(?load, "Vacating Lease held beyond capacity");
+                        if let Err(error) = manager.vacate(&claimant).await {
+                            tracing::warn!(%error, "Failed to vacate Lease");
+                        }
+                        claim = unclaimed();
+                        balance.update(shard, claim.clone());
+                        if tx.send(claim.clone()).is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                } else {
+                    tokio::select! {
+                        biased;
+                        _ = tx.closed() => break,
+                        _ = claim.expire() => {}
+                    }
+
+                    let load = balance.load();
+                    if load.held >= load.capacity {
+                        tokio::select! {
+                            biased;
+                            _ = tx.closed() => break,
+                            _ = time::sleep(LEASE_DURATION) => {}
+                        }
+                    }
+                }
+
+                let next = match manager.ensure_claimed(&claimant, &params).await {
+                    Ok(next) => next,
+                    Err(error) => {
+                        tracing::debug!(%error, "Error claiming lease, retrying...");
+                        tokio::select! {
+                            biased;
+                            _ = tx.closed() => break,
+                            _ = time::sleep(RENEW_GRACE_PERIOD) => {}
+                        }
+                        continue;
+                    }
+                };
+                claim = next;
+                balance.update(shard, claim.clone());
+                if tx.send(claim.clone()).is_err() {
+                    break;
+                }
+            }
+
+            if claim.holder == claimant {
+                if let Err(error) = manager.vacate(&claimant).await {
+                    tracing::warn!(%error, "Failed to vacate Lease");
+                }
+            }
+            balance.update(shard, unclaimed());
+        }
+        .in_current_span(),
+    );
+
+    Ok(rx)
+}
+
+/// A claim that is not held by any replica.
+fn unclaimed() -> Arc<Claim> {
+    Arc::new(Claim {
+        holder: String::new(),
+        expiry: chrono::Utc::now(),
+    })
+}
+
+// === impl Balance ===
+
+impl Balance {
+    fn update(&self, shard: usize, claim: Arc<Claim>) {
+        self.claims.lock()[shard] = claim;
+    }
+
+    fn load(&self) -> Load {
+        let ready = *self.ready.borrow();
+        Load::new(&self.claimant, &self.claims.lock(), ready, Utc::now())
+    }
+}
+
+// === impl Load ===
+
+impl Load {
+    /// Determines the load from the claims on each shard's Lease and the
+    /// number of ready replicas.
+    ///
+    /// Replicas that hold no shards aren't visible in the Leases, so the
+    /// number of live replicas is the greater of the ready replicas and the
+    /// replicas that hold a current claim, including this one.
+    fn new(claimant: &str, claims: &[Arc<Claim>], ready: usize, now: DateTime<Utc>) -> Self {
+        let mut held = 0;
+        let mut others = HashMap::<&str, usize>::new();
+        for claim in claims {
+            if claim.holder == claimant {
+                held += 1;
+            } else if !claim.holder.is_empty() && claim.expiry > now {
+                *others.entry(claim.holder.as_str()).or_default() += 1;
+            }
+        }
+
+        let live = ready.max(others.len() + 1);
+        let capacity = claims.len().div_ceil(live);
+        let idle = live - 1 > others.len();
+        let underloaded = idle || others.values().any(|&n| n < capacity);
+        Self {
+            held,
+            capacity,
+            underloaded,
+        }
+    }
+}
 
-    let (claim, _task) = runtime.spawn_lease(params).await?;
-    Ok(claim)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims(holders: &[&str], now: DateTime<Utc>) -> Vec<Arc<Claim>> {
+        holders
+            .iter()
+            .map(|holder| {
+                Arc::new(Claim {
+                    holder: holder.to_string(),
+                    expiry: now + chrono::Duration::seconds(30),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn claims_all_shards_when_alone() {
+        let now = Utc::now();
+        let load = Load::new("a", &claims(&["", "", "", ""], now), 0, now);
+        assert_eq!(
+            load,
+            Load {
+                held: 0,
+                capacity: 4,
+                underloaded: false,
+            }
+        );
+
+        // Still holding every shard once ready, the replica has no reason to
+        // vacate any of them.
+        let load = Load::new("a", &claims(&["a", "a", "a", "a"], now), 1, now);
+        assert_eq!(load.held, 4);
+        assert_eq!(load.capacity, 4);
+        assert!(!load.underloaded);
+    }
+
+    #[test]
+    fn does_not_vacate_when_fewer_replicas_are_running() {
+        // The Deployment requests three replicas, but only one is running, so
+        // it holds every shard.
+        let now = Utc::now();
+        let load = Load::new("a", &claims(&["a", "a", "a", "a"], now), 1, now);
+        assert_eq!(load.held, 4);
+        assert_eq!(load.capacity, 4);
+        assert!(!load.underloaded);
+
+        // With two running, each holds its share of the shards.
+        let load = Load::new("a", &claims(&["a", "a", "b", "b"], now), 2, now);
+        assert_eq!(load.held, 2);
+        assert_eq!(load.capacity, 2);
+        assert!(!load.underloaded);
+    }
+
+    #[test]
+    fn vacates_for_idle_replicas() {
+        // A second replica is ready but holds no shards, so the first vacates
+        // the shards it holds beyond its share.
+        let now = Utc::now();
+        let load = Load::new("a", &claims(&["a", "a", "a", "a"], now), 2, now);
+        assert_eq!(
+            load,
+            Load {
+                held: 4,
+                capacity: 2,
+                underloaded: true,
+            }
+        );
+
+        // Once the second replica has claimed a shard, it is still below its
+        // share.
+        let load = Load::new("a", &claims(&["a", "a", "a", "b"], now), 2, now);
+        assert_eq!(load.held, 3);
+        assert!(load.underloaded);
+
+        // The idle replica is below its share, so it claims an expired Lease
+        // without waiting.
+        let load = Load::new("b", &claims(&["a", "a", "a", ""], now), 2, now);
+        assert_eq!(load.held, 0);
+        assert_eq!(load.capacity, 2);
+        assert!(load.held < load.capacity);
+    }
+
+    #[test]
+    fn counts_holders_that_are_not_ready() {
+        // A replica that holds shards is live even if it isn't ready, e.g.
+        // while it starts up.
+        let now = Utc::now();
+        let load = Load::new("a", &claims(&["a", "a", "b", "c"], now), 1, now);
+        assert_eq!(load.capacity, 2);
+        assert!(load.underloaded);
+    }
+
+    #[test]
+    fn ignores_expired_claims() {
+        // A replica whose claims have expired is no longer live.
+        let now = Utc::now();
+        let later = now + chrono::Duration::seconds(60);
+        let load = Load::new("a", &claims(&["a", "a", "b", "b"], now), 1, later);
+        assert_eq!(
+            load,
+            Load {
+                held: 2,
+                capacity: 4,
+                underloaded: false,
+            }
+        );
+    }
 }
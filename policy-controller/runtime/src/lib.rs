@@ -6,7 +6,10 @@ pub use linkerd_policy_controller_k8s_status as status;
 
 mod admission;
 mod args;
+mod debug;
+mod grpc_tls;
 mod index_list;
+mod index_sync;
 mod validation;
 
 mod lease;
@@ -82,6 +85,19 @@ impl core::inbound::DiscoverInboundServer<(grpc::workload::Workload, NonZeroU16)
     }
 }
 
+impl grpc::workload::WorkloadIdentity for InboundDiscover {
+    fn workload_identity(&self, workload: &grpc::workload::Workload) -> Option<String> {
+        let index = self.0.read();
+        let id = match &workload.kind {
+            grpc::workload::Kind::Pod(name) => index.pod_identity(&workload.namespace, name),
+            grpc::workload::Kind::External(name) => {
+                index.external_workload_identity(&workload.namespace, name)
+            }
+        };
+        id.map(Into::into)
+    }
+}
+
 #[async_trait::async_trait]
 impl
     core::outbound::DiscoverOutboundPolicy<
@@ -156,4 +172,8 @@ impl
 
         None
     }
+
+    fn service_exists(&self, namespace: &str, name: &str) -> bool {
+        self.0.read().service_exists(namespace, name)
+    }
 }
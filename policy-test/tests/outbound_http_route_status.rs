@@ -100,9 +100,9 @@
 //         let status = await_route_status(&client, &ns, "test-route").await;
 //         let cond = find_route_condition(&status.parents, "test-service")
 //             .expect("must have at least one 'Accepted' condition set for parent");
-//         // Parent with no ClusterIP should not match.
-//         assert_eq!(cond.status, "False");
-//         assert_eq!(cond.reason, "NoMatchingParent");
+//         // Headless parents are accepted.
+//         assert_eq!(cond.status, "True");
+//         assert_eq!(cond.reason, "Accepted");
 //     })
 //     .await;
 // }
@@ -144,9 +144,9 @@
 //         let status = await_route_status(&client, &ns, "test-route").await;
 //         let cond = find_route_condition(&status.parents, "test-service")
 //             .expect("must have at least one 'Accepted' condition set for parent");
-//         // Parent with ExternalName should not match.
-//         assert_eq!(cond.status, "False");
-//         assert_eq!(cond.reason, "NoMatchingParent");
+//         // ExternalName parents are accepted.
+//         assert_eq!(cond.status, "True");
+//         assert_eq!(cond.reason, "Accepted");
 //     })
 //     .await;
 // }